@@ -0,0 +1,93 @@
+//! APIs that register a client on the server, used to obtain the credentials
+//! when no Pre-Shared Key is set on the display.
+
+use crate::{
+    error::{Error, Result},
+    Bravia, RequestBodyBuilder, RequestBuilder, RequestGetElementType, ServiceContext,
+};
+use reqwest::StatusCode;
+use serde_json::{json, Map, Value};
+
+const ENDPOINT: &str = "accessControl";
+
+/// Provides access to access_control service APIs.
+pub struct AccessControlService(ServiceContext);
+
+impl_service_options!(AccessControlService);
+
+impl AccessControlService {
+    pub fn new(bravia: &Bravia) -> Self {
+        Self(ServiceContext::new(bravia))
+    }
+
+    /// Registers the client on the server using the PIN code shown on the display.\
+    /// The registration is done in two steps:
+    /// 1. Call this API without a `pin`, the server shows a PIN code on the display and `None` is returned.
+    /// 2. Call this API again with the `pin` shown on the display, the value of the `auth` cookie is returned.
+    ///
+    /// The returned cookie can then be passed to [auth_cookie](crate::BraviaBuilder::auth_cookie)
+    /// to access the APIs with the `Private` authentication level.
+    ///
+    /// # Arguments
+    /// * `client_id` - Unique identifier of the client, usually `<nickname>:<uuid>`.
+    /// * `nickname` - Name of the client shown on the display.
+    /// * `pin` - PIN code shown on the display.
+    ///
+    /// # Authentication Level
+    /// None
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", None).await?;
+    /// let access_control = bravia.access_control();
+    /// // The PIN code is now shown on the display
+    /// access_control.act_register("client:1234", "client", None).await?;
+    /// let cookie = access_control
+    ///     .act_register("client:1234", "client", Some("0000"))
+    ///     .await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn act_register(
+        &self,
+        client_id: &str,
+        nickname: &str,
+        pin: Option<&str>,
+    ) -> Result<Option<String>> {
+        let mut params = Map::new();
+        params.insert(String::from("clientid"), Value::from(client_id));
+        params.insert(String::from("nickname"), Value::from(nickname));
+        params.insert(String::from("level"), Value::from("private"));
+
+        let body = RequestBodyBuilder::default()
+            .id(8)
+            .method("actRegister")
+            .params_list(vec![
+                Value::from(params),
+                json!([{"value": "yes", "function": "WOL"}]),
+            ])
+            .build()?;
+        let mut req = RequestBuilder::default();
+        req.endpoint(ENDPOINT).body(body).no_retry();
+        match pin {
+            Some(pin) => {
+                let cookie = req
+                    .pin(pin)
+                    .has_result()
+                    .get(RequestGetElementType::Cookie("auth"))
+                    .make(&self.0)
+                    .await?;
+                Ok(cookie.as_str().map(String::from))
+            }
+            // Without a PIN the server asks for it and shows it on the display
+            None => match req.make(&self.0).await {
+                Err(Error::BadStatus(StatusCode::UNAUTHORIZED, _)) => Ok(None),
+                Err(err) => Err(err),
+                Ok(_) => Ok(None),
+            },
+        }
+    }
+}
@@ -1,8 +1,15 @@
 //! APIs that launch the application itself and the accompanying manipulations related to specific applications.
 
-use crate::{error::Result, Bravia, RequestBodyBuilder, RequestBuilder};
+#[cfg(feature = "encryption")]
+use crate::encryption::Cipher;
+use crate::{
+    error::{Error, Result},
+    lenient, runtime, Bravia, RequestBodyBuilder, RequestBuilder, ServiceContext,
+};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
+use std::fmt;
 
 const ENDPOINT: &str = "appControl";
 
@@ -39,18 +46,261 @@ pub struct ApplicationStatus {
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct WebAppStatus {
     /// WebAppRuntime application status.
+    #[serde(deserialize_with = "lenient::bool")]
     pub active: bool,
     /// The URL of the current webpage to open on WebApp.
     #[serde(default)]
     pub url: String,
 }
 
+// Characters left untouched when encoding intent extras (RFC 3986 unreserved characters).
+const QUERY_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Native application launch request with optional intent extras.\
+/// The extras are percent-encoded and appended to the application URI as query parameters,
+/// this can be used to open deep links into applications.
+/// The application is launched with the [AppUri] built from the intent.
+///
+/// # Examples
+/// ```
+/// # use bravia_api::{app_control::{AppIntent, AppUri}, error::Result};
+/// # fn main() -> Result<()> {
+/// let intent = AppIntent::new("com.sony.dtv.com.google.android.youtube.tv")?
+///     .extra("v", "dQw4w9WgXcQ")
+///     .extra("t", "1m 30s");
+/// assert_eq!(
+///     "com.sony.dtv.com.google.android.youtube.tv?v=dQw4w9WgXcQ&t=1m%2030s",
+///     AppUri::from(intent).to_string()
+/// );
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AppIntent {
+    uri: String,
+    extras: Vec<(String, String)>,
+}
+
+impl AppIntent {
+    /// # Arguments
+    /// * `uri` - URI of the target application, as returned by
+    ///   [getApplicationList](AppControlService::get_application_list).
+    ///
+    /// # Errors
+    /// Returns [Error::InvalidParameter] if the URI is neither a `scheme:` URI nor a package name,
+    /// or if its query is not made of `key=value` pairs.
+    pub fn new(uri: &str) -> Result<Self> {
+        check_app_uri(uri)?;
+        Ok(Self {
+            uri: uri.to_string(),
+            extras: Vec::new(),
+        })
+    }
+
+    /// Adds an intent extra, both `key` and `value` are percent-encoded.
+    pub fn extra(mut self, key: &str, value: &str) -> Self {
+        self.extras.push((key.to_string(), value.to_string()));
+        self
+    }
+}
+
+impl From<AppIntent> for AppUri {
+    fn from(intent: AppIntent) -> Self {
+        let mut uri = intent.uri;
+        // Extras are appended to the query of the URI if there is already one
+        let mut separator = if uri.contains('?') { '&' } else { '?' };
+        for (key, value) in &intent.extras {
+            uri.push(separator);
+            uri.extend(utf8_percent_encode(key, QUERY_ENCODE_SET));
+            uri.push('=');
+            uri.extend(utf8_percent_encode(value, QUERY_ENCODE_SET));
+            separator = '&';
+        }
+        Self(uri)
+    }
+}
+
+// Checks that the URI of an installed application is a `scheme:` URI or a package name,
+// followed by an optional query of `key=value` pairs.
+fn check_app_uri(uri: &str) -> Result<()> {
+    if uri.is_empty() {
+        return Err(Error::InvalidParameter(String::from(
+            "the application URI is empty",
+        )));
+    }
+    if let Some(c) = uri
+        .chars()
+        .find(|c| !c.is_ascii() || c.is_ascii_whitespace() || c.is_ascii_control())
+    {
+        return Err(Error::InvalidParameter(format!(
+            "invalid character {c:?} in the application URI {uri:?}"
+        )));
+    }
+
+    let invalid = |reason: &str| {
+        Err(Error::InvalidParameter(format!(
+            "invalid application URI {uri:?}: {reason}"
+        )))
+    };
+    let without_fragment = uri.split_once('#').map_or(uri, |(uri, _)| uri);
+    let (target, query) = match without_fragment.split_once('?') {
+        Some((target, query)) => (target, Some(query)),
+        None => (without_fragment, None),
+    };
+    match target.split_once(':') {
+        Some((scheme, _)) if !is_uri_scheme(scheme) => return invalid("malformed scheme"),
+        Some(_) => {}
+        None if !is_package_name(target) => {
+            return invalid("expected a scheme: URI or a package name")
+        }
+        None => {}
+    }
+    if let Some(query) = query {
+        if !query.split('&').all(is_query_pair) {
+            return invalid("the query is not made of key=value pairs");
+        }
+    }
+    Ok(())
+}
+
+// Scheme of a URI as defined by RFC 3986, like `localapp` or `https`.
+fn is_uri_scheme(scheme: &str) -> bool {
+    let mut chars = scheme.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+// Package or activity name like `com.sony.dtv.com.netflix.ninja`.
+fn is_package_name(name: &str) -> bool {
+    name.contains('.')
+        && name.split('.').all(|segment| {
+            let mut chars = segment.chars();
+            chars
+                .next()
+                .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        })
+}
+
+// Query parameter with a non empty key and well-formed percent-encoded octets.
+fn is_query_pair(pair: &str) -> bool {
+    let well_formed = |text: &str| {
+        text.split('%').skip(1).all(|escape| {
+            escape.len() >= 2 && escape.as_bytes()[..2].iter().all(u8::is_ascii_hexdigit)
+        })
+    };
+    match pair.split_once('=') {
+        Some((key, value)) => !key.is_empty() && well_formed(key) && well_formed(value),
+        None => false,
+    }
+}
+
+/// URI of the application launched by [setActiveApp](AppControlService::set_active_app).\
+/// The URLs and identifiers are percent-encoded into the query of the WebAppRuntime URI.
+///
+/// # Examples
+/// ```
+/// # use bravia_api::{app_control::AppUri, error::Result};
+/// # fn main() -> Result<()> {
+/// let uri = AppUri::from_url("http://example.com/")?;
+/// assert_eq!(
+///     "localapp://webappruntime?url=http%3A%2F%2Fexample.com%2F",
+///     uri.to_string()
+/// );
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct AppUri(String);
+
+impl AppUri {
+    /// Launches the web page at `url` in the WebAppRuntime.
+    ///
+    /// # Errors
+    /// Returns [Error::InvalidParameter] if `url` is not an `http` or `https` URL.
+    pub fn from_url(url: &str) -> Result<Self> {
+        Ok(Self::web_app("url", check_web_url(url)?))
+    }
+
+    /// Launches the web application described by the manifest at `url` in the WebAppRuntime.
+    ///
+    /// # Errors
+    /// Returns [Error::InvalidParameter] if `url` is not an `http` or `https` URL.
+    pub fn from_manifest(url: &str) -> Result<Self> {
+        Ok(Self::web_app("manifest", check_web_url(url)?))
+    }
+
+    /// Launches the web application with the given unique ID from the USB storage.
+    ///
+    /// # Errors
+    /// Returns [Error::InvalidParameter] if `auid` is empty.
+    pub fn from_auid(auid: &str) -> Result<Self> {
+        if auid.is_empty() {
+            return Err(Error::InvalidParameter(String::from(
+                "the application unique ID is empty",
+            )));
+        }
+        Ok(Self::web_app("auid", auid))
+    }
+
+    /// Launches an installed application.
+    ///
+    /// # Arguments
+    /// * `uri` - URI of the application, as returned by
+    ///   [getApplicationList](AppControlService::get_application_list).
+    ///
+    /// # Errors
+    /// Returns [Error::InvalidParameter] if the URI is neither a `scheme:` URI nor a package name,
+    /// or if its query is not made of `key=value` pairs.
+    pub fn from_package(uri: &str) -> Result<Self> {
+        check_app_uri(uri)?;
+        Ok(Self(uri.to_string()))
+    }
+
+    fn web_app(key: &str, value: &str) -> Self {
+        Self(format!(
+            "localapp://webappruntime?{key}={}",
+            utf8_percent_encode(value, QUERY_ENCODE_SET)
+        ))
+    }
+}
+
+impl fmt::Display for AppUri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+// Lowercase title without spaces and punctuation, used by the fuzzy matching.
+fn normalize_title(title: &str) -> String {
+    title
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn check_web_url(url: &str) -> Result<&str> {
+    match reqwest::Url::parse(url) {
+        Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => Ok(url),
+        _ => Err(Error::InvalidParameter(format!(
+            "{url:?} is not an http or https URL"
+        ))),
+    }
+}
+
 /// Provides access to app_control service APIs.
-pub struct AppControlService<'a>(&'a Bravia);
+pub struct AppControlService(ServiceContext);
+
+impl_service_options!(AppControlService);
 
-impl<'a> AppControlService<'a> {
-    pub fn new(bravia: &'a Bravia) -> Self {
-        Self(bravia)
+impl AppControlService {
+    pub fn new(bravia: &Bravia) -> Self {
+        Self(ServiceContext::new(bravia))
     }
 
     /// Provides the list of applications that can be launched by [setActiveApp](Self::set_active_app).
@@ -76,14 +326,130 @@ impl<'a> AppControlService<'a> {
             .id(60)
             .method("getApplicationList")
             .build()?;
-        let req = RequestBuilder::default()
+        RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
             .has_result()
-            .make(self.0)
-            .await?;
-        Ok(serde_json::from_value(req)?)
+            .make_as(&self.0)
+            .await
+    }
+
+    /// Launches the installed application with the given title, ignoring case.\
+    /// The applications are read with [cached_application_list](Self::cached_application_list),
+    /// the list is read again once if no application matches.
+    ///
+    /// # Arguments
+    /// * `title` - Title of the application, like `YouTube`.
+    /// * `fuzzy` - Also matches the titles that contain `title`, ignoring spaces and punctuation.
+    ///   The shortest matching title is launched.
+    ///
+    /// # Authentication Level
+    /// Generic
+    ///
+    /// # Errors
+    /// [InvalidParameter](Error::InvalidParameter) if no installed application matches the title.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// bravia.app_control().launch_by_title("prime video", true).await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn launch_by_title(&self, title: &str, fuzzy: bool) -> Result<()> {
+        let app = match self.find_application(title, fuzzy).await? {
+            Some(app) => app,
+            None => {
+                // The cached list may be older than the last installation
+                self.clear_application_cache();
+                self.find_application(title, fuzzy).await?.ok_or_else(|| {
+                    Error::InvalidParameter(format!("no application named {:?}", title))
+                })?
+            }
+        };
+        self.set_active_app(app.uri).await
+    }
+
+    /// Same as [getApplicationList](Self::get_application_list), but the list is cached by the client
+    /// and shared by all its clones.\
+    /// The list is read again after the [TTL](crate::BraviaBuilder::app_list_ttl) of the client expires,
+    /// or after [clear_application_cache](Self::clear_application_cache) is called.
+    ///
+    /// # Authentication Level
+    /// Private
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # use std::time::Duration;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::builder("ADDRESS")
+    ///     .auth("PASSWORD")
+    ///     .app_list_ttl(Duration::from_secs(600))
+    ///     .build()
+    ///     .await?;
+    /// let app_list = bravia.app_control().cached_application_list().await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn cached_application_list(&self) -> Result<Vec<Application>> {
+        let bravia = &self.0.bravia;
+        let ttl = bravia.shared.app_list_ttl;
+        let cached = bravia.target().applications.lock().ok().and_then(|cache| {
+            cache
+                .as_ref()
+                .filter(|(read_at, _)| ttl.is_none_or(|ttl| read_at.elapsed() < ttl))
+                .map(|(_, apps)| apps.clone())
+        });
+        if let Some(apps) = cached {
+            return Ok(apps);
+        }
+        let apps = self.get_application_list().await?;
+        if let Ok(mut cache) = bravia.target().applications.lock() {
+            cache.replace((runtime::Instant::now(), apps.clone()));
+        }
+        Ok(apps)
+    }
+
+    /// Forgets the applications cached by [cached_application_list](Self::cached_application_list),
+    /// for example after an application is installed.
+    pub fn clear_application_cache(&self) {
+        if let Ok(mut cache) = self.0.bravia.target().applications.lock() {
+            cache.take();
+        }
+    }
+
+    #[cfg(feature = "encryption")]
+    async fn cipher(&self) -> Result<Cipher> {
+        self.0
+            .bravia
+            .encryption()
+            .with_options(self.0.options.clone())
+            .cipher()
+            .await
+    }
+
+    async fn find_application(&self, title: &str, fuzzy: bool) -> Result<Option<Application>> {
+        let apps = self.cached_application_list().await?;
+        if let Some(app) = apps
+            .iter()
+            .find(|app| app.title.eq_ignore_ascii_case(title))
+        {
+            return Ok(Some(app.clone()));
+        }
+        if !fuzzy {
+            return Ok(None);
+        }
+        let title = normalize_title(title);
+        Ok(apps
+            .into_iter()
+            .filter(|app| normalize_title(&app.title).contains(&title))
+            .min_by_key(|app| app.title.len()))
     }
 
     /// Provides the status of the application itself or the accompanying status related to a specific application.
@@ -95,14 +461,13 @@ impl<'a> AppControlService<'a> {
             .id(55)
             .method("getApplicationStatusList")
             .build()?;
-        let req = RequestBuilder::default()
+        RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
             .has_result()
-            .make(self.0)
-            .await?;
-        Ok(serde_json::from_value(req)?)
+            .make_as(&self.0)
+            .await
     }
 
     /// Returns the current text input on the field of the software keyboard, if `enc_key` is set this must be encrypted.
@@ -110,11 +475,11 @@ impl<'a> AppControlService<'a> {
     ///
     /// # Arguments
     /// * `enc_key` - Encryption key encrypted by the public key.\
-    /// The default value is `None` , which means the data is not encrypted.
+    ///   The default value is `None` , which means the data is not encrypted.
     ///
     /// # Authentication Level
     /// Private
-    pub async fn get_text_form(&self, enc_key: Option<String>) -> Result<String> {
+    pub async fn get_text_form(&self, enc_key: Option<&str>) -> Result<String> {
         let mut params = Map::new();
         if let Some(key) = enc_key {
             params.insert(String::from("encKey"), Value::from(key));
@@ -126,15 +491,27 @@ impl<'a> AppControlService<'a> {
             .version(Some("1.1"))
             .params(Value::from(params))
             .build()?;
-        let req = RequestBuilder::default()
+        RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
             .has_result()
             .get("text".into())
-            .make(self.0)
-            .await?;
-        Ok(serde_json::from_value(req)?)
+            .make_as(&self.0)
+            .await
+    }
+
+    /// Same as [get_text_form](Self::get_text_form) but the text is transmitted encrypted
+    /// with a new [Cipher].\
+    /// Requires the `encryption` feature.
+    ///
+    /// # Authentication Level
+    /// Private
+    #[cfg(feature = "encryption")]
+    pub async fn get_text_form_decrypted(&self) -> Result<String> {
+        let cipher = self.cipher().await?;
+        let text = self.get_text_form(Some(cipher.enc_key())).await?;
+        cipher.decrypt(&text)
     }
 
     /// Provides functions to retrieve the status of WebAppRuntime and to retrieve the URL of the current webpage to open on WebApp.
@@ -146,14 +523,13 @@ impl<'a> AppControlService<'a> {
             .id(1)
             .method("getWebAppStatus")
             .build()?;
-        let req = RequestBuilder::default()
+        RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
             .has_result()
-            .make(self.0)
-            .await?;
-        Ok(serde_json::from_value(req)?)
+            .make_as(&self.0)
+            .await
     }
 
     /// Provides functions to launch an application.
@@ -173,13 +549,13 @@ impl<'a> AppControlService<'a> {
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
     /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
-    /// bravia.app_control().set_active_app("localapp://webappruntime?url=http%3A%2F%2Fexample.com%2F".to_string()).await?;
+    /// bravia.app_control().set_active_app("localapp://webappruntime?url=http%3A%2F%2Fexample.com%2F").await?;
     /// #    Ok(())
     /// # }
     /// ```
-    pub async fn set_active_app(&self, uri: String) -> Result<()> {
+    pub async fn set_active_app(&self, uri: impl Into<String>) -> Result<()> {
         let mut params = Map::new();
-        params.insert(String::from("uri"), Value::from(uri));
+        params.insert(String::from("uri"), Value::from(uri.into()));
 
         let body = RequestBodyBuilder::default()
             .id(601)
@@ -190,17 +566,157 @@ impl<'a> AppControlService<'a> {
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
-            .make(self.0)
+            .make(&self.0)
             .await?;
         Ok(())
     }
 
+    /// Launches an application through [setActiveApp](Self::set_active_app).
+    ///
+    /// # Arguments
+    /// * `uri` - Application to launch, a native application with intent extras
+    ///   can be launched with the URI built from an [AppIntent].
+    ///
+    /// # Authentication Level
+    /// Generic
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{app_control::AppUri, Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// let uri = AppUri::from_url("http://example.com/")?;
+    /// bravia.app_control().set_active_app_uri(&uri).await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn set_active_app_uri(&self, uri: &AppUri) -> Result<()> {
+        self.set_active_app(uri.to_string()).await
+    }
+
+    /// Opens a web page on the display.\
+    /// The page is launched in the WebAppRuntime through [setActiveApp](Self::set_active_app),
+    /// see [AppUri::from_url].
+    ///
+    /// # Arguments
+    /// * `url` - Address of the page, must be an `http` or `https` URL.
+    /// * `prefer_browser` - Opens the page in the built-in browser when the server supports it:
+    ///   the browser is started with [actBrowserControl](crate::browser::BrowserService::act_browser_control)
+    ///   when available, then the page is loaded with [setTextUrl](crate::browser::BrowserService::set_text_url).
+    ///
+    /// # Authentication Level
+    /// Generic
+    ///
+    /// # Errors
+    /// Returns [Error::InvalidParameter] if `url` is not an `http` or `https` URL.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// bravia.app_control().open_url("https://example.com/", false).await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn open_url(&self, url: &str, prefer_browser: bool) -> Result<()> {
+        let uri = AppUri::from_url(url)?;
+        let bravia = &self.0.bravia;
+        if !prefer_browser || !bravia.supports("browser", "setTextUrl", "1.0").await? {
+            return self.set_active_app_uri(&uri).await;
+        }
+        let browser = bravia.browser().with_options(self.0.options.clone());
+        if bravia
+            .supports("browser", "actBrowserControl", "1.0")
+            .await?
+        {
+            browser.act_browser_control("start").await?;
+        }
+        browser.set_text_url(url.to_string()).await
+    }
+
+    /// Same as [set_text_form](Self::set_text_form) with API version 1.1, the text is transmitted encrypted.\
+    /// Requires the `encryption` feature.
+    ///
+    /// # Arguments
+    /// * `text` - Text to input.
+    ///
+    /// # Authentication Level
+    /// Generic
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// bravia.app_control().set_text_form_encrypted("my password").await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "encryption")]
+    pub async fn set_text_form_encrypted(&self, text: &str) -> Result<()> {
+        let cipher = self.cipher().await?;
+        self.set_text_form(cipher.encrypt(text), Some(cipher.enc_key()), Some("1.1"))
+            .await
+    }
+
+    /// Inputs text on the focused text field, after checking with
+    /// [getApplicationStatusList](Self::get_application_status_list) that the software keyboard is shown.\
+    /// With the `encryption` feature the text is transmitted encrypted
+    /// when the server supports [setTextForm](Self::set_text_form) version 1.1.
+    ///
+    /// # Arguments
+    /// * `text` - Text to input.
+    ///
+    /// # Authentication Level
+    /// Generic
+    ///
+    /// # Errors
+    /// [VerificationFailed](Error::VerificationFailed) if no text field is focused.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// bravia.app_control().type_text("the office").await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn type_text(&self, text: &str) -> Result<()> {
+        let text_input = self
+            .get_application_status_list()
+            .await?
+            .into_iter()
+            .any(|app| app.name == "textInput" && app.status == "on");
+        if !text_input {
+            return Err(Error::VerificationFailed(String::from(
+                "the software keyboard is not shown, no text field is focused",
+            )));
+        }
+
+        #[cfg(feature = "encryption")]
+        {
+            let bravia = &self.0.bravia;
+            if bravia.supports(ENDPOINT, "setTextForm", "1.1").await?
+                && bravia.supports("encryption", "getPublicKey", "1.0").await?
+            {
+                return self.set_text_form_encrypted(text).await;
+            }
+        }
+        self.set_text_form(text.to_string(), None, None).await
+    }
+
     /// Provides the function to input text on the field of the software keyboard.
     ///
     /// # Arguments
     /// * `text` - Text data encoded by UTF8. If `enc_key` is set, this must be encrypted.
     /// * `enc_key` - Encryption key encrypted by the public key.\
-    /// The default value is `None`, which means the data is not encrypted.
+    ///   The default value is `None`, which means the data is not encrypted.
     ///     * Not supported with API version 1.0
     /// * `version` - API version.
     ///
@@ -208,8 +724,8 @@ impl<'a> AppControlService<'a> {
     /// Generic
     pub async fn set_text_form(
         &self,
-        text: String,
-        enc_key: Option<String>,
+        text: impl Into<String>,
+        enc_key: Option<&str>,
         version: Option<&str>,
     ) -> Result<()> {
         let params = if let Some(version) = version {
@@ -218,13 +734,13 @@ impl<'a> AppControlService<'a> {
                 if let Some(enc_key) = enc_key {
                     map.insert(String::from("encKey"), Value::from(enc_key));
                 }
-                map.insert(String::from("text"), Value::from(text));
+                map.insert(String::from("text"), Value::from(text.into()));
                 Value::from(map)
             } else {
-                Value::from(text)
+                Value::from(text.into())
             }
         } else {
-            Value::from(text)
+            Value::from(text.into())
         };
 
         let body = RequestBodyBuilder::default()
@@ -237,7 +753,7 @@ impl<'a> AppControlService<'a> {
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
-            .make(self.0)
+            .make(&self.0)
             .await?;
         Ok(())
     }
@@ -255,7 +771,7 @@ impl<'a> AppControlService<'a> {
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
-            .make(self.0)
+            .make(&self.0)
             .await?;
         Ok(())
     }
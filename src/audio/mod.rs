@@ -1,70 +1,385 @@
 //! APIs that are related to audio functions like volume, sound effects and so on.
 
-use crate::{error::Result, Bravia, RequestBodyBuilder, RequestBuilder};
+use crate::{
+    error::{Error, Result},
+    ircc::RemoteKey,
+    lenient, runtime, Bravia, RequestBodyBuilder, RequestBuilder, ServiceContext,
+};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
+use std::time::Duration;
 
 const ENDPOINT: &str = "audio";
+// Time between two key presses sent to an audio system, the receivers drop faster presses.
+const AUDIO_SYSTEM_KEY_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Selected speakers or terminals, value of the `outputTerminal` target.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum OutputTerminal {
+    /// Audio is output from the speaker.
+    Speaker,
+    /// Audio is output from the speaker and HDMI.
+    SpeakerHdmi,
+    /// Audio is output from HDMI.
+    Hdmi,
+    /// Audio is output from HDMI or digital audio output.
+    AudioSystem,
+}
+
+impl OutputTerminal {
+    /// Value used by the server, like `speaker_hdmi`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Speaker => "speaker",
+            Self::SpeakerHdmi => "speaker_hdmi",
+            Self::Hdmi => "hdmi",
+            Self::AudioSystem => "audioSystem",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "speaker" => Some(Self::Speaker),
+            "speaker_hdmi" => Some(Self::SpeakerHdmi),
+            "hdmi" => Some(Self::Hdmi),
+            "audioSystem" => Some(Self::AudioSystem),
+            _ => None,
+        }
+    }
+}
 
 /// Target name.
 /// * `outputTerminal` - Selecting speakers or terminals to output sound.
 pub type SoundTarget = String;
 
+/// Sound setting, as read by [get_sound_settings](AudioService::get_sound_settings).
+///
+/// # Examples
+/// ```
+/// # use bravia_api::audio::{OutputTerminal, SoundSettings};
+/// let settings = SoundSettings::new("outputTerminal".into(), "hdmi");
+/// assert_eq!(settings, SoundSettings::OutputTerminal(OutputTerminal::Hdmi));
+/// assert_eq!(settings.value(), "hdmi");
+/// ```
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
-pub struct SoundSettings {
-    pub target: SoundTarget,
-    /// Current value of target.\
-    /// If `target` is `outputTerminal`:
-    /// * `speaker` - Audio is output from the speaker.
-    /// * `speaker_hdmi` - Audio is output from the speaker and HDMI.
-    /// * `hdmi` - Audio is output from HDMI.
-    /// * `audioSystem` - Audio is output from HDMI or digital audio output.
-    #[serde(alias = "currentValue")]
-    pub value: String,
+#[serde(from = "RawSetting", into = "RawSetting")]
+pub enum SoundSettings {
+    OutputTerminal(OutputTerminal),
+    /// Target or value that doesn't match the other variants.
+    Other {
+        target: SoundTarget,
+        value: String,
+    },
 }
 
 impl SoundSettings {
-    pub fn new(target: SoundTarget, value: String) -> Self {
-        Self { target, value }
+    /// Creates the setting from the names used by the server,
+    /// falling back to [Other](Self::Other) for unknown targets or values.
+    pub fn new(target: SoundTarget, value: impl Into<String>) -> Self {
+        let value = value.into();
+        match (target.as_str(), OutputTerminal::parse(&value)) {
+            ("outputTerminal", Some(terminal)) => Self::OutputTerminal(terminal),
+            _ => Self::Other { target, value },
+        }
+    }
+
+    /// Target name, like `outputTerminal`.
+    pub fn target(&self) -> &str {
+        match self {
+            Self::OutputTerminal(_) => "outputTerminal",
+            Self::Other { target, .. } => target,
+        }
+    }
+
+    /// Value of the target, as used by the server.
+    pub fn value(&self) -> String {
+        match self {
+            Self::OutputTerminal(terminal) => terminal.as_str().to_string(),
+            Self::Other { value, .. } => value.clone(),
+        }
+    }
+}
+
+impl From<RawSetting> for SoundSettings {
+    fn from(setting: RawSetting) -> Self {
+        Self::new(setting.target, setting.value)
+    }
+}
+
+impl From<SoundSettings> for RawSetting {
+    fn from(setting: SoundSettings) -> Self {
+        Self {
+            target: setting.target().to_string(),
+            value: setting.value(),
+        }
+    }
+}
+
+/// Display position, value of the `tvPosition` target.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum TvPosition {
+    /// Provides the best sound quality when you place the display on a TV stand.
+    TableTop,
+    /// Provides the best sound quality when you hang the display on a wall.
+    WallMount,
+}
+
+impl TvPosition {
+    /// Value used by the server, like `tableTop`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::TableTop => "tableTop",
+            Self::WallMount => "wallMount",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "tableTop" => Some(Self::TableTop),
+            "wallMount" => Some(Self::WallMount),
+            _ => None,
+        }
+    }
+}
+
+/// Phase polarity of the subwoofer, value of the `subwooferPhase` target.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum SubwooferPhase {
+    Normal,
+    Reverse,
+}
+
+impl SubwooferPhase {
+    /// Value used by the server, like `normal`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Normal => "normal",
+            Self::Reverse => "reverse",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "normal" => Some(Self::Normal),
+            "reverse" => Some(Self::Reverse),
+            _ => None,
+        }
+    }
+}
+
+/// Level of the subwoofer speaker, from 0 to [MAX](Self::MAX).
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SubwooferLevel(u8);
+
+impl SubwooferLevel {
+    pub const MAX: u8 = 24;
+
+    /// # Errors
+    /// [InvalidParameter](Error::InvalidParameter) if `level` is greater than [MAX](Self::MAX).
+    pub fn new(level: u8) -> Result<Self> {
+        if level > Self::MAX {
+            return Err(Error::InvalidParameter(format!(
+                "Subwoofer level {} is greater than {}",
+                level,
+                Self::MAX
+            )));
+        }
+        Ok(Self(level))
+    }
+
+    pub fn get(&self) -> u8 {
+        self.0
+    }
+}
+
+/// Cut off frequency of the wireless subwoofer, from 0 to [MAX](Self::MAX).
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SubwooferFreq(u8);
+
+impl SubwooferFreq {
+    pub const MAX: u8 = 30;
+
+    /// # Errors
+    /// [InvalidParameter](Error::InvalidParameter) if `freq` is greater than [MAX](Self::MAX).
+    pub fn new(freq: u8) -> Result<Self> {
+        if freq > Self::MAX {
+            return Err(Error::InvalidParameter(format!(
+                "Subwoofer frequency {} is greater than {}",
+                freq,
+                Self::MAX
+            )));
+        }
+        Ok(Self(freq))
+    }
+
+    pub fn get(&self) -> u8 {
+        self.0
     }
 }
 
 /// Target name. (UI setting target)
 /// * `tvPosition` - Sets the sound according to the display position.
 /// * `subwooferLevel` - Sets the level of the Subwoofer speaker.
-///     Note that the range and step values vary depending on the device.
+///   Note that the range and step values vary depending on the device.
 /// * `subwooferFreq` - Adjusts the cut off frequency of the Wireless Subwoofer.
-///     All frequencies below the cut off frequency are output to the Wireless Subwoofer instead of the display speakers.
+///   All frequencies below the cut off frequency are output to the Wireless Subwoofer instead of the display speakers.
 /// * `subwooferPhase` - Sets the phase polarity of the subwoofer.
 /// * `subwooferPower` - Sets the power control method of the Wireless Subwoofer.
 pub type SpeakerTarget = String;
 
+/// Speaker setting, as read by [get_speaker_settings](AudioService::get_speaker_settings).
+///
+/// # Examples
+/// ```
+/// # use bravia_api::audio::{SpeakerSettings, SubwooferLevel};
+/// let settings = SpeakerSettings::new("subwooferLevel".into(), "12");
+/// assert_eq!(settings, SpeakerSettings::SubwooferLevel(SubwooferLevel::new(12).unwrap()));
+///
+/// // Values out of the documented range are kept as they are
+/// let settings = SpeakerSettings::new("subwooferLevel".into(), "40");
+/// assert!(matches!(settings, SpeakerSettings::Other { .. }));
+/// ```
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
-pub struct SpeakerSettings {
-    pub target: SpeakerTarget,
-    /// Target value.
-    /// * If `target` is `tvPosition`
-    ///     * `tableTop` - Provides the best sound quality when you place the display on a TV stand.
-    ///     * `wallMount` - Provides the best sound quality when you hang the display on a wall.
-    /// * If `target` is `subwooferLevel`
-    ///     * `0` - Minimum value.
-    ///     * `24` - Maximum value.
-    /// * If `target` is `subwooferFreq`
-    ///     * `0` - Minimum value.
-    ///     * `30` - Maximum value.
-    /// * If `target` is `subwooferPhase`
-    ///     * `normal` - normal.
-    ///     * `reverse` - reverse.
-    /// * If `target` is `subwooferPower`
-    ///     * `on` - on.
-    ///     * `off` - off.
+#[serde(from = "RawSetting", into = "RawSetting")]
+pub enum SpeakerSettings {
+    TvPosition(TvPosition),
+    SubwooferLevel(SubwooferLevel),
+    SubwooferFreq(SubwooferFreq),
+    SubwooferPhase(SubwooferPhase),
+    /// `true` if the wireless subwoofer is powered on.
+    SubwooferPower(bool),
+    /// Target or value that doesn't match the other variants.
+    Other {
+        target: SpeakerTarget,
+        value: String,
+    },
+}
+
+impl SpeakerSettings {
+    /// Creates the setting from the names used by the server,
+    /// falling back to [Other](Self::Other) for unknown targets or values.
+    pub fn new(target: SpeakerTarget, value: impl Into<String>) -> Self {
+        let value = value.into();
+        let setting = match target.as_str() {
+            "tvPosition" => TvPosition::parse(&value).map(Self::TvPosition),
+            "subwooferLevel" => value
+                .parse()
+                .ok()
+                .and_then(|level| SubwooferLevel::new(level).ok())
+                .map(Self::SubwooferLevel),
+            "subwooferFreq" => value
+                .parse()
+                .ok()
+                .and_then(|freq| SubwooferFreq::new(freq).ok())
+                .map(Self::SubwooferFreq),
+            "subwooferPhase" => SubwooferPhase::parse(&value).map(Self::SubwooferPhase),
+            "subwooferPower" => match value.as_str() {
+                "on" => Some(Self::SubwooferPower(true)),
+                "off" => Some(Self::SubwooferPower(false)),
+                _ => None,
+            },
+            _ => None,
+        };
+        setting.unwrap_or(Self::Other { target, value })
+    }
+
+    /// Target name, like `tvPosition`.
+    pub fn target(&self) -> &str {
+        match self {
+            Self::TvPosition(_) => "tvPosition",
+            Self::SubwooferLevel(_) => "subwooferLevel",
+            Self::SubwooferFreq(_) => "subwooferFreq",
+            Self::SubwooferPhase(_) => "subwooferPhase",
+            Self::SubwooferPower(_) => "subwooferPower",
+            Self::Other { target, .. } => target,
+        }
+    }
+
+    /// Value of the target, as used by the server.
+    pub fn value(&self) -> String {
+        match self {
+            Self::TvPosition(position) => position.as_str().to_string(),
+            Self::SubwooferLevel(level) => level.get().to_string(),
+            Self::SubwooferFreq(freq) => freq.get().to_string(),
+            Self::SubwooferPhase(phase) => phase.as_str().to_string(),
+            Self::SubwooferPower(true) => String::from("on"),
+            Self::SubwooferPower(false) => String::from("off"),
+            Self::Other { value, .. } => value.clone(),
+        }
+    }
+}
+
+impl From<RawSetting> for SpeakerSettings {
+    fn from(setting: RawSetting) -> Self {
+        Self::new(setting.target, setting.value)
+    }
+}
+
+impl From<SpeakerSettings> for RawSetting {
+    fn from(setting: SpeakerSettings) -> Self {
+        Self {
+            target: setting.target().to_string(),
+            value: setting.value(),
+        }
+    }
+}
+
+// Wire format of the sound and speaker settings.
+#[derive(Serialize, Deserialize)]
+struct RawSetting {
+    target: String,
     #[serde(alias = "currentValue")]
+    value: String,
+}
+
+/// Band of the custom equalizer.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum EqualizerBand {
+    #[serde(rename = "100HzBand")]
+    Band100Hz,
+    #[serde(rename = "330HzBand")]
+    Band330Hz,
+    #[serde(rename = "1kHzBand")]
+    Band1kHz,
+    #[serde(rename = "3.3kHzBand")]
+    Band3_3kHz,
+    #[serde(rename = "10kHzBand")]
+    Band10kHz,
+}
+
+/// Range of the values accepted by an equalizer band.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EqualizerCandidate {
+    /// Minimum value.
+    #[serde(deserialize_with = "lenient::number")]
+    pub min: i32,
+    /// Maximum value.
+    #[serde(deserialize_with = "lenient::number")]
+    pub max: i32,
+    /// Step between two values.
+    #[serde(deserialize_with = "lenient::number")]
+    pub step: i32,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EqualizerSettings {
+    pub target: EqualizerBand,
+    /// Gain of the band, within the range of one of the [candidates](Self::candidate).
+    #[serde(alias = "currentValue", deserialize_with = "lenient::string")]
     pub value: String,
+    /// Ranges supported by the band, only returned by
+    /// [get_custom_equalizer_settings](AudioService::get_custom_equalizer_settings).
+    #[serde(default, skip_serializing)]
+    pub candidate: Vec<EqualizerCandidate>,
 }
 
-impl SpeakerSettings {
-    pub fn new(target: SpeakerTarget, value: String) -> Self {
-        Self { target, value }
+impl EqualizerSettings {
+    pub fn new(target: EqualizerBand, value: impl Into<String>) -> Self {
+        Self {
+            target,
+            value: value.into(),
+            candidate: Vec::new(),
+        }
     }
 }
 
@@ -77,21 +392,40 @@ pub struct VolumeInformation {
     /// * `headphone` - outputs sound to the headphones
     pub target: String,
     /// Current volume.
+    #[serde(deserialize_with = "lenient::number")]
     pub volume: usize,
     /// Current mute status.
+    #[serde(default, deserialize_with = "lenient::bool")]
     pub mute: bool,
     /// Max volume level.
+    #[serde(default, deserialize_with = "lenient::number")]
     pub max_volume: usize,
     /// Min volume level.
+    #[serde(default, deserialize_with = "lenient::number")]
     pub min_volume: usize,
 }
 
+/// Volume change made by [set_effective_volume](AudioService::set_effective_volume).\
+/// Only relative changes are supported, since the level of an external audio system
+/// can't be read through the API.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum VolumeChange {
+    /// Increases the volume by the given step.
+    Up(u32),
+    /// Decreases the volume by the given step.
+    Down(u32),
+    /// Mutes the audio if it's not muted, otherwise unmutes it.
+    ToggleMute,
+}
+
 /// Provides access to audio service APIs.
-pub struct AudioService<'a>(&'a Bravia);
+pub struct AudioService(ServiceContext);
+
+impl_service_options!(AudioService);
 
-impl<'a> AudioService<'a> {
-    pub fn new(bravia: &'a Bravia) -> Self {
-        Self(bravia)
+impl AudioService {
+    pub fn new(bravia: &Bravia) -> Self {
+        Self(ServiceContext::new(bravia))
     }
 
     /// Provides the current settings and supported settings related to the sound configuration items.
@@ -115,13 +449,40 @@ impl<'a> AudioService<'a> {
             .version(Some("1.1"))
             .params(Value::from(params))
             .build()?;
-        let req = RequestBuilder::default()
+        RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .has_result()
-            .make(self.0)
-            .await?;
-        Ok(serde_json::from_value(req)?)
+            .make_as(&self.0)
+            .await
+    }
+
+    /// Provides the current settings and supported settings of the custom equalizer bands.
+    ///
+    /// # Arguments
+    /// `target`
+    /// * `None` - This indicates the settings of all bands.
+    ///
+    /// # Authentication Level
+    /// None
+    pub async fn get_custom_equalizer_settings(
+        &self,
+        target: Option<EqualizerBand>,
+    ) -> Result<Vec<EqualizerSettings>> {
+        let mut params = Map::new();
+        params.insert(String::from("target"), serde_json::to_value(target)?);
+
+        let body = RequestBodyBuilder::default()
+            .id(74)
+            .method("getCustomEqualizerSettings")
+            .params(Value::from(params))
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .has_result()
+            .make_as(&self.0)
+            .await
     }
 
     /// Provides current settings and supported settings related to speaker configuration items.
@@ -144,13 +505,12 @@ impl<'a> AudioService<'a> {
             .method("getSpeakerSettings")
             .params(Value::from(params))
             .build()?;
-        let req = RequestBuilder::default()
+        RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .has_result()
-            .make(self.0)
-            .await?;
-        Ok(serde_json::from_value(req)?)
+            .make_as(&self.0)
+            .await
     }
 
     /// Provides information about the sound volume (and mute status) of the device.
@@ -162,13 +522,12 @@ impl<'a> AudioService<'a> {
             .id(33)
             .method("getVolumeInformation")
             .build()?;
-        let req = RequestBuilder::default()
+        RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .has_result()
-            .make(self.0)
-            .await?;
-        Ok(serde_json::from_value(req)?)
+            .make_as(&self.0)
+            .await
     }
 
     /// Provides the function to change the audio mute status.
@@ -192,7 +551,7 @@ impl<'a> AudioService<'a> {
             .body(body)
             .is_protected()
             .has_result()
-            .make(self.0)
+            .make(&self.0)
             .await?;
         Ok(())
     }
@@ -202,7 +561,7 @@ impl<'a> AudioService<'a> {
     /// # Arguments
     /// * `target` -  Output target of the sound. The following values are defined:
     ///     * `None` - outputs sound to all output equipment of the device.
-    ///     If the mute information of all outputs is the same, this value is set.
+    ///       If the mute information of all outputs is the same, this value is set.
     ///     * `speaker` - outputs sound to the speaker(s).
     ///     * `headphone` - outputs sound to the headphones.
     /// * `volume` - Volume level to set. The following formats are applied:
@@ -220,6 +579,9 @@ impl<'a> AudioService<'a> {
     /// # Authentication Level
     /// Generic
     ///
+    /// # Note
+    /// The relative changes are never retried, regardless of the [RetryPolicy](crate::retry::RetryPolicy).
+    ///
     /// # Examples
     /// ```no_run
     /// # use bravia_api::{Bravia, error::Result};
@@ -231,9 +593,9 @@ impl<'a> AudioService<'a> {
     ///     if element.target == "speaker" && element.volume > 10 {
     ///         bravia.audio()
     ///             .set_audio_volume(
-    ///                 Some("speaker".to_string()),
-    ///                 "10".to_string(),
-    ///                 Some("on".to_string()),
+    ///                 Some("speaker"),
+    ///                 "10",
+    ///                 Some("on"),
     ///                 None
     ///             )
     ///             .await?
@@ -244,11 +606,14 @@ impl<'a> AudioService<'a> {
     /// ```
     pub async fn set_audio_volume(
         &self,
-        target: Option<String>,
-        volume: String,
-        ui: Option<String>,
+        target: Option<&str>,
+        volume: impl Into<String>,
+        ui: Option<&str>,
         version: Option<&str>,
     ) -> Result<()> {
+        let volume = volume.into();
+        // Sending a relative change twice would change the volume twice
+        let relative = volume.starts_with(['+', '-']);
         let mut params = Map::new();
         params.insert(String::from("target"), Value::from(target));
         params.insert(String::from("volume"), Value::from(volume));
@@ -264,34 +629,68 @@ impl<'a> AudioService<'a> {
             .version(version)
             .params(Value::from(params))
             .build()?;
+        let mut req = RequestBuilder::default();
+        req.endpoint(ENDPOINT).body(body).is_protected();
+        if relative {
+            req.no_retry();
+        }
+        req.make(&self.0).await?;
+        Ok(())
+    }
+
+    /// Provides the function to change the settings related to sound setting items.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn set_sound_settings(
+        &self,
+        settings: impl IntoIterator<Item = SoundSettings>,
+    ) -> Result<()> {
+        let mut params = Map::new();
+        params.insert(
+            String::from("settings"),
+            serde_json::to_value(settings.into_iter().collect::<Vec<_>>())?,
+        );
+
+        let body = RequestBodyBuilder::default()
+            .id(5)
+            .method("setSoundSettings")
+            .version(Some("1.1"))
+            .params(Value::from(params))
+            .build()?;
         RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
-            .make(self.0)
+            .make(&self.0)
             .await?;
         Ok(())
     }
 
-    /// Provides the function to change the settings related to sound setting items.
+    /// Provides the function to change the gain of the custom equalizer bands.
     ///
     /// # Authentication Level
     /// Generic
-    pub async fn set_sound_settings(&self, settings: Vec<SoundSettings>) -> Result<()> {
+    pub async fn set_custom_equalizer_settings(
+        &self,
+        settings: impl IntoIterator<Item = EqualizerSettings>,
+    ) -> Result<()> {
         let mut params = Map::new();
-        params.insert(String::from("settings"), serde_json::to_value(settings)?);
+        params.insert(
+            String::from("settings"),
+            serde_json::to_value(settings.into_iter().collect::<Vec<_>>())?,
+        );
 
         let body = RequestBodyBuilder::default()
-            .id(5)
-            .method("setSoundSettings")
-            .version(Some("1.1"))
+            .id(75)
+            .method("setCustomEqualizerSettings")
             .params(Value::from(params))
             .build()?;
         RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
-            .make(self.0)
+            .make(&self.0)
             .await?;
         Ok(())
     }
@@ -300,9 +699,15 @@ impl<'a> AudioService<'a> {
     ///
     /// # Authentication Level
     /// Generic
-    pub async fn set_speaker_settings(&self, settings: Vec<SpeakerSettings>) -> Result<()> {
+    pub async fn set_speaker_settings(
+        &self,
+        settings: impl IntoIterator<Item = SpeakerSettings>,
+    ) -> Result<()> {
         let mut params = Map::new();
-        params.insert(String::from("settings"), serde_json::to_value(settings)?);
+        params.insert(
+            String::from("settings"),
+            serde_json::to_value(settings.into_iter().collect::<Vec<_>>())?,
+        );
 
         let body = RequestBodyBuilder::default()
             .id(62)
@@ -313,8 +718,159 @@ impl<'a> AudioService<'a> {
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
-            .make(self.0)
+            .make(&self.0)
+            .await?;
+        Ok(())
+    }
+
+    /// Increases the volume of all the outputs by `step`.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn volume_up(&self, step: u32) -> Result<()> {
+        self.set_audio_volume(Some(""), format!("+{}", step), None, None)
+            .await
+    }
+
+    /// Decreases the volume of all the outputs by `step`.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn volume_down(&self, step: u32) -> Result<()> {
+        self.set_audio_volume(Some(""), format!("-{}", step), None, None)
+            .await
+    }
+
+    /// Mutes the audio if it's not muted, otherwise unmutes it.\
+    /// The current status is read from the speaker output, or from the first output
+    /// if the device has no speaker.
+    ///
+    /// Returns the new mute status.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn toggle_mute(&self) -> Result<bool> {
+        let outputs = self.get_volume_information().await?;
+        let output = outputs
+            .iter()
+            .find(|output| output.target == "speaker")
+            .or(outputs.first())
+            .ok_or(Error::MissingValue("volumeInformation"))?;
+        let mute = !output.mute;
+        self.set_audio_mute(mute).await?;
+        Ok(mute)
+    }
+
+    /// Changes the volume of the output actually in use.\
+    /// When the [outputTerminal](OutputTerminal) sound setting routes the audio to an
+    /// external audio system, the volume and mute keys of the remote control are sent
+    /// through [IRCC](crate::ircc::IrccService), one key press for each step, 300ms apart,
+    /// otherwise the volume of the display is changed with [setAudioVolume](Self::set_audio_volume).\
+    /// The volume can't be set to an absolute level, see [VolumeChange].
+    ///
+    /// # Authentication Level
+    /// Generic
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{audio::VolumeChange, Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// bravia.audio().set_effective_volume(VolumeChange::Up(2)).await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn set_effective_volume(&self, change: VolumeChange) -> Result<()> {
+        let settings = self
+            .get_sound_settings(Some(String::from("outputTerminal")))
             .await?;
+        if !settings.contains(&SoundSettings::OutputTerminal(OutputTerminal::AudioSystem)) {
+            return match change {
+                VolumeChange::Up(step) => self.volume_up(step).await,
+                VolumeChange::Down(step) => self.volume_down(step).await,
+                VolumeChange::ToggleMute => self.toggle_mute().await.map(|_| ()),
+            };
+        }
+
+        let ircc = self.0.bravia.ircc().with_options(self.0.options.clone());
+        let (key, presses) = match change {
+            VolumeChange::Up(step) => (RemoteKey::VolumeUp, step),
+            VolumeChange::Down(step) => (RemoteKey::VolumeDown, step),
+            VolumeChange::ToggleMute => (RemoteKey::Mute, 1),
+        };
+        for press in 0..presses {
+            if press > 0 {
+                runtime::sleep(AUDIO_SYSTEM_KEY_INTERVAL).await;
+            }
+            ircc.send(key).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Lowers the volume and brings it back later, like while a voice assistant is speaking.\
+/// The mute status of the outputs is never changed.
+///
+/// # Examples
+/// ```no_run
+/// # use bravia_api::{audio::VolumeController, Bravia, error::Result};
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+/// let mut volume = VolumeController::new(bravia.audio());
+/// volume.duck_to(5).await?;
+/// // Speak
+/// volume.restore().await?;
+/// #    Ok(())
+/// # }
+/// ```
+pub struct VolumeController {
+    audio: AudioService,
+    // Outputs that were lowered, with their previous volume
+    saved: Option<Vec<(String, usize)>>,
+}
+
+impl VolumeController {
+    pub fn new(audio: AudioService) -> Self {
+        Self { audio, saved: None }
+    }
+
+    /// Checks if the volume was lowered and not restored yet.
+    pub fn is_ducked(&self) -> bool {
+        self.saved.is_some()
+    }
+
+    /// Lowers the volume of the outputs louder than `volume` to `volume`.\
+    /// Calling it again before [restore](Self::restore) keeps the volume saved the first time.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn duck_to(&mut self, volume: usize) -> Result<()> {
+        let outputs = self.audio.get_volume_information().await?;
+        let saved = self.saved.get_or_insert_with(Vec::new);
+        for output in outputs.into_iter().filter(|output| output.volume > volume) {
+            self.audio
+                .set_audio_volume(Some(&output.target), volume.to_string(), None, None)
+                .await?;
+            if !saved.iter().any(|(target, _)| *target == output.target) {
+                saved.push((output.target, output.volume));
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets the outputs back to the volume they had before [duck_to](Self::duck_to) was called.\
+    /// Does nothing if the volume wasn't lowered.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn restore(&mut self) -> Result<()> {
+        for (target, volume) in self.saved.take().unwrap_or_default() {
+            self.audio
+                .set_audio_volume(Some(&target), volume.to_string(), None, None)
+                .await?;
+        }
         Ok(())
     }
 }
@@ -14,14 +14,35 @@
 //! The client sets the URI of the source to the `uri` parameter of [getContentList](AvContentService::get_content_list)
 //! and calls this API to get the content information or browse the content.
 
-use crate::{error::Result, Bravia, RequestBodyBuilder, RequestBuilder};
+use crate::{
+    error::{Error, Result},
+    lenient, runtime, Bravia, RequestBodyBuilder, RequestBuilder, ServiceContext,
+};
+use futures_core::Stream;
+use futures_util::stream;
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
-use std::collections::HashMap;
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt,
+    str::FromStr,
+    time::Duration,
+};
 
 const ENDPOINT: &str = "avContent";
 
+// Number of channels requested at a time by the channel helpers.
+const CHANNEL_PAGE_SIZE: u32 = 50;
+
+// Largest number of contents requested at a time by get_all_contents.
+const CONTENT_PAGE_MAX: u32 = 200;
+
+// Checks of the playing content done after selecting an input, and the time between them.
+const SELECT_VERIFY_ATTEMPTS: u32 = 3;
+const SELECT_VERIFY_INTERVAL: Duration = Duration::from_millis(500);
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Content {
     /// URI to identify the content.
     pub uri: String,
@@ -31,8 +52,32 @@ pub struct Content {
     /// Index of the list.\
     /// This starts with `stIdx` that is indicated in the request.
     /// When this value is -1, this indicates that the content itself is specified by the URI in the request parameter.
-    #[serde(default)]
+    #[serde(default, deserialize_with = "lenient::number")]
     pub index: i32,
+    /// Channel number shown to the user, like `021`. Only set for TV channels.
+    #[serde(default)]
+    pub disp_num: Option<String>,
+    /// Channel number assigned by the broadcaster, before the user edited it. Only set for TV channels.
+    #[serde(default)]
+    pub original_disp_num: Option<String>,
+    /// DVB triplet of the channel (original network ID, transport stream ID and service ID),
+    /// like `9018.4161.1025`. Only set for TV channels.
+    #[serde(default)]
+    pub triplet_str: Option<String>,
+    /// Media type of the channel.
+    /// * `tv` - TV channel.
+    /// * `radio` - Radio channel.
+    #[serde(default)]
+    pub program_media_type: Option<String>,
+    /// Number of the remote control key that selects the channel.
+    #[serde(default, deserialize_with = "lenient::optional_number")]
+    pub direct_remote_num: Option<i32>,
+    /// The content is protected from deletion.
+    #[serde(default, deserialize_with = "lenient::flag")]
+    pub is_protected: Option<bool>,
+    /// The content has already been played.
+    #[serde(default, deserialize_with = "lenient::flag")]
+    pub is_already_played: Option<bool>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -63,10 +108,13 @@ pub struct ExternalInputStatus {
     /// * `meta:playbackdevice` - Playback-type CEC device is connected.
     /// * `meta:tunerdevice` - Tuner-type CEC device is connected.
     /// * `meta:wifidisplay` - WiFi Display input
+    #[serde(default)]
     pub icon: String,
     /// Input connection status.
+    #[serde(default, deserialize_with = "lenient::bool")]
     pub connection: bool,
     /// Label name of the input set by the user.
+    #[serde(default)]
     pub label: String,
     /// Name of input.
     pub title: String,
@@ -78,29 +126,308 @@ pub struct ExternalInputStatus {
     /// * `true` - signal is detected.
     /// * `false` - signal is not detected.
     /// * `None` - unknown (Default on version 1.0)
-    pub status: Option<String>,
+    #[serde(default, deserialize_with = "lenient::flag")]
+    pub status: Option<bool>,
+}
+
+impl ExternalInputStatus {
+    /// Parsed [uri](Self::uri) of the input, `None` if it isn't a known input URI.
+    pub fn input_uri(&self) -> Option<InputUri> {
+        self.uri.parse().ok()
+    }
+}
+
+/// URI of an external input, like `extInput:hdmi?port=1`.
+///
+/// # Examples
+/// ```
+/// # use bravia_api::{av_content::InputUri, error::Result};
+/// # fn main() -> Result<()> {
+/// let input: InputUri = "extInput:cec?type=player&port=3&logicalAddr=4".parse()?;
+/// assert_eq!(
+///     InputUri::Cec {
+///         kind: String::from("player"),
+///         port: 3,
+///         logical_addr: Some(4)
+///     },
+///     input
+/// );
+/// assert_eq!("extInput:hdmi?port=2", InputUri::Hdmi(2).to_string());
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum InputUri {
+    Hdmi(u32),
+    Component(u32),
+    Composite(u32),
+    Scart(u32),
+    /// Wi-Fi Display (screen mirroring) input.
+    Widi(u32),
+    /// Device connected through HDMI-CEC.
+    Cec {
+        /// Type of the device, like `player`, `recorder`, `tuner` or `audioSystem`.
+        kind: String,
+        /// HDMI port of the device.
+        port: u32,
+        /// Logical address of the device on the CEC bus.
+        logical_addr: Option<u32>,
+    },
+}
+
+impl fmt::Display for InputUri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (kind, port) = match self {
+            Self::Hdmi(port) => ("hdmi", port),
+            Self::Component(port) => ("component", port),
+            Self::Composite(port) => ("composite", port),
+            Self::Scart(port) => ("scart", port),
+            Self::Widi(port) => ("widi", port),
+            Self::Cec {
+                kind,
+                port,
+                logical_addr,
+            } => {
+                write!(f, "extInput:cec?type={}&port={}", kind, port)?;
+                if let Some(logical_addr) = logical_addr {
+                    write!(f, "&logicalAddr={}", logical_addr)?;
+                }
+                return Ok(());
+            }
+        };
+        write!(f, "extInput:{}?port={}", kind, port)
+    }
+}
+
+impl FromStr for InputUri {
+    type Err = Error;
+
+    fn from_str(uri: &str) -> Result<Self> {
+        let invalid = || Error::InvalidParameter(format!("invalid input URI {:?}", uri));
+        let (kind, query) = uri
+            .strip_prefix("extInput:")
+            .and_then(|input| input.split_once('?'))
+            .ok_or_else(invalid)?;
+        let param = |name: &str| {
+            query
+                .split('&')
+                .filter_map(|pair| pair.split_once('='))
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value)
+        };
+        let number = |name: &str| param(name).map(str::parse::<u32>).transpose();
+        let port = number("port").ok().flatten().ok_or_else(invalid)?;
+        match kind {
+            "hdmi" => Ok(Self::Hdmi(port)),
+            "component" => Ok(Self::Component(port)),
+            "composite" => Ok(Self::Composite(port)),
+            "scart" => Ok(Self::Scart(port)),
+            "widi" => Ok(Self::Widi(port)),
+            "cec" => Ok(Self::Cec {
+                kind: param("type").ok_or_else(invalid)?.to_string(),
+                port,
+                logical_addr: number("logicalAddr").map_err(|_| invalid())?,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl From<InputUri> for String {
+    fn from(input: InputUri) -> Self {
+        input.to_string()
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParentalRatingSettings {
+    /// Age limit, programs rated for older viewers are locked.\
+    /// `-1` means that the age rating is not used.
+    #[serde(deserialize_with = "lenient::number")]
+    pub rating_type_age: i32,
+    /// Rating level of the Sony rating system, if used.
+    #[serde(default)]
+    pub rating_type_sony: Option<String>,
+    /// Country whose rating system is used, as an ISO 3166-1 alpha-3 code (ex. `usa`).
+    pub rating_country: String,
+    /// Locked ratings of the US TV Parental Guidelines, like `TV-MA`.
+    #[serde(default, rename = "ratingCustomTypeTV")]
+    pub rating_custom_type_tv: Vec<String>,
+    /// Locked rating of the MPAA film rating system, like `R`.
+    #[serde(default)]
+    pub rating_custom_type_mpaa: Option<String>,
+    /// Locked rating of the Canadian English rating system.
+    #[serde(default)]
+    pub rating_custom_type_ca_english: Option<String>,
+    /// Locked rating of the Canadian French rating system.
+    #[serde(default)]
+    pub rating_custom_type_ca_french: Option<String>,
+    /// Locks the programs without a rating.
+    #[serde(deserialize_with = "lenient::bool")]
+    pub unrated_lock: bool,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PlayingContentInfo {
     /// Source of the content.
+    #[serde(default)]
     pub source: String,
     /// Title of this content to be recognized by the user.
     ///
     /// # Note
     /// Use the [getCurrentExternalInputStatus](AvContentService::get_current_external_input_status)
     ///  method to get the label name that a user sets via the UI setting.
+    #[serde(default)]
     pub title: String,
     /// URI to identify the content.
     pub uri: String,
+    /// Title of the program being broadcast. Only set for TV channels.
+    #[serde(default)]
+    pub program_title: Option<String>,
+    /// Start time of the program in ISO 8601 format, like `2018-10-03T20:00:00+0100`.
+    /// Only set for TV channels.
+    #[serde(default)]
+    pub start_date_time: Option<String>,
+    /// Duration of the program in seconds. Only set for TV channels.
+    #[serde(default, deserialize_with = "lenient::optional_number")]
+    pub duration_sec: Option<u64>,
+    /// Channel number shown to the user, like `021`. Only set for TV channels.
+    #[serde(default)]
+    pub disp_num: Option<String>,
+    /// Channel number assigned by the broadcaster. Only set for TV channels.
+    #[serde(default)]
+    pub original_disp_num: Option<String>,
+    /// DVB triplet of the channel, like `9018.4161.1025`. Only set for TV channels.
+    #[serde(default)]
+    pub triplet_str: Option<String>,
+    /// Media type of the content, like `tv` or `radio`.
+    #[serde(default)]
+    pub media_type: Option<String>,
+    /// Media type of the program, like `tv` or `radio`.
+    #[serde(default)]
+    pub program_media_type: Option<String>,
+}
+
+impl PlayingContentInfo {
+    /// Parsed [uri](Self::uri) of the content, `None` if it isn't an external input.
+    pub fn input_uri(&self) -> Option<InputUri> {
+        self.uri.parse().ok()
+    }
+
+    /// Duration of the program, if known.
+    pub fn duration(&self) -> Option<Duration> {
+        self.duration_sec.map(Duration::from_secs)
+    }
+
+    /// Start time of the program, if known and valid.\
+    /// Requires the `chrono` feature.
+    #[cfg(feature = "chrono")]
+    pub fn start_time(&self) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+        let start = self.start_date_time.as_deref()?;
+        chrono::DateTime::parse_from_str(start, "%Y-%m-%dT%H:%M:%S%z").ok()
+    }
+
+    /// End time of the program, if known and valid.\
+    /// Requires the `chrono` feature.
+    #[cfg(feature = "chrono")]
+    pub fn end_time(&self) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+        let duration = chrono::Duration::seconds(i64::try_from(self.duration_sec?).ok()?);
+        self.start_time()?.checked_add_signed(duration)
+    }
+}
+
+/// Scheme returned by [getSchemeList](AvContentService::get_scheme_list).
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Scheme {
+    /// Scheme name, like `extInput` or `tv`.
+    pub scheme: String,
+    /// Fields returned by some devices that aren't documented.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Source returned by [getSourceList](AvContentService::get_source_list).
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Source {
+    /// URI of the source, like `extInput:hdmi`.
+    pub source: String,
+    /// Fields returned by some devices that aren't documented.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Visibility of a channel, see [setTvContentVisibility](AvContentService::set_tv_content_visibility).\
+/// Each value can be:
+/// * `visible` - The channel is shown.
+/// * `invisible` - The channel is hidden.
+/// * `auto` - The server decides.
+/// * `None` - Not changed.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TvContentVisibility {
+    /// URI of the channel.
+    pub uri: String,
+    /// Visibility in the program guide.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub epg_visibility: Option<String>,
+    /// Visibility when changing channel with the remote control.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_surfing_visibility: Option<String>,
+    /// Visibility in the channel list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<String>,
+}
+
+impl TvContentVisibility {
+    pub fn new(uri: impl Into<String>) -> Self {
+        Self {
+            uri: uri.into(),
+            epg_visibility: None,
+            channel_surfing_visibility: None,
+            visibility: None,
+        }
+    }
 }
 
 /// Provides access to av_content service APIs.
-pub struct AvContentService<'a>(&'a Bravia);
+pub struct AvContentService(ServiceContext);
 
-impl<'a> AvContentService<'a> {
-    pub fn new(bravia: &'a Bravia) -> Self {
-        Self(bravia)
+impl_service_options!(AvContentService);
+
+impl AvContentService {
+    pub fn new(bravia: &Bravia) -> Self {
+        Self(ServiceContext::new(bravia))
+    }
+
+    /// Deletes a content, like a recorded program.
+    ///
+    /// # Arguments
+    /// * `uri` - URI of the content, obtained from [getContentList](AvContentService::get_content_list).
+    ///
+    /// # Authentication Level
+    /// Generic
+    ///
+    /// # Note
+    /// This request is never retried, regardless of the [RetryPolicy](crate::retry::RetryPolicy).
+    pub async fn delete_content(&self, uri: impl Into<String>) -> Result<()> {
+        let mut params = Map::new();
+        params.insert(String::from("uri"), Value::from(uri.into()));
+
+        let body = RequestBodyBuilder::default()
+            .id(18)
+            .method("deleteContent")
+            .params(Value::from(params))
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .no_retry()
+            .make(&self.0)
+            .await?;
+        Ok(())
     }
 
     /// Provides the count of contents in the source.
@@ -120,20 +447,20 @@ impl<'a> AvContentService<'a> {
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
     /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
-    /// let hdmi_count = bravia.av_content().get_content_count("extInput:hdmi".to_string(), None, None, Some("1.1")).await?;
+    /// let hdmi_count = bravia.av_content().get_content_count("extInput:hdmi", None, None, Some("1.1")).await?;
     /// println!("hdmi: {}", hdmi_count);
     /// #    Ok(())
     /// # }
     /// ```
     pub async fn get_content_count(
         &self,
-        source: String,
-        content_type: Option<String>,
-        target: Option<String>,
+        source: impl Into<String>,
+        content_type: Option<&str>,
+        target: Option<&str>,
         version: Option<&str>,
     ) -> Result<usize> {
         let mut params = Map::new();
-        params.insert(String::from("source"), Value::from(source));
+        params.insert(String::from("source"), Value::from(source.into()));
         if let Some(t) = content_type {
             params.insert(String::from("type"), Value::from(t));
         }
@@ -151,15 +478,14 @@ impl<'a> AvContentService<'a> {
             .version(version)
             .params(Value::from(params))
             .build()?;
-        let req = RequestBuilder::default()
+        RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
             .has_result()
             .get("count".into())
-            .make(self.0)
-            .await?;
-        Ok(serde_json::from_value(req)?)
+            .make_as(&self.0)
+            .await
     }
 
     /// Provides the list of contents under the URI.\
@@ -175,7 +501,9 @@ impl<'a> AvContentService<'a> {
     /// * `uri` - URI to identify the content. `None` means all contents are supported by the device.
     /// * `st_idx` - Start index to get list items. The default value is 0.
     /// * `cnt` - Count of the maximum number of items that can be listed, starting from `stIdx`. The
-    /// default value is `50`.
+    ///   default value is `50`.
+    /// * `content_types` - Types of the contents to list, like `tv_analog` or `tv_digital`.
+    ///   `None` means all types.
     ///
     /// # Authentication Level
     /// Private
@@ -186,7 +514,7 @@ impl<'a> AvContentService<'a> {
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
     /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
-    /// let hdmi_list = bravia.av_content().get_content_list(Some("extInput:hdmi".to_string()), None, None).await?;
+    /// let hdmi_list = bravia.av_content().get_content_list(Some("extInput:hdmi"), None, None, None).await?;
     /// for element in hdmi_list {
     ///     println!("{} - {}", element.index, element.title.unwrap());
     /// }
@@ -195,9 +523,10 @@ impl<'a> AvContentService<'a> {
     /// ```
     pub async fn get_content_list(
         &self,
-        uri: Option<String>,
+        uri: Option<&str>,
         st_idx: Option<u32>,
         cnt: Option<u32>,
+        content_types: Option<&[&str]>,
     ) -> Result<Vec<Content>> {
         let mut params = Map::new();
         if let Some(uri) = uri {
@@ -209,6 +538,9 @@ impl<'a> AvContentService<'a> {
         if let Some(cnt) = cnt {
             params.insert(String::from("cnt"), Value::from(cnt));
         }
+        if let Some(content_types) = content_types {
+            params.insert(String::from("type"), Value::from(content_types));
+        }
 
         let body = RequestBodyBuilder::default()
             .id(88)
@@ -216,14 +548,13 @@ impl<'a> AvContentService<'a> {
             .version(Some("1.5"))
             .params(Value::from(params))
             .build()?;
-        let req = RequestBuilder::default()
+        RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
             .has_result()
-            .make(self.0)
-            .await?;
-        Ok(serde_json::from_value(req)?)
+            .make_as(&self.0)
+            .await
     }
 
     /// Provides information on the current status of all external input sources of the device.
@@ -242,37 +573,37 @@ impl<'a> AvContentService<'a> {
             .method("getCurrentExternalInputsStatus")
             .version(version)
             .build()?;
-        let req = RequestBuilder::default()
+        let mut inputs: Vec<ExternalInputStatus> = RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .has_result()
-            .make(self.0)
+            .make_as(&self.0)
             .await?;
-        Ok(serde_json::from_value(req)?)
+
+        if version.is_none() || version == Some("1.0") {
+            // The signal status is only meaningful from version 1.1
+            for input in &mut inputs {
+                input.status = None;
+            }
+        }
+        Ok(inputs)
     }
 
     /// This API provides the list of schemes that the device can handle.
     ///
     /// # Authentication Level
     /// None
-    pub async fn get_scheme_list(&self) -> Result<Vec<String>> {
+    pub async fn get_scheme_list(&self) -> Result<Vec<Scheme>> {
         let body = RequestBodyBuilder::default()
             .id(1)
             .method("getSchemeList")
             .build()?;
-        let req = RequestBuilder::default()
+        RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .has_result()
-            .make(self.0)
-            .await?;
-
-        let vec: Vec<HashMap<String, String>> = serde_json::from_value(req)?;
-        let mut result = Vec::new();
-        for map in vec {
-            result.push(map.into_values().collect());
-        }
-        Ok(result)
+            .make_as(&self.0)
+            .await
     }
 
     /// Provides the list of sources in the scheme.
@@ -282,28 +613,39 @@ impl<'a> AvContentService<'a> {
     ///
     /// # Authentication Level
     /// None
-    pub async fn get_source_list(&self, scheme: String) -> Result<Vec<String>> {
+    pub async fn get_source_list(&self, scheme: impl Into<String>) -> Result<Vec<Source>> {
         let mut params = Map::new();
-        params.insert(String::from("scheme"), Value::from(scheme));
+        params.insert(String::from("scheme"), Value::from(scheme.into()));
 
         let body = RequestBodyBuilder::default()
             .id(1)
             .method("getSourceList")
             .params(Value::from(params))
             .build()?;
-        let req = RequestBuilder::default()
+        RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .has_result()
-            .make(self.0)
-            .await?;
+            .make_as(&self.0)
+            .await
+    }
 
-        let vec: Vec<HashMap<String, String>> = serde_json::from_value(req)?;
-        let mut result = Vec::new();
-        for map in vec {
-            result.push(map.into_values().collect());
-        }
-        Ok(result)
+    /// Provides the current parental rating settings.
+    ///
+    /// # Authentication Level
+    /// Private
+    pub async fn get_parental_rating_settings(&self) -> Result<ParentalRatingSettings> {
+        let body = RequestBodyBuilder::default()
+            .id(16)
+            .method("getParentalRatingSettings")
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .has_result()
+            .make_as(&self.0)
+            .await
     }
 
     /// Provides information of the currently playing content or the currently selected input.
@@ -315,27 +657,27 @@ impl<'a> AvContentService<'a> {
             .id(103)
             .method("getPlayingContentInfo")
             .build()?;
-        let req = RequestBuilder::default()
+        RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
             .has_result()
-            .make(self.0)
-            .await?;
-        Ok(serde_json::from_value(req)?)
+            .make_as(&self.0)
+            .await
     }
 
     /// Provides the function to play content.
     /// With this API, content specified in the request parameter is shown to the user.
     ///
     /// # Arguments
-    /// `uri` - URI obtained from [getContentList](AvContentService::get_content_list) API.
+    /// `uri` - URI obtained from [getContentList](AvContentService::get_content_list) API,
+    /// or an [InputUri].
     ///
     /// # Authentication Level
     /// Generic
-    pub async fn set_play_content(&self, uri: String) -> Result<()> {
+    pub async fn set_play_content(&self, uri: impl Into<String>) -> Result<()> {
         let mut params = Map::new();
-        params.insert(String::from("uri"), Value::from(uri));
+        params.insert(String::from("uri"), Value::from(uri.into()));
 
         let body = RequestBodyBuilder::default()
             .id(101)
@@ -346,8 +688,338 @@ impl<'a> AvContentService<'a> {
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
-            .make(self.0)
+            .make(&self.0)
+            .await?;
+        Ok(())
+    }
+
+    /// Shows or hides a channel in the program guide, the channel list or while surfing channels.
+    ///
+    /// # Authentication Level
+    /// Generic
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{av_content::TvContentVisibility, Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// let mut visibility = TvContentVisibility::new("tv:dvbt?trip=9018.4161.1025");
+    /// visibility.visibility = Some("invisible".to_string());
+    /// bravia.av_content().set_tv_content_visibility(visibility).await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn set_tv_content_visibility(&self, visibility: TvContentVisibility) -> Result<()> {
+        let body = RequestBodyBuilder::default()
+            .id(17)
+            .method("setTvContentVisibility")
+            .params(serde_json::to_value(visibility)?)
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .make(&self.0)
             .await?;
         Ok(())
     }
+
+    /// Provides the contents of a source one at a time, reading them with
+    /// [getContentList](AvContentService::get_content_list) in pages of `page_size`
+    /// until the server returns fewer contents than requested.\
+    /// The stream ends after the first error.
+    ///
+    /// # Arguments
+    /// * `uri` - URI of the source, like `tv:dvbt`.
+    /// * `page_size` - Number of contents requested at a time, the maximum depends on the device.
+    ///
+    /// # Authentication Level
+    /// Private
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # use futures_util::{pin_mut, StreamExt};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// let av_content = bravia.av_content();
+    /// let contents = av_content.content_list_stream("tv:dvbt", 50);
+    /// pin_mut!(contents);
+    /// while let Some(content) = contents.next().await {
+    ///     println!("{:?}", content?.title);
+    /// }
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn content_list_stream(
+        &self,
+        uri: &str,
+        page_size: u32,
+    ) -> impl Stream<Item = Result<Content>> + '_ {
+        let uri = uri.to_string();
+        let page_size = page_size.max(1);
+        let state = (VecDeque::new(), 0, false);
+        stream::unfold(state, move |(mut page, mut st_idx, mut last)| {
+            let uri = uri.clone();
+            async move {
+                if page.is_empty() && !last {
+                    match self
+                        .get_content_list(Some(&uri), Some(st_idx), Some(page_size), None)
+                        .await
+                    {
+                        Ok(contents) => {
+                            last = contents.len() < page_size as usize;
+                            st_idx += contents.len() as u32;
+                            page = contents.into();
+                        }
+                        Err(err) => return Some((Err(err), (page, st_idx, true))),
+                    }
+                }
+                let content = page.pop_front()?;
+                Some((Ok(content), (page, st_idx, last)))
+            }
+        })
+    }
+
+    /// Provides all the contents of a source, reading them with
+    /// [getContentList](AvContentService::get_content_list).\
+    /// Pages of up to 200 contents are requested, the page size is halved while the device
+    /// rejects it as too large. Contents returned more than once are only kept the first time.
+    ///
+    /// # Arguments
+    /// * `uri` - URI of the source, like `tv:dvbt`.
+    /// * `progress` - Called with the number of contents read so far after each page.
+    ///
+    /// # Authentication Level
+    /// Private
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// let mut progress = |count| println!("{} channels read", count);
+    /// let channels = bravia
+    ///     .av_content()
+    ///     .get_all_contents("tv:dvbt", Some(&mut progress))
+    ///     .await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn get_all_contents(
+        &self,
+        uri: &str,
+        mut progress: Option<&mut dyn FnMut(usize)>,
+    ) -> Result<Vec<Content>> {
+        let mut page_size = CONTENT_PAGE_MAX;
+        let mut st_idx = 0;
+        let mut seen = HashSet::new();
+        let mut contents = Vec::new();
+        loop {
+            let page = match self
+                .get_content_list(Some(uri), Some(st_idx), Some(page_size), None)
+                .await
+            {
+                Ok(page) => page,
+                // Illegal Argument, the page is larger than the device limit
+                Err(Error::BraviaError(code, _)) if code.code == 3 && page_size > 1 => {
+                    page_size /= 2;
+                    continue;
+                }
+                Err(err) => return Err(err),
+            };
+            let last = page.len() < page_size as usize;
+            st_idx += page.len() as u32;
+            contents.extend(
+                page.into_iter()
+                    .filter(|content| seen.insert(content.uri.clone())),
+            );
+            if let Some(progress) = progress.as_mut() {
+                progress(contents.len());
+            }
+            if last {
+                return Ok(contents);
+            }
+        }
+    }
+
+    /// Switches to an external input with [setPlayContent](AvContentService::set_play_content).
+    ///
+    /// # Arguments
+    /// * `input` - External input to show.
+    /// * `verify` - Checks with [getPlayingContentInfo](AvContentService::get_playing_content_info)
+    ///   that the input is shown afterwards.
+    ///
+    /// # Authentication Level
+    /// Generic
+    ///
+    /// # Errors
+    /// [VerificationFailed](Error::VerificationFailed) if `verify` is set and
+    /// the display still shows another content after a short time.
+    pub async fn select_input(&self, input: InputUri, verify: bool) -> Result<()> {
+        self.set_play_content(input.clone()).await?;
+        if !verify {
+            return Ok(());
+        }
+        let mut playing = None;
+        for attempt in 0..SELECT_VERIFY_ATTEMPTS {
+            if attempt > 0 {
+                runtime::sleep(SELECT_VERIFY_INTERVAL).await;
+            }
+            let info = self.get_playing_content_info().await?;
+            if info.input_uri().as_ref() == Some(&input) {
+                return Ok(());
+            }
+            playing = Some(info.uri);
+        }
+        Err(Error::VerificationFailed(format!(
+            "{} was selected but {} is shown",
+            input,
+            playing.unwrap_or_default()
+        )))
+    }
+
+    /// Switches to an HDMI input, see [select_input](Self::select_input).
+    pub async fn select_hdmi(&self, port: u8, verify: bool) -> Result<()> {
+        self.select_input(InputUri::Hdmi(port.into()), verify).await
+    }
+
+    /// Switches to a component input, see [select_input](Self::select_input).
+    pub async fn select_component(&self, port: u8, verify: bool) -> Result<()> {
+        self.select_input(InputUri::Component(port.into()), verify)
+            .await
+    }
+
+    /// Provides all the channels of a TV source in the order of the channel list,
+    /// reading them with [getContentList](AvContentService::get_content_list).\
+    /// The channels are cached by the client until [clear_channel_cache](Self::clear_channel_cache) is called.
+    ///
+    /// # Arguments
+    /// * `source` - TV source, like `tv:dvbt`.
+    ///
+    /// # Authentication Level
+    /// Private
+    pub async fn get_tv_channels(&self, source: &str) -> Result<Vec<Content>> {
+        if let Some(channels) = self.cached_channels(source) {
+            return Ok(channels);
+        }
+        let mut channels = Vec::new();
+        loop {
+            let page = self
+                .get_content_list(
+                    Some(source),
+                    Some(channels.len() as u32),
+                    Some(CHANNEL_PAGE_SIZE),
+                    None,
+                )
+                .await?;
+            let last = page.len() < CHANNEL_PAGE_SIZE as usize;
+            channels.extend(page);
+            if last {
+                break;
+            }
+        }
+        if let Ok(mut cache) = self.0.bravia.target().channels.lock() {
+            cache.insert(source.to_string(), channels.clone());
+        }
+        Ok(channels)
+    }
+
+    /// Forgets the channels cached by [get_tv_channels](Self::get_tv_channels),
+    /// for example after a channel scan.
+    pub fn clear_channel_cache(&self) {
+        if let Ok(mut cache) = self.0.bravia.target().channels.lock() {
+            cache.clear();
+        }
+    }
+
+    /// Switches to a TV channel by the number shown to the user.
+    ///
+    /// # Arguments
+    /// * `source` - TV source, like `tv:dvbt`.
+    /// * `disp_num` - Channel number, like `21` or `021`.
+    ///
+    /// # Authentication Level
+    /// Generic
+    ///
+    /// # Errors
+    /// [InvalidParameter](Error::InvalidParameter) if the source has no channel with that number.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// bravia.av_content().play_tv_channel("tv:dvbt", "1").await?;
+    /// bravia.av_content().next_channel().await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn play_tv_channel(&self, source: &str, disp_num: &str) -> Result<()> {
+        let channel = self
+            .get_tv_channels(source)
+            .await?
+            .into_iter()
+            .find(|channel| {
+                channel
+                    .disp_num
+                    .as_deref()
+                    .is_some_and(|number| same_channel_number(number, disp_num))
+            })
+            .ok_or_else(|| {
+                Error::InvalidParameter(format!("no channel {} in {}", disp_num, source))
+            })?;
+        self.set_play_content(channel.uri).await
+    }
+
+    /// Switches to the channel after the one being watched, going back to the first one at the end of the list.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn next_channel(&self) -> Result<()> {
+        self.zap(1).await
+    }
+
+    /// Switches to the channel before the one being watched, going to the last one at the start of the list.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn previous_channel(&self) -> Result<()> {
+        self.zap(-1).await
+    }
+
+    // Moves by `offset` positions in the channel list of the playing source.
+    async fn zap(&self, offset: isize) -> Result<()> {
+        let playing = self.get_playing_content_info().await?;
+        let mut channels = self.get_tv_channels(&playing.source).await?;
+        if !channels.iter().any(|channel| channel.uri == playing.uri) {
+            // The cached list may be older than the last channel scan
+            self.clear_channel_cache();
+            channels = self.get_tv_channels(&playing.source).await?;
+        }
+        let position = channels
+            .iter()
+            .position(|channel| channel.uri == playing.uri)
+            .ok_or(Error::MissingValue("channel"))?;
+        let next = (position as isize + offset).rem_euclid(channels.len() as isize) as usize;
+        self.set_play_content(channels.swap_remove(next).uri).await
+    }
+
+    fn cached_channels(&self, source: &str) -> Option<Vec<Content>> {
+        let target = self.0.bravia.target();
+        let cache = target.channels.lock().ok()?;
+        cache.get(source).cloned()
+    }
+}
+
+// Compares channel numbers ignoring the leading zeros.
+fn same_channel_number(a: &str, b: &str) -> bool {
+    match (a.parse::<u32>(), b.parse::<u32>()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
 }
@@ -0,0 +1,194 @@
+//! Command line tool to control a display, built with the `cli` feature.
+//!
+//! The address and the Pre-Shared Key are read from the options, the environment
+//! (`BRAVIA_ADDRESS`, `BRAVIA_PSK`) or a credentials file (`--config` or `BRAVIA_CONFIG`),
+//! by default `~/.config/bravia/credentials.json`.
+
+use bravia_api::{
+    credentials::Credentials,
+    error::{Error, Result},
+    Auth, Bravia,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use serde_json::Value;
+use std::{path::PathBuf, process::ExitCode};
+
+#[derive(Parser)]
+#[command(name = "bravia", version, about = "Controls a Sony Bravia display")]
+struct Cli {
+    /// Display address.
+    #[arg(long, env = "BRAVIA_ADDRESS")]
+    address: Option<String>,
+    /// Pre-Shared Key set on the display.
+    #[arg(long, env = "BRAVIA_PSK", hide_env_values = true)]
+    psk: Option<String>,
+    /// Credentials file, as saved by `Credentials::to_json`.
+    #[arg(long, env = "BRAVIA_CONFIG")]
+    config: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Turns the display on or off, or prints the power status.
+    Power { state: Option<PowerState> },
+    /// Sets the speaker volume, or prints the volume of each output.
+    Volume { level: Option<u32> },
+    /// Switches to an external input, like `hdmi2`, or to the given URI.
+    Input { name: String },
+    /// Lists or launches the applications.
+    Apps {
+        #[command(subcommand)]
+        command: AppsCommand,
+    },
+    /// Calls any API and prints its result.
+    Raw {
+        /// Service name, like `system`.
+        service: String,
+        /// API name, like `getPowerStatus`.
+        method: String,
+        /// JSON parameters.
+        params: Option<String>,
+        /// API version.
+        #[arg(long, default_value = "1.0")]
+        api_version: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum AppsCommand {
+    /// Prints the title and the URI of the applications.
+    List,
+    /// Launches an application by title or URI.
+    Launch { app: String },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum PowerState {
+    On,
+    Off,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    match run(Cli::parse()).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(cli: Cli) -> Result<()> {
+    let bravia = connect(&cli).await?;
+    match cli.command {
+        Command::Power { state: None } => {
+            println!("{}", bravia.system().get_power_status().await?);
+        }
+        Command::Power { state: Some(state) } => {
+            let on = matches!(state, PowerState::On);
+            bravia.system().set_power_status(on).await?;
+        }
+        Command::Volume { level: None } => {
+            for info in bravia.audio().get_volume_information().await? {
+                let mute = if info.mute { " (muted)" } else { "" };
+                println!("{}: {}{mute}", info.target, info.volume);
+            }
+        }
+        Command::Volume { level: Some(level) } => {
+            bravia
+                .audio()
+                .set_audio_volume(Some("speaker"), level.to_string(), None, None)
+                .await?;
+        }
+        Command::Input { name } => {
+            bravia
+                .av_content()
+                .set_play_content(input_uri(&name)?)
+                .await?;
+        }
+        Command::Apps {
+            command: AppsCommand::List,
+        } => {
+            for app in bravia.app_control().get_application_list().await? {
+                println!("{}\t{}", app.title, app.uri);
+            }
+        }
+        Command::Apps {
+            command: AppsCommand::Launch { app },
+        } => {
+            let apps = bravia.app_control().get_application_list().await?;
+            let uri = apps
+                .into_iter()
+                .find(|candidate| candidate.title.eq_ignore_ascii_case(&app))
+                .map_or(app, |candidate| candidate.uri);
+            bravia.app_control().set_active_app(uri).await?;
+        }
+        Command::Raw {
+            service,
+            method,
+            params,
+            api_version,
+        } => {
+            let params = match params {
+                Some(params) => serde_json::from_str(&params)?,
+                None => Value::Object(Default::default()),
+            };
+            let result = bravia
+                .raw_call(&service, &method, &api_version, params)
+                .await?;
+            println!("{}", serde_json::to_string_pretty(&result)?);
+        }
+    }
+    Ok(())
+}
+
+// Creates the client from the options, the environment and the credentials file.
+async fn connect(cli: &Cli) -> Result<Bravia> {
+    let default_config = std::env::var_os("HOME")
+        .map(|home| PathBuf::from(home).join(".config/bravia/credentials.json"));
+    let config = match (&cli.config, default_config) {
+        (Some(path), _) => Some(read_credentials(path)?),
+        (None, Some(path)) if path.exists() => Some(read_credentials(&path)?),
+        _ => None,
+    };
+
+    let address = cli
+        .address
+        .clone()
+        .or_else(|| config.as_ref().map(|config| config.address.clone()))
+        .ok_or_else(|| {
+            Error::InvalidParameter(String::from(
+                "no address given, use --address or BRAVIA_ADDRESS",
+            ))
+        })?;
+    let auth = match &cli.psk {
+        Some(psk) => Some(Auth::Psk(psk.clone())),
+        None => config.and_then(|config| config.auth),
+    };
+    Bravia::from_credentials(&Credentials::new(&address, auth)).await
+}
+
+fn read_credentials(path: &PathBuf) -> Result<Credentials> {
+    Credentials::from_json(&std::fs::read_to_string(path)?)
+}
+
+// Converts an input name like `hdmi2` to its URI, URIs are returned as they are.
+fn input_uri(name: &str) -> Result<String> {
+    if name.contains(':') {
+        return Ok(name.to_string());
+    }
+    let name = name.to_ascii_lowercase();
+    let split = name
+        .find(|c: char| c.is_ascii_digit())
+        .unwrap_or(name.len());
+    let (kind, port) = name.split_at(split);
+    match (kind, port.parse::<u32>()) {
+        ("hdmi" | "component" | "composite" | "scart", Ok(port)) => {
+            Ok(format!("extInput:{kind}?port={port}"))
+        }
+        _ => Err(Error::InvalidParameter(format!("unknown input {name:?}"))),
+    }
+}
@@ -0,0 +1,135 @@
+//! APIs that are related to the built-in web browser.
+
+use crate::{error::Result, Bravia, RequestBodyBuilder, RequestBuilder, ServiceContext};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+const ENDPOINT: &str = "browser";
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Bookmark {
+    /// Title of the bookmark.
+    pub title: String,
+    /// Address of the bookmarked page.
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PageInfo {
+    /// Address of the page shown by the browser.
+    pub url: String,
+    /// Title of the page.\
+    /// The default value is `None`, this means that there is no title information.
+    #[serde(default)]
+    pub title: Option<String>,
+}
+
+/// Provides access to browser service APIs.
+pub struct BrowserService(ServiceContext);
+
+impl_service_options!(BrowserService);
+
+impl BrowserService {
+    pub fn new(bravia: &Bravia) -> Self {
+        Self(ServiceContext::new(bravia))
+    }
+
+    /// Starts or stops the browser.
+    ///
+    /// # Arguments
+    /// * `control` - Action to perform.
+    ///     * `start` - Opens the browser.
+    ///     * `stop` - Closes the browser.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn act_browser_control(&self, control: impl Into<String>) -> Result<()> {
+        let mut params = Map::new();
+        params.insert(String::from("control"), Value::from(control.into()));
+
+        let body = RequestBodyBuilder::default()
+            .id(160)
+            .method("actBrowserControl")
+            .params(Value::from(params))
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .make(&self.0)
+            .await?;
+        Ok(())
+    }
+
+    /// Provides the bookmarks saved in the browser.
+    ///
+    /// # Authentication Level
+    /// Private
+    pub async fn get_browser_bookmark_list(&self) -> Result<Vec<Bookmark>> {
+        let body = RequestBodyBuilder::default()
+            .id(161)
+            .method("getBrowserBookmarkList")
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .has_result()
+            .make_as(&self.0)
+            .await
+    }
+
+    /// Provides the page currently shown by the browser.
+    ///
+    /// # Authentication Level
+    /// Private
+    pub async fn get_text_url(&self) -> Result<PageInfo> {
+        let body = RequestBodyBuilder::default()
+            .id(162)
+            .method("getTextUrl")
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .has_result()
+            .make_as(&self.0)
+            .await
+    }
+
+    /// Opens a page in the browser, starting it if needed.
+    ///
+    /// # Arguments
+    /// * `url` - Address of the page.
+    ///
+    /// # Authentication Level
+    /// Generic
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// bravia.browser().set_text_url("https://example.com").await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn set_text_url(&self, url: impl Into<String>) -> Result<()> {
+        let mut params = Map::new();
+        params.insert(String::from("url"), Value::from(url.into()));
+
+        let body = RequestBodyBuilder::default()
+            .id(163)
+            .method("setTextUrl")
+            .params(Value::from(params))
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .make(&self.0)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+//! APIs that configure the HDMI-CEC and MHL behavior of the device.
+
+use crate::{error::Result, lenient, Bravia, RequestBodyBuilder, RequestBuilder, ServiceContext};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+const ENDPOINT: &str = "cec";
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PowerSyncMode {
+    /// Turns off the connected devices when the display is turned off.
+    #[serde(deserialize_with = "lenient::bool")]
+    pub sink_power_off_sync: bool,
+    /// Turns on the display when a connected device is turned on.
+    #[serde(deserialize_with = "lenient::bool")]
+    pub source_power_on_sync: bool,
+}
+
+impl PowerSyncMode {
+    pub fn new(sink_power_off_sync: bool, source_power_on_sync: bool) -> Self {
+        Self {
+            sink_power_off_sync,
+            source_power_on_sync,
+        }
+    }
+}
+
+/// Provides access to cec service APIs.
+pub struct CecService(ServiceContext);
+
+impl_service_options!(CecService);
+
+impl CecService {
+    pub fn new(bravia: &Bravia) -> Self {
+        Self(ServiceContext::new(bravia))
+    }
+
+    /// Enables or disables the control of the connected devices through HDMI-CEC.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn set_cec_control_mode(&self, enabled: bool) -> Result<()> {
+        self.set_enabled(170, "setCecControlMode", enabled).await
+    }
+
+    /// Sets how the power status is synchronized with the connected devices.
+    ///
+    /// # Authentication Level
+    /// Generic
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{cec::PowerSyncMode, Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// bravia.cec().set_power_sync_mode(PowerSyncMode::new(true, false)).await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn set_power_sync_mode(&self, mode: PowerSyncMode) -> Result<()> {
+        let body = RequestBodyBuilder::default()
+            .id(171)
+            .method("setPowerSyncMode")
+            .params(serde_json::to_value(mode)?)
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .make(&self.0)
+            .await?;
+        Ok(())
+    }
+
+    /// Enables or disables the switch to the MHL input when a device is connected to it.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn set_mhl_auto_input_change_mode(&self, enabled: bool) -> Result<()> {
+        self.set_enabled(172, "setMhlAutoInputChangeMode", enabled)
+            .await
+    }
+
+    /// Enables or disables charging the device connected to the MHL input.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn set_mhl_power_feed_mode(&self, enabled: bool) -> Result<()> {
+        self.set_enabled(173, "setMhlPowerFeedMode", enabled).await
+    }
+
+    // Calls an API whose only parameter is `enabled`.
+    async fn set_enabled(&self, id: usize, method: &str, enabled: bool) -> Result<()> {
+        let mut params = Map::new();
+        params.insert(String::from("enabled"), Value::from(enabled));
+
+        let body = RequestBodyBuilder::default()
+            .id(id)
+            .method(method)
+            .params(Value::from(params))
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .make(&self.0)
+            .await?;
+        Ok(())
+    }
+}
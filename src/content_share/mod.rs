@@ -0,0 +1,112 @@
+//! APIs of the Photo Share feature, that lets smartphones show their photos on the display
+//! through a web page served by the display itself.
+//!
+//! # Note
+//! Only some models expose this service, use [supports](crate::Bravia::supports) to check its availability.
+
+use crate::{error::Result, lenient, Bravia, RequestBodyBuilder, RequestBuilder, ServiceContext};
+use serde::{Deserialize, Serialize};
+
+const ENDPOINT: &str = "contentshare";
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShareSession {
+    /// Address of the web page that the smartphones open to share their content.
+    pub url: String,
+    /// SSID of the network the smartphones must join, when the display provides its own access point.
+    #[serde(default)]
+    pub ssid: Option<String>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShareSessionInfo {
+    /// Session status.
+    /// * `active` - A session is open.
+    /// * `inactive` - No session is open.
+    pub status: String,
+    /// Address of the web page of the open session.
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Number of smartphones connected to the session.
+    #[serde(default, deserialize_with = "lenient::number")]
+    pub connected_devices: usize,
+}
+
+/// Provides access to contentshare service APIs.
+pub struct ContentShareService(ServiceContext);
+
+impl_service_options!(ContentShareService);
+
+impl ContentShareService {
+    pub fn new(bravia: &Bravia) -> Self {
+        Self(ServiceContext::new(bravia))
+    }
+
+    /// Opens a Photo Share session and shows its instructions on the display.
+    ///
+    /// # Authentication Level
+    /// Generic
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// let session = bravia.content_share().start_photo_share().await?;
+    /// println!("Open {} to share your photos", session.url);
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn start_photo_share(&self) -> Result<ShareSession> {
+        let body = RequestBodyBuilder::default()
+            .id(140)
+            .method("startPhotoShare")
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .has_result()
+            .make_as(&self.0)
+            .await
+    }
+
+    /// Closes the Photo Share session, disconnecting the smartphones.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn stop_photo_share(&self) -> Result<()> {
+        let body = RequestBodyBuilder::default()
+            .id(141)
+            .method("stopPhotoShare")
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .make(&self.0)
+            .await?;
+        Ok(())
+    }
+
+    /// Provides the status of the Photo Share session.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn get_photo_share_info(&self) -> Result<ShareSessionInfo> {
+        let body = RequestBodyBuilder::default()
+            .id(142)
+            .method("getPhotoShareInfo")
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .has_result()
+            .make_as(&self.0)
+            .await
+    }
+}
@@ -0,0 +1,76 @@
+//! Credentials that can be stored to connect to the same server across restarts.
+
+use crate::{error::Result, Auth, Bravia};
+use serde::{Deserialize, Serialize};
+
+/// Everything needed to connect to a server without pairing the client again.
+///
+/// # Examples
+/// ```no_run
+/// # use bravia_api::{credentials::Credentials, Auth, Bravia, error::Result};
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// let credentials = Credentials::new("ADDRESS", Some(Auth::Cookie(String::from("COOKIE"))))
+///     .device_id("client:1234");
+/// std::fs::write("credentials.json", credentials.to_json()?).unwrap();
+///
+/// let json = std::fs::read_to_string("credentials.json").unwrap();
+/// let bravia = Bravia::from_credentials(&Credentials::from_json(&json)?).await?;
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Credentials {
+    /// Server address.
+    pub address: String,
+    /// Pre-Shared Key or cookie used to access the APIs.
+    #[serde(default)]
+    pub auth: Option<Auth>,
+    /// Unique identifier of the client, used as `client_id` with
+    /// [actRegister](crate::access_control::AccessControlService::act_register).\
+    /// Needed to renew the cookie when it expires.
+    #[serde(default)]
+    pub device_id: Option<String>,
+}
+
+impl Credentials {
+    /// # Arguments
+    /// * `address` - Server address.
+    /// * `auth` - Pre-Shared Key or cookie used to access the APIs.
+    pub fn new(address: &str, auth: Option<Auth>) -> Self {
+        Self {
+            address: address.to_string(),
+            auth,
+            device_id: None,
+        }
+    }
+
+    /// Sets the unique identifier of the client used to pair with the server.
+    pub fn device_id(mut self, device_id: &str) -> Self {
+        self.device_id = Some(device_id.to_string());
+        self
+    }
+
+    /// Serializes the credentials to JSON.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Loads the credentials from JSON created by [to_json](Self::to_json).
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+impl Bravia {
+    /// Same as [new](Self::new) but uses stored [Credentials].
+    pub async fn from_credentials(credentials: &Credentials) -> Result<Self> {
+        let mut builder = Self::builder(&credentials.address);
+        builder = match &credentials.auth {
+            Some(Auth::Psk(psk)) => builder.auth(psk),
+            Some(Auth::Cookie(cookie)) => builder.auth_cookie(cookie),
+            None => builder,
+        };
+        builder.build().await
+    }
+}
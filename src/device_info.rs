@@ -0,0 +1,55 @@
+//! Identity of a display, read once and kept for the whole session.
+
+use crate::{
+    error::Result,
+    system::{InterfaceInfo, SystemInformation},
+    Bravia,
+};
+
+/// Information that doesn't change while the display is running,
+/// returned by [device_info](Bravia::device_info).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DeviceInfo {
+    /// Result of [getInterfaceInformation](crate::system::SystemService::get_interface_information).
+    pub interface: InterfaceInfo,
+    /// Result of [getSystemInformation](crate::system::SystemService::get_system_information).
+    pub system: SystemInformation,
+}
+
+impl Bravia {
+    /// Fetches the interface and the system information at the same time.\
+    /// They are read from the server only once, the following calls return the cached values
+    /// until the [address](Bravia::set_address) or the [credentials](Bravia::set_auth) change.
+    ///
+    /// # Authentication Level
+    /// Private
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// let info = bravia.device_info().await?;
+    /// println!("{} {}", info.system.model, info.system.serial);
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn device_info(&self) -> Result<DeviceInfo> {
+        self.target()
+            .device_info
+            .get_or_try_init(|| async {
+                let system = self.system();
+                let (interface, information) = tokio::join!(
+                    system.get_interface_information(),
+                    system.get_system_information(),
+                );
+                Ok(DeviceInfo {
+                    interface: interface?,
+                    system: information?,
+                })
+            })
+            .await
+            .cloned()
+    }
+}
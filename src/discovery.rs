@@ -0,0 +1,172 @@
+//! SSDP discovery of the displays on the local network.
+
+use crate::{error::Result, Bravia};
+use reqwest::Url;
+use std::{
+    collections::HashSet,
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    time::Duration,
+};
+use tokio::{net::UdpSocket, time::Instant};
+
+const SEARCH_TARGET: &str = "urn:schemas-sony-com:service:ScalarWebAPI:1";
+
+/// Display found on the local network.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct DiscoveredDevice {
+    /// Address of the display, can be passed to [Bravia::new].
+    pub address: IpAddr,
+    /// URL of the UPnP device description.
+    pub location: String,
+    /// Name of the display, read from the device description.\
+    /// `None` if the description couldn't be read.
+    pub friendly_name: Option<String>,
+}
+
+/// Searches the displays with an SSDP `M-SEARCH` request.
+///
+/// # Examples
+/// ```no_run
+/// # use bravia_api::{discovery::Discovery, error::Result};
+/// # use std::time::Duration;
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// let devices = Discovery::new()
+///     .timeout(Duration::from_secs(5))
+///     .run()
+///     .await?;
+/// for device in devices {
+///     println!("{:?} at {}", device.friendly_name, device.address);
+/// }
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Discovery {
+    timeout: Duration,
+    search_address: SocketAddr,
+}
+
+impl Default for Discovery {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(3),
+            search_address: SocketAddr::from((Ipv4Addr::new(239, 255, 255, 250), 1900)),
+        }
+    }
+}
+
+impl Discovery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// How long to wait for the answers.\
+    /// The default value is 3 seconds.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Address the search request is sent to.\
+    /// The default value is the SSDP multicast address `239.255.255.250:1900`.
+    pub fn search_address(mut self, address: SocketAddr) -> Self {
+        self.search_address = address;
+        self
+    }
+
+    /// Sends the search request and collects the displays that answered before the timeout.
+    pub async fn run(&self) -> Result<Vec<DiscoveredDevice>> {
+        let request = format!(
+            "M-SEARCH * HTTP/1.1\r\n\
+            HOST: {}\r\n\
+            MAN: \"ssdp:discover\"\r\n\
+            MX: {}\r\n\
+            ST: {SEARCH_TARGET}\r\n\r\n",
+            self.search_address,
+            self.timeout.as_secs().clamp(1, 5)
+        );
+        let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)).await?;
+        socket
+            .send_to(request.as_bytes(), self.search_address)
+            .await?;
+
+        // Collects the answers until the timeout expires
+        let deadline = Instant::now() + self.timeout;
+        let mut locations = HashSet::new();
+        let mut buffer = [0; 2048];
+        while let Ok(received) = tokio::time::timeout_at(deadline, socket.recv(&mut buffer)).await {
+            let response = String::from_utf8_lossy(&buffer[..received?]);
+            if let Some(location) = parse_response(&response) {
+                locations.insert(location);
+            }
+        }
+
+        let client = reqwest::Client::builder().timeout(self.timeout).build()?;
+        let mut devices = Vec::new();
+        for location in locations {
+            let Some(address) = location_address(&location) else {
+                continue;
+            };
+            let friendly_name = match client.get(&location).send().await {
+                Ok(resp) => resp
+                    .text()
+                    .await
+                    .ok()
+                    .and_then(|description| friendly_name(&description)),
+                Err(_) => None,
+            };
+            devices.push(DiscoveredDevice {
+                address,
+                location,
+                friendly_name,
+            });
+        }
+        devices.sort_by_key(|device| device.address);
+        Ok(devices)
+    }
+}
+
+// Returns the location of an answer to the search request.
+fn parse_response(response: &str) -> Option<String> {
+    let mut lines = response.lines();
+    if !lines.next()?.contains(" 200 ") {
+        return None;
+    }
+    let mut location = None;
+    let mut matches_target = false;
+    for line in lines {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        match name.trim().to_ascii_uppercase().as_str() {
+            "LOCATION" => location = Some(value.trim().to_string()),
+            "ST" => matches_target = value.trim() == SEARCH_TARGET,
+            _ => {}
+        }
+    }
+    location.filter(|_| matches_target)
+}
+
+// Returns the IP address of the host in the location URL.
+fn location_address(location: &str) -> Option<IpAddr> {
+    let url = Url::parse(location).ok()?;
+    url.host_str()?
+        .trim_matches(|c| c == '[' || c == ']')
+        .parse()
+        .ok()
+}
+
+// Reads the friendly name from the UPnP device description.
+fn friendly_name(description: &str) -> Option<String> {
+    let start = description.find("<friendlyName>")? + "<friendlyName>".len();
+    let end = start + description[start..].find("</friendlyName>")?;
+    Some(description[start..end].trim().to_string())
+}
+
+impl Bravia {
+    /// Searches the displays on the local network, see [Discovery].
+    pub async fn discover() -> Result<Vec<DiscoveredDevice>> {
+        Discovery::new().run().await
+    }
+}
@@ -4,16 +4,225 @@
 //! For details on encryption specifications,
 //! please see [Sony's documentation](https://pro-bravia.sony.net/develop/integrate/rest-api/doc/Data-Encryption_401146660/index.html).
 
-use crate::{error::Result, Bravia, RequestBodyBuilder, RequestBuilder};
+#[cfg(feature = "encryption")]
+use crate::error::Error;
+use crate::{error::Result, Bravia, RequestBodyBuilder, RequestBuilder, ServiceContext};
+#[cfg(feature = "encryption")]
+use aes::cipher::{block_padding::Pkcs7, BlockDecryptMut, BlockEncryptMut, KeyIvInit};
+#[cfg(feature = "encryption")]
+use base64::{engine::general_purpose::STANDARD, Engine};
+#[cfg(feature = "encryption")]
+use rand::RngCore;
+#[cfg(feature = "encryption")]
+use rsa::{pkcs1::DecodeRsaPublicKey, pkcs8::DecodePublicKey, Pkcs1v15Encrypt, RsaPublicKey};
+#[cfg(feature = "encryption")]
+use std::future::Future;
+#[cfg(feature = "encryption")]
+use tokio::sync::Mutex;
 
 const ENDPOINT: &str = "encryption";
 
+// Error returned when the server can't decrypt the common key, like after it generated a new key pair.
+#[cfg(feature = "encryption")]
+const ENCRYPTION_FAILED: usize = 40002;
+
+/// Encrypts the parameters of a single exchange with a random AES-128 common key,
+/// as described in Sony's documentation.\
+/// The key and the IV are encrypted together with the RSA public key of the device
+/// and sent as [enc_key](Self::enc_key), the data is encrypted with AES-128-CBC and PKCS#7 padding
+/// and framed with base64.\
+/// Requires the `encryption` feature.
+///
+/// # Examples
+/// ```no_run
+/// # use bravia_api::{Bravia, error::Result};
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+/// let cipher = bravia.encryption().cipher().await?;
+/// bravia
+///     .app_control()
+///     .set_text_form(
+///         cipher.encrypt("my password"),
+///         Some(cipher.enc_key()),
+///         Some("1.1"),
+///     )
+///     .await?;
+/// #    Ok(())
+/// # }
+/// ```
+#[cfg(feature = "encryption")]
+#[derive(Clone)]
+pub struct Cipher {
+    key: [u8; 16],
+    iv: [u8; 16],
+    enc_key: String,
+}
+
+#[cfg(feature = "encryption")]
+impl Cipher {
+    /// Generates a random common key.
+    ///
+    /// # Arguments
+    /// * `public_key` - Base64 DER public key, as returned by [getPublicKey](EncryptionService::get_public_key).
+    ///
+    /// # Errors
+    /// Returns [Error::EncryptionError] if the public key is invalid.
+    pub fn new(public_key: &str) -> Result<Self> {
+        let der = STANDARD
+            .decode(public_key.trim())
+            .map_err(|err| Error::EncryptionError(format!("invalid public key: {err}")))?;
+        let public_key = RsaPublicKey::from_public_key_der(&der)
+            .or_else(|_| RsaPublicKey::from_pkcs1_der(&der))
+            .map_err(|err| Error::EncryptionError(format!("invalid public key: {err}")))?;
+
+        let mut rng = rand::thread_rng();
+        let mut key = [0; 16];
+        let mut iv = [0; 16];
+        rng.fill_bytes(&mut key);
+        rng.fill_bytes(&mut iv);
+        let enc_key = public_key
+            .encrypt(&mut rng, Pkcs1v15Encrypt, &[key, iv].concat())
+            .map_err(|err| Error::EncryptionError(err.to_string()))?;
+        Ok(Self {
+            key,
+            iv,
+            enc_key: STANDARD.encode(enc_key),
+        })
+    }
+
+    /// Common key encrypted with the public key, sent as the `encKey` parameter.
+    pub fn enc_key(&self) -> &str {
+        &self.enc_key
+    }
+
+    /// Encrypts `text` with the common key, returning it in base64.
+    pub fn encrypt(&self, text: &str) -> String {
+        let data = cbc::Encryptor::<aes::Aes128>::new(&self.key.into(), &self.iv.into())
+            .encrypt_padded_vec_mut::<Pkcs7>(text.as_bytes());
+        STANDARD.encode(data)
+    }
+
+    /// Decrypts base64 `text` encrypted with the common key, like the values returned by the server.
+    ///
+    /// # Errors
+    /// Returns [Error::EncryptionError] if `text` was not encrypted with this key.
+    pub fn decrypt(&self, text: &str) -> Result<String> {
+        let data = STANDARD
+            .decode(text.trim())
+            .map_err(|err| Error::EncryptionError(format!("invalid encrypted data: {err}")))?;
+        let data = cbc::Decryptor::<aes::Aes128>::new(&self.key.into(), &self.iv.into())
+            .decrypt_padded_vec_mut::<Pkcs7>(&data)
+            .map_err(|_| Error::EncryptionError(String::from("invalid padding")))?;
+        String::from_utf8(data)
+            .map_err(|_| Error::EncryptionError(String::from("decrypted data is not UTF-8")))
+    }
+}
+
+/// Keeps the public key of the device and a common key across the exchanges,
+/// for the encrypted APIs called repeatedly like the text fields of a kiosk keyboard.\
+/// Created by [session](EncryptionService::session).
+/// Requires the `encryption` feature.
+///
+/// # Examples
+/// ```no_run
+/// # use bravia_api::{Bravia, error::Result};
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+/// let session = bravia.encryption().session();
+/// let app_control = bravia.app_control();
+/// for text in ["user", "password"] {
+///     session
+///         .run(|cipher| {
+///             let app_control = &app_control;
+///             async move {
+///                 app_control
+///                     .set_text_form(cipher.encrypt(text), Some(cipher.enc_key()), Some("1.1"))
+///                     .await
+///             }
+///         })
+///         .await?;
+/// }
+/// #    Ok(())
+/// # }
+/// ```
+#[cfg(feature = "encryption")]
+pub struct EncryptionSession {
+    service: EncryptionService,
+    state: Mutex<SessionState>,
+}
+
+#[cfg(feature = "encryption")]
+#[derive(Default)]
+struct SessionState {
+    public_key: Option<String>,
+    cipher: Option<Cipher>,
+}
+
+#[cfg(feature = "encryption")]
+impl EncryptionSession {
+    /// Returns the common key of the session, it's generated on first use
+    /// with the public key returned by [getPublicKey](EncryptionService::get_public_key).
+    pub async fn cipher(&self) -> Result<Cipher> {
+        let mut state = self.state.lock().await;
+        match &state.cipher {
+            Some(cipher) => Ok(cipher.clone()),
+            None => self.new_cipher(&mut state).await,
+        }
+    }
+
+    /// Replaces the common key with a new random one, the public key is kept.
+    pub async fn rotate_key(&self) -> Result<Cipher> {
+        let mut state = self.state.lock().await;
+        self.new_cipher(&mut state).await
+    }
+
+    /// Forgets the public key and the common key, they are requested and generated again on next use.
+    pub async fn invalidate(&self) {
+        *self.state.lock().await = SessionState::default();
+    }
+
+    /// Runs an exchange with the common key of the session.\
+    /// If the server can't decrypt the common key, like after it generated a new key pair,
+    /// the public key is requested again and the exchange is repeated once with a new common key.
+    ///
+    /// # Arguments
+    /// * `exchange` - Calls the encrypted API with the given cipher.
+    pub async fn run<T, F, Fut>(&self, exchange: F) -> Result<T>
+    where
+        F: Fn(Cipher) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        match exchange(self.cipher().await?).await {
+            Err(Error::BraviaError(code, _)) if code.code == ENCRYPTION_FAILED => {
+                self.invalidate().await;
+                exchange(self.cipher().await?).await
+            }
+            resp => resp,
+        }
+    }
+
+    async fn new_cipher(&self, state: &mut SessionState) -> Result<Cipher> {
+        let public_key = match &state.public_key {
+            Some(public_key) => public_key.clone(),
+            None => self.service.get_public_key().await?,
+        };
+        let cipher = Cipher::new(&public_key)?;
+        state.public_key = Some(public_key);
+        state.cipher = Some(cipher.clone());
+        Ok(cipher)
+    }
+}
+
 /// Provides access to encryption service APIs.
-pub struct EncryptionService<'a>(&'a Bravia);
+pub struct EncryptionService(ServiceContext);
+
+impl_service_options!(EncryptionService);
 
-impl<'a> EncryptionService<'a> {
-    pub fn new(bravia: &'a Bravia) -> Self {
-        Self(bravia)
+impl EncryptionService {
+    pub fn new(bravia: &Bravia) -> Self {
+        Self(ServiceContext::new(bravia))
     }
 
     /// Requests the device to provide an RSA public key for encryption.
@@ -25,13 +234,34 @@ impl<'a> EncryptionService<'a> {
             .id(1)
             .method("getPublicKey")
             .build()?;
-        let req = RequestBuilder::default()
+        RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .has_result()
             .get("publicKey".into())
-            .make(self.0)
-            .await?;
-        Ok(serde_json::from_value(req)?)
+            .make_as(&self.0)
+            .await
+    }
+
+    /// Generates a [Cipher] with the public key returned by [getPublicKey](Self::get_public_key).\
+    /// A new cipher should be used for each exchange.\
+    /// Requires the `encryption` feature.
+    ///
+    /// # Authentication Level
+    /// None
+    #[cfg(feature = "encryption")]
+    pub async fn cipher(&self) -> Result<Cipher> {
+        Cipher::new(&self.get_public_key().await?)
+    }
+
+    /// Starts an [EncryptionSession] that reuses the public key and the common key
+    /// across the exchanges, with the options of this handle.\
+    /// Requires the `encryption` feature.
+    #[cfg(feature = "encryption")]
+    pub fn session(&self) -> EncryptionSession {
+        EncryptionSession {
+            service: Self(self.0.clone()),
+            state: Mutex::default(),
+        }
     }
 }
@@ -0,0 +1,241 @@
+//! Single pass parsing of the responses, the requested element of the result
+//! is deserialized straight from the body without building a [Value](serde_json::Value) first.
+
+use crate::{error::BraviaErrorCode, RequestGetElementType};
+use serde::de::{
+    DeserializeOwned, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor,
+};
+use serde::Deserialize;
+use std::{fmt, marker::PhantomData};
+
+/// Fields of a response body.
+pub(crate) struct ResultEnvelope<T> {
+    /// `Some` when the body has a `result` field, holding the requested element if it was found.
+    pub(crate) result: Option<Option<T>>,
+    pub(crate) error: Option<BraviaErrorCode>,
+}
+
+impl<T: DeserializeOwned> ResultEnvelope<T> {
+    /// Parses the body, keeping only the element of the result selected by `get`.\
+    /// The result is skipped if `get` is `None` or asks for a cookie.
+    pub(crate) fn parse(
+        text: &str,
+        get: Option<&RequestGetElementType<'_>>,
+    ) -> serde_json::Result<Self> {
+        let mut deserializer = serde_json::Deserializer::from_str(text);
+        let envelope = EnvelopeSeed {
+            get,
+            marker: PhantomData,
+        }
+        .deserialize(&mut deserializer)?;
+        deserializer.end()?;
+        Ok(envelope)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Field {
+    Result,
+    Error,
+    #[serde(other)]
+    Other,
+}
+
+struct EnvelopeSeed<'a, T> {
+    get: Option<&'a RequestGetElementType<'a>>,
+    marker: PhantomData<T>,
+}
+
+impl<'de, T: DeserializeOwned> DeserializeSeed<'de> for EnvelopeSeed<'_, T> {
+    type Value = ResultEnvelope<T>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de, T: DeserializeOwned> Visitor<'de> for EnvelopeSeed<'_, T> {
+    type Value = ResultEnvelope<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a response object")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut envelope = ResultEnvelope {
+            result: None,
+            error: None,
+        };
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::Result if envelope.result.is_none() => {
+                    envelope.result = Some(map.next_value_seed(ResultSeed {
+                        get: self.get,
+                        marker: PhantomData,
+                    })?);
+                }
+                Field::Error if envelope.error.is_none() => {
+                    envelope.error = Some(map.next_value()?);
+                }
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(envelope)
+    }
+
+    // Bodies that are not objects have neither a result nor an error
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        while seq.next_element::<IgnoredAny>()?.is_some() {}
+        Ok(ResultEnvelope {
+            result: None,
+            error: None,
+        })
+    }
+}
+
+// Selects the requested element of the `result` field.
+struct ResultSeed<'a, T> {
+    get: Option<&'a RequestGetElementType<'a>>,
+    marker: PhantomData<T>,
+}
+
+impl<'de, T: DeserializeOwned> DeserializeSeed<'de> for ResultSeed<'_, T> {
+    type Value = Option<T>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        match self.get {
+            Some(RequestGetElementType::All) => T::deserialize(deserializer).map(Some),
+            Some(RequestGetElementType::Index(index)) => {
+                deserializer.deserialize_any(ElementVisitor {
+                    index: *index,
+                    seed: Whole(PhantomData),
+                })
+            }
+            Some(RequestGetElementType::Text(name)) => {
+                deserializer.deserialize_any(ElementVisitor {
+                    index: 0,
+                    seed: Member {
+                        name,
+                        marker: PhantomData,
+                    },
+                })
+            }
+            Some(RequestGetElementType::Cookie(_)) | None => {
+                IgnoredAny::deserialize(deserializer)?;
+                Ok(None)
+            }
+        }
+    }
+}
+
+// Deserializes the whole value.
+struct Whole<T>(PhantomData<T>);
+
+impl<'de, T: DeserializeOwned> DeserializeSeed<'de> for Whole<T> {
+    type Value = Option<T>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        T::deserialize(deserializer).map(Some)
+    }
+}
+
+// Deserializes the value of the member `name` of an object, or of the first element of an array.
+struct Member<'a, T> {
+    name: &'a str,
+    marker: PhantomData<T>,
+}
+
+impl<'de, T: DeserializeOwned> DeserializeSeed<'de> for Member<'_, T> {
+    type Value = Option<T>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de, T: DeserializeOwned> Visitor<'de> for Member<'_, T> {
+    type Value = Option<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an object")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut value = None;
+        while let Some(matches) = map.next_key_seed(KeyMatcher(self.name))? {
+            if matches && value.is_none() {
+                value = Some(map.next_value()?);
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        Ok(value)
+    }
+
+    // Some firmwares nest the object in an array
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let value = seq.next_element_seed(self)?.flatten();
+        while seq.next_element::<IgnoredAny>()?.is_some() {}
+        Ok(value)
+    }
+}
+
+// Compares a key with the expected name without allocating it.
+struct KeyMatcher<'a>(&'a str);
+
+impl<'de> DeserializeSeed<'de> for KeyMatcher<'_> {
+    type Value = bool;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de> Visitor<'de> for KeyMatcher<'_> {
+    type Value = bool;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a key")
+    }
+
+    fn visit_str<E>(self, key: &str) -> Result<Self::Value, E> {
+        Ok(key == self.0)
+    }
+}
+
+// Deserializes the element at `index` of an array with `seed`, skipping the others.
+struct ElementVisitor<S> {
+    index: usize,
+    seed: S,
+}
+
+impl<'de, T, S: DeserializeSeed<'de, Value = Option<T>>> Visitor<'de> for ElementVisitor<S> {
+    type Value = Option<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an array")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        for _ in 0..self.index {
+            if seq.next_element::<IgnoredAny>()?.is_none() {
+                return Ok(None);
+            }
+        }
+        let value = seq.next_element_seed(self.seed)?.flatten();
+        while seq.next_element::<IgnoredAny>()?.is_some() {}
+        Ok(value)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        while map.next_entry::<IgnoredAny, IgnoredAny>()?.is_some() {}
+        Ok(None)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+}
@@ -1,8 +1,9 @@
 //! A composite error type for errors that can occur while interacting with the server.
 
+use crate::lenient;
 use derive_builder::UninitializedFieldError;
 use serde::Deserialize;
-use std::fmt;
+use std::{borrow::Cow, fmt};
 
 /// Alias to a Result containing a local Error type.
 pub type Result<T> = std::result::Result<T, Error>;
@@ -13,6 +14,7 @@ pub struct BraviaErrorCode {
     /// The numeric error code returned by the server.\
     /// For details on the error codes, please see the
     /// [official documentation](https://pro-bravia.sony.net/develop/integrate/rest-api/spec/errorcode-list/index.html).
+    #[serde(deserialize_with = "lenient::number")]
     pub code: usize,
     /// The error message returned by the server.
     pub message: String,
@@ -24,6 +26,46 @@ impl fmt::Display for BraviaErrorCode {
     }
 }
 
+/// Identifies the API call that caused an error.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CallContext {
+    /// Service endpoint, like `system`.
+    pub endpoint: String,
+    /// API name, like `getPowerStatus`.
+    pub method: String,
+    /// API version, like `1.0`.
+    pub version: String,
+}
+
+impl fmt::Display for CallContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{} v{}", self.endpoint, self.method, self.version)
+    }
+}
+
+// Formats the optional call context of an error.
+fn in_call(context: &Option<CallContext>) -> String {
+    context
+        .as_ref()
+        .map(|context| format!(" in {context}"))
+        .unwrap_or_default()
+}
+
+// Maximum length of the payload shown by the message of an error, in bytes.
+const DISPLAYED_PAYLOAD_LEN: usize = 256;
+
+// Shortens a payload to keep the message of an error readable.
+fn truncated(payload: &str) -> Cow<'_, str> {
+    if payload.len() <= DISPLAYED_PAYLOAD_LEN {
+        return Cow::Borrowed(payload);
+    }
+    let mut end = DISPLAYED_PAYLOAD_LEN;
+    while !payload.is_char_boundary(end) {
+        end -= 1;
+    }
+    Cow::Owned(format!("{}…", &payload[..end]))
+}
+
 /// A set of errors that can occur when interacting with the server.
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -33,21 +75,48 @@ pub enum Error {
     /// The web request experienced an error. The enclosed error was returned from `reqwest`.
     #[error("NetworkError: {}", _0)]
     NetworkError(#[from] reqwest::Error),
+    /// An I/O error occurred outside of the HTTP requests, like while sending a Wake-on-LAN packet.
+    #[error("I/O error: {}", _0)]
+    IoError(#[from] std::io::Error),
+    /// The WebSocket connection used for the notifications experienced an error.
+    #[cfg(all(feature = "notifications", not(target_arch = "wasm32")))]
+    #[error("WebSocket error: {}", _0)]
+    WebSocketError(Box<tokio_tungstenite::tungstenite::Error>),
+    /// The connection to the MQTT broker used by the bridge experienced an error.
+    #[cfg(all(feature = "mqtt", not(target_arch = "wasm32")))]
+    #[error("MQTT error: {}", _0)]
+    MqttError(String),
+    /// The parameters couldn't be encrypted or decrypted, like when the public key of the device is invalid.
+    #[cfg(feature = "encryption")]
+    #[error("Encryption error: {}", _0)]
+    EncryptionError(String),
     /// The response from the server gave a response code that indicated an error.
-    #[error("Error status received: {}", _0)]
-    BadStatus(reqwest::StatusCode),
+    #[error("Error status received in {}: {}", _1, _0)]
+    BadStatus(reqwest::StatusCode, CallContext),
     /// An expected value was missing from the response.
     #[error("Value missing from response: {}", _0)]
     MissingValue(&'static str),
     /// Invalid response format (`result` and `error` fields was missing).
     #[error("Invalid response received: {}", _0)]
     InvalidResponse(&'static str),
-    /// An error occurred while loading the JSON response. The enclosed error was returned from `serde_json`
-    #[error("JSON deserialize error: {}", _0)]
-    DeserializeError(#[from] serde_json::Error),
+    /// An error occurred while loading the JSON response. The enclosed error was returned from `serde_json`,
+    /// the call context is set when the error comes from the response of an API call.
+    #[error("JSON deserialize error{}: {}", in_call(_1), _0)]
+    DeserializeError(#[source] serde_json::Error, Option<CallContext>),
+    /// The response of an API call is valid JSON but doesn't have the expected format,
+    /// even after working around the known firmware quirks.
+    /// The enclosed string is the whole body of the response, to report the mismatch,
+    /// the message of the error shows only its first 256 bytes.
+    #[error(
+        "Unexpected response payload in {}: {}, received {}",
+        _1,
+        _0,
+        truncated(_2)
+    )]
+    UnexpectedPayload(#[source] serde_json::Error, CallContext, String),
     /// Errors returned by the server.
-    #[error("Error returned by Bravia: {}", _0)]
-    BraviaError(BraviaErrorCode),
+    #[error("Error returned by Bravia in {}: {}", _1, _0)]
+    BraviaError(BraviaErrorCode, CallContext),
     /// The requested API service was not found.
     #[error("API service not found.")]
     BraviaApiServiceNotFound,
@@ -57,7 +126,55 @@ pub enum Error {
     /// The requested API version was not supported by the server.
     #[error("This API version is not supported.")]
     BraviaApiLevelError,
+    /// A parameter passed to an API wrapper failed validation.
+    #[error("Invalid parameter: {}", _0)]
+    InvalidParameter(String),
+    /// The display didn't reach the requested state after a successful call,
+    /// like a different input being shown after selecting one.
+    #[error("Verification failed: {}", _0)]
+    VerificationFailed(String),
+    /// The display refused a Simple IP Control command, the enclosed value is the command name.
+    #[error("Simple IP Control command {} failed", _0)]
+    SimpleIpError(String),
+    /// No recorded interaction matches the request sent while replaying a [Vcr](crate::vcr::Vcr).
+    #[error("No recorded response for {}", _0)]
+    NotRecorded(CallContext),
     /// Wrong or absent password for the requested authentication level.
     #[error("A password is required in order to access this API")]
     BraviaAuthLevelError,
 }
+
+impl Error {
+    /// Returns the API call that caused the error, if known.
+    pub fn call_context(&self) -> Option<&CallContext> {
+        match self {
+            Self::BadStatus(_, context)
+            | Self::BraviaError(_, context)
+            | Self::UnexpectedPayload(_, context, _)
+            | Self::NotRecorded(context) => Some(context),
+            Self::DeserializeError(_, context) => context.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the body of the response that couldn't be deserialized, if known.
+    pub fn payload(&self) -> Option<&str> {
+        match self {
+            Self::UnexpectedPayload(_, _, payload) => Some(payload),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(all(feature = "notifications", not(target_arch = "wasm32")))]
+impl From<tokio_tungstenite::tungstenite::Error> for Error {
+    fn from(err: tokio_tungstenite::tungstenite::Error) -> Self {
+        Self::WebSocketError(Box::new(err))
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Self::DeserializeError(err, None)
+    }
+}
@@ -0,0 +1,334 @@
+//! Control of several displays at the same time, like a video wall.
+
+use crate::{
+    av_content::InputUri,
+    error::{Error, Result},
+    ircc::RemoteKey,
+    runtime::{self, Instant},
+    Bravia,
+};
+use std::{
+    future::{poll_fn, Future},
+    pin::{pin, Pin},
+    task::Poll,
+    time::Duration,
+};
+
+/// Outcome of a [synchronized](BraviaGroup::synchronized) change on a display.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum SyncStatus {
+    /// The change was committed.
+    Committed,
+    /// The change wasn't committed, because another display failed to prepare it.
+    Skipped,
+    /// The change was committed and then reverted, because another display failed to commit it
+    /// or didn't commit it in time.
+    RolledBack,
+}
+
+/// Sends the same commands to several displays concurrently.\
+/// Every method returns the result of each display, in the order they were added.
+///
+/// # Examples
+/// ```no_run
+/// # use bravia_api::{group::BraviaGroup, Bravia, error::Result};
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// let group = BraviaGroup::new(vec![
+///     Bravia::new("ADDRESS_1", Some("PASSWORD")).await?,
+///     Bravia::new("ADDRESS_2", Some("PASSWORD")).await?,
+/// ]);
+/// for (bravia, result) in group.devices().iter().zip(group.set_power(true).await) {
+///     if let Err(err) = result {
+///         println!("{:?}: {}", bravia, err);
+///     }
+/// }
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BraviaGroup {
+    devices: Vec<Bravia>,
+}
+
+impl BraviaGroup {
+    pub fn new(devices: impl IntoIterator<Item = Bravia>) -> Self {
+        Self {
+            devices: devices.into_iter().collect(),
+        }
+    }
+
+    /// Adds a display to the group.
+    pub fn push(&mut self, bravia: Bravia) {
+        self.devices.push(bravia);
+    }
+
+    /// Displays of the group.
+    pub fn devices(&self) -> &[Bravia] {
+        &self.devices
+    }
+
+    /// Calls `f` with each display and waits for all the returned futures.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{group::BraviaGroup, error::Result};
+    /// # async fn example(group: BraviaGroup) {
+    /// let inputs = group
+    ///     .for_each(|bravia| async move { bravia.av_content().get_playing_content_info().await })
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn for_each<F, Fut, T>(&self, f: F) -> Vec<Result<T>>
+    where
+        F: Fn(Bravia) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        join_all(self.devices.iter().cloned().map(f).collect()).await
+    }
+
+    /// Turns the displays on or puts them in standby.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn set_power(&self, status: bool) -> Vec<Result<()>> {
+        self.for_each(|bravia| async move { bravia.system().set_power_status(status).await })
+            .await
+    }
+
+    /// Reads the power status of the displays.
+    ///
+    /// # Authentication Level
+    /// None
+    pub async fn power_status(&self) -> Vec<Result<String>> {
+        self.for_each(|bravia| async move { bravia.system().get_power_status().await })
+            .await
+    }
+
+    /// Sets the volume of the speakers, see [set_audio_volume](crate::audio::AudioService::set_audio_volume)
+    /// for the accepted formats.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn set_volume(&self, volume: &str) -> Vec<Result<()>> {
+        self.for_each(|bravia| async move {
+            bravia
+                .audio()
+                .set_audio_volume(Some("speaker"), volume.to_string(), None, None)
+                .await
+        })
+        .await
+    }
+
+    /// Mutes or unmutes the audio.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn set_mute(&self, status: bool) -> Vec<Result<()>> {
+        self.for_each(|bravia| async move { bravia.audio().set_audio_mute(status).await })
+            .await
+    }
+
+    /// Switches the displays to the given external input.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn select_input(&self, input: InputUri) -> Vec<Result<()>> {
+        let input = &input;
+        self.for_each(
+            |bravia| async move { bravia.av_content().set_play_content(input.clone()).await },
+        )
+        .await
+    }
+
+    /// Makes the same change on all the displays at once, like switching the input of a video wall.\
+    /// The change is made in two phases:
+    /// 1. `prepare` is called with each display, usually to read the state to restore on failure.
+    /// 2. Once all the displays are prepared, `commit` is called with all of them at the same time.
+    ///
+    /// Nothing is committed if a display fails to prepare.
+    /// A commit that doesn't complete within `commit_timeout` is cancelled and fails with
+    /// [VerificationFailed](Error::VerificationFailed), its display is not rolled back
+    /// since it may have applied the change anyway.
+    /// If a display fails to commit, `rollback` is called with each display that committed
+    /// and the state returned by its `prepare`.
+    ///
+    /// Returns the status of each display, or the error of its prepare, commit or rollback.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{group::BraviaGroup, error::Result};
+    /// # use std::time::Duration;
+    /// # async fn example(group: BraviaGroup) {
+    /// let results = group
+    ///     .synchronized(
+    ///         |bravia| async move { bravia.audio().get_volume_information().await },
+    ///         |bravia| async move { bravia.audio().set_audio_mute(true).await },
+    ///         |bravia, outputs| async move {
+    ///             let muted = outputs.iter().any(|output| output.mute);
+    ///             bravia.audio().set_audio_mute(muted).await
+    ///         },
+    ///         Duration::from_secs(2),
+    ///     )
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn synchronized<S, P, PFut, C, CFut, R, RFut>(
+        &self,
+        prepare: P,
+        commit: C,
+        rollback: R,
+        commit_timeout: Duration,
+    ) -> Vec<Result<SyncStatus>>
+    where
+        P: Fn(Bravia) -> PFut,
+        PFut: Future<Output = Result<S>>,
+        C: Fn(Bravia) -> CFut,
+        CFut: Future<Output = Result<()>>,
+        R: Fn(Bravia, S) -> RFut,
+        RFut: Future<Output = Result<()>>,
+    {
+        // No display commits before all of them are prepared
+        let prepared = self.for_each(prepare).await;
+        if prepared.iter().any(Result::is_err) {
+            return prepared
+                .into_iter()
+                .map(|result| result.map(|_| SyncStatus::Skipped))
+                .collect();
+        }
+        let states: Vec<S> = prepared.into_iter().flatten().collect();
+
+        // A slow display can't hold the others, nor delay their rollback
+        let deadline = Instant::now() + commit_timeout;
+        let commit = &commit;
+        let committed = self
+            .for_each(|bravia| async move {
+                until(deadline, commit(bravia)).await.unwrap_or_else(|| {
+                    Err(Error::VerificationFailed(format!(
+                        "not committed within {commit_timeout:?}"
+                    )))
+                })
+            })
+            .await;
+        if committed.iter().all(Result::is_ok) {
+            return committed
+                .into_iter()
+                .map(|result| result.map(|()| SyncStatus::Committed))
+                .collect();
+        }
+        let rollback = &rollback;
+        let rollbacks = self
+            .devices
+            .iter()
+            .cloned()
+            .zip(states)
+            .zip(committed)
+            .map(|((bravia, state), result)| async move {
+                result?;
+                rollback(bravia, state).await?;
+                Ok(SyncStatus::RolledBack)
+            })
+            .collect();
+        join_all(rollbacks).await
+    }
+
+    /// Switches all the displays to the given external input at the same time,
+    /// see [synchronized](Self::synchronized).\
+    /// If a display fails, the others are switched back to the content they were playing.
+    /// The displays that were showing the home screen or an application are brought back
+    /// to the home screen through [IRCC](crate::ircc::IrccService).
+    ///
+    /// # Authentication Level
+    /// Private
+    pub async fn select_input_synchronized(
+        &self,
+        input: InputUri,
+        commit_timeout: Duration,
+    ) -> Vec<Result<SyncStatus>> {
+        let input = &input;
+        self.synchronized(
+            |bravia| async move {
+                match bravia.av_content().get_playing_content_info().await {
+                    Ok(content) => Ok(Some(content.uri)),
+                    // The server returns an error when there is no content to describe
+                    Err(Error::BraviaError(..)) => Ok(None),
+                    Err(err) => Err(err),
+                }
+            },
+            |bravia| async move { bravia.av_content().set_play_content(input.clone()).await },
+            |bravia, previous| async move {
+                match previous {
+                    Some(uri) => bravia.av_content().set_play_content(uri).await,
+                    None => bravia.ircc().send(RemoteKey::Home).await,
+                }
+            },
+            commit_timeout,
+        )
+        .await
+    }
+
+    /// Turns all the displays on or puts them in standby at the same time,
+    /// see [synchronized](Self::synchronized).\
+    /// If a display fails, the others are brought back to their previous power status.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn set_power_synchronized(
+        &self,
+        status: bool,
+        commit_timeout: Duration,
+    ) -> Vec<Result<SyncStatus>> {
+        self.synchronized(
+            |bravia| async move { bravia.system().get_power_status().await },
+            |bravia| async move { bravia.system().set_power_status(status).await },
+            |bravia, previous| async move {
+                bravia.system().set_power_status(previous == "active").await
+            },
+            commit_timeout,
+        )
+        .await
+    }
+}
+
+impl FromIterator<Bravia> for BraviaGroup {
+    fn from_iter<I: IntoIterator<Item = Bravia>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+// Polls all the futures on the current task, keeping the results in order.
+async fn join_all<F: Future>(futures: Vec<F>) -> Vec<F::Output> {
+    let mut futures: Vec<_> = futures.into_iter().map(Box::pin).collect();
+    let mut outputs: Vec<Option<F::Output>> = futures.iter().map(|_| None).collect();
+    poll_fn(|cx| {
+        let mut done = true;
+        for (future, output) in futures.iter_mut().zip(outputs.iter_mut()) {
+            if output.is_none() {
+                match Pin::as_mut(future).poll(cx) {
+                    Poll::Ready(value) => *output = Some(value),
+                    Poll::Pending => done = false,
+                }
+            }
+        }
+        if done {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    })
+    .await;
+    outputs.into_iter().flatten().collect()
+}
+
+// Waits for the future until the deadline, `None` if the deadline expires first.
+async fn until<F: Future>(deadline: Instant, future: F) -> Option<F::Output> {
+    let mut future = pin!(future);
+    let mut timer = pin!(runtime::sleep(
+        deadline.saturating_duration_since(Instant::now())
+    ));
+    poll_fn(|cx| match future.as_mut().poll(cx) {
+        Poll::Ready(output) => Poll::Ready(Some(output)),
+        Poll::Pending => timer.as_mut().poll(cx).map(|()| None),
+    })
+    .await
+}
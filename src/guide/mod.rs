@@ -2,7 +2,7 @@
 
 use crate::{
     error::{Error, Result},
-    Bravia, RequestBodyBuilder, RequestBuilder,
+    Bravia, RequestBodyBuilder, RequestBuilder, RequestGetElementType, ServiceContext,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
@@ -43,6 +43,13 @@ pub struct Api {
     pub versions: Vec<Versions>,
 }
 
+impl Api {
+    /// Highest supported version, `None` if no version is listed.
+    pub fn max_version(&self) -> Option<&str> {
+        max_version(&self.versions)
+    }
+}
+
 impl Ord for Api {
     fn cmp(&self, other: &Self) -> Ordering {
         let self_max = self.versions.iter().max();
@@ -65,6 +72,13 @@ pub struct Notifications {
     pub versions: Vec<Versions>,
 }
 
+impl Notifications {
+    /// Highest supported version, `None` if no version is listed.
+    pub fn max_version(&self) -> Option<&str> {
+        max_version(&self.versions)
+    }
+}
+
 impl Ord for Notifications {
     fn cmp(&self, other: &Self) -> Ordering {
         let self_max = self.versions.iter().max();
@@ -91,12 +105,102 @@ pub struct ServiceData {
     pub protocols: Vec<String>,
 }
 
+impl ServiceData {
+    /// Returns the API with the given name, if supported.
+    pub fn api(&self, name: &str) -> Option<&Api> {
+        self.apis.iter().find(|api| api.name == name)
+    }
+
+    /// Returns the notification with the given name, if supported.
+    pub fn notification(&self, name: &str) -> Option<&Notifications> {
+        self.notifications
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .find(|notification| notification.name == name)
+    }
+}
+
+/// Services returned by [getSupportedApiInfo](GuideService::get_supported_api_info),
+/// with lookups by name.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ApiCatalog(Vec<ServiceData>);
+
+impl ApiCatalog {
+    /// All the services of the catalog.
+    pub fn services(&self) -> &[ServiceData] {
+        &self.0
+    }
+
+    /// Returns the service with the given name, if supported.
+    pub fn service(&self, name: &str) -> Option<&ServiceData> {
+        self.0.iter().find(|service| service.service == name)
+    }
+
+    /// Returns an API of a service, if supported.
+    ///
+    /// # Arguments
+    /// * `service` - Name of the service (e.g. `avContent`).
+    /// * `api` - Name of the API (e.g. `getCurrentExternalInputsStatus`).
+    pub fn api(&self, service: &str, api: &str) -> Option<&Api> {
+        self.service(service)?.api(api)
+    }
+
+    /// Returns the highest supported version of an API of a service,
+    /// `None` if the service or the API are not supported.
+    ///
+    /// # Arguments
+    /// * `service` - Name of the service (e.g. `avContent`).
+    /// * `api` - Name of the API (e.g. `getCurrentExternalInputsStatus`).
+    pub fn max_version(&self, service: &str, api: &str) -> Option<&str> {
+        self.api(service, api)?.max_version()
+    }
+}
+
+impl From<Vec<ServiceData>> for ApiCatalog {
+    fn from(services: Vec<ServiceData>) -> Self {
+        Self(services)
+    }
+}
+
+impl From<ApiCatalog> for Vec<ServiceData> {
+    fn from(catalog: ApiCatalog) -> Self {
+        catalog.0
+    }
+}
+
+fn max_version(versions: &[Versions]) -> Option<&str> {
+    versions.iter().max().map(|x| x.version.as_str())
+}
+
+/// Transports supported by a service, returned by
+/// [getServiceProtocols](GuideService::get_service_protocols).
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ServiceProtocols {
+    /// Name of the service.
+    pub service: String,
+    /// Supported transports, like `xhrpost:jsonizer` or `websocket:jsonizer`.
+    pub protocols: Vec<String>,
+}
+
+impl ServiceProtocols {
+    /// Checks if the service can be reached with a WebSocket, like to receive the notifications.
+    pub fn supports_websocket(&self) -> bool {
+        self.protocols
+            .iter()
+            .any(|protocol| protocol.starts_with("websocket:"))
+    }
+}
+
 /// Provides access to guide service APIs.
-pub struct GuideService<'a>(&'a Bravia);
+pub struct GuideService(ServiceContext);
+
+impl_service_options!(GuideService);
 
-impl<'a> GuideService<'a> {
-    pub fn new(bravia: &'a Bravia) -> Self {
-        Self(bravia)
+impl GuideService {
+    pub fn new(bravia: &Bravia) -> Self {
+        Self(ServiceContext::new(bravia))
     }
 
     /// This API provides the supported services and their information.
@@ -105,7 +209,7 @@ impl<'a> GuideService<'a> {
     ///
     /// # Arguments
     /// * `services` - Services to fetch API information.\
-    /// None or empty vectors are treated as all services.
+    ///   None or empty vectors are treated as all services.
     ///
     /// # Authentication Level
     /// None
@@ -116,7 +220,8 @@ impl<'a> GuideService<'a> {
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
     /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
-    /// let service = bravia.guide().get_supported_api_info(Some(vec!["avContent".to_string()])).await?;
+    /// // See also max_version and api_catalog
+    /// let service = bravia.guide().get_supported_api_info(Some(&["avContent"])).await?;
     /// let api_name = "getCurrentExternalInputsStatus";
     /// let api = service.get(0).ok_or(Error::MissingValue("getCurrentExternalInputsStatus service data"))?
     ///     .apis.iter().find(|&x| x.name == api_name);
@@ -130,7 +235,76 @@ impl<'a> GuideService<'a> {
     /// ```
     pub async fn get_supported_api_info(
         &self,
-        services: Option<Vec<String>>,
+        services: Option<&[&str]>,
+    ) -> Result<Vec<ServiceData>> {
+        let parsed = self.fetch_supported_api_info(services).await?;
+        if parsed.is_empty() {
+            Err(Error::MissingValue("apis"))
+        } else {
+            Ok(parsed)
+        }
+    }
+
+    /// Provides the supported services and their information, like
+    /// [get_supported_api_info](Self::get_supported_api_info), with lookups by name.
+    ///
+    /// # Arguments
+    /// * `services` - Services to fetch API information.\
+    ///   None or empty vectors are treated as all services.
+    ///
+    /// # Authentication Level
+    /// None
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// let catalog = bravia.guide().api_catalog(None).await?;
+    /// if let Some(api) = catalog.api("avContent", "getCurrentExternalInputsStatus") {
+    ///     println!("{} versions: {}", api.name, api.versions.len());
+    /// }
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn api_catalog(&self, services: Option<&[&str]>) -> Result<ApiCatalog> {
+        self.fetch_supported_api_info(services)
+            .await
+            .map(ApiCatalog::from)
+    }
+
+    /// Provides the highest version of an API supported by the server,
+    /// `None` if the service or the API are not supported.
+    ///
+    /// # Arguments
+    /// * `service` - Name of the service (e.g. `avContent`).
+    /// * `api` - Name of the API (e.g. `getCurrentExternalInputsStatus`).
+    ///
+    /// # Authentication Level
+    /// None
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// let api_name = "getCurrentExternalInputsStatus";
+    /// if let Some(max) = bravia.guide().max_version("avContent", api_name).await? {
+    ///     println!("Max {} version: {}", api_name, max);
+    /// }
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn max_version(&self, service: &str, api: &str) -> Result<Option<String>> {
+        let catalog = self.api_catalog(Some(&[service])).await?;
+        Ok(catalog.max_version(service, api).map(String::from))
+    }
+
+    async fn fetch_supported_api_info(
+        &self,
+        services: Option<&[&str]>,
     ) -> Result<Vec<ServiceData>> {
         let mut params = Map::new();
         if let Some(services) = services {
@@ -143,18 +317,44 @@ impl<'a> GuideService<'a> {
             .params(Value::from(params))
             .build()?;
 
-        let req = RequestBuilder::default()
+        RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .has_result()
-            .make(self.0)
-            .await?;
+            .make_as(&self.0)
+            .await
+    }
 
-        let parsed: Vec<ServiceData> = serde_json::from_value(req)?;
-        if parsed.is_empty() {
-            Err(Error::MissingValue("apis"))
-        } else {
-            Ok(parsed)
-        }
+    /// Provides the services of the server and the transports supported by each one.
+    ///
+    /// # Authentication Level
+    /// None
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// for service in bravia.guide().get_service_protocols().await? {
+    ///     if service.supports_websocket() {
+    ///         println!("{} can send notifications", service.service);
+    ///     }
+    /// }
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn get_service_protocols(&self) -> Result<Vec<ServiceProtocols>> {
+        let body = RequestBodyBuilder::default()
+            .id(2)
+            .method("getServiceProtocols")
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .has_result()
+            .get(RequestGetElementType::All)
+            .make_as(&self.0)
+            .await
     }
 }
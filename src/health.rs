@@ -0,0 +1,144 @@
+//! Reachability and power checks, useful to monitor the displays.
+
+use crate::{
+    error::{Error, Result},
+    retry::RetryPolicy,
+    runtime::{self, Instant},
+    system::PowerState,
+    Bravia, RequestOptions,
+};
+use std::time::Duration;
+
+// Time between the checks of the power status.
+const POWER_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Result of a [ping](Bravia::ping).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct HealthStatus {
+    /// The server answered the request, even with an error.
+    pub reachable: bool,
+    /// Current power status, like `active` or `standby`.\
+    /// `None` if the server couldn't provide it.
+    pub power: Option<String>,
+    /// Time taken by the server to answer.\
+    /// `None` if the server is not reachable.
+    pub latency: Option<Duration>,
+}
+
+impl Bravia {
+    /// Checks if the server is reachable with a single
+    /// [getPowerStatus](crate::system::SystemService::get_power_status) request.\
+    /// The request is never retried.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new_unchecked("ADDRESS", None)?;
+    /// let health = bravia.ping().await;
+    /// if health.reachable {
+    ///     println!("{:?} in {:?}", health.power, health.latency);
+    /// }
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn ping(&self) -> HealthStatus {
+        let start = Instant::now();
+        let power = self
+            .system()
+            .with_options(RequestOptions {
+                retry_policy: Some(RetryPolicy::none()),
+                ..Default::default()
+            })
+            .get_power_status()
+            .await;
+        let latency = start.elapsed();
+
+        match power {
+            Ok(power) => HealthStatus {
+                reachable: true,
+                power: Some(power),
+                latency: Some(latency),
+            },
+            // The request didn't reach the server or the connection was dropped
+            Err(Error::NetworkError(_)) => HealthStatus {
+                reachable: false,
+                power: None,
+                latency: None,
+            },
+            Err(_) => HealthStatus {
+                reachable: true,
+                power: None,
+                latency: Some(latency),
+            },
+        }
+    }
+
+    /// Checks if the display is turned on.
+    ///
+    /// # Authentication Level
+    /// None
+    pub async fn is_on(&self) -> Result<bool> {
+        Ok(self.system().get_power_status().await? == PowerState::Active.as_str())
+    }
+
+    /// Waits until the display reaches the given power state, polling its power status.\
+    /// The errors returned while the display changes state are ignored,
+    /// each request is never retried and is cancelled when `timeout` expires.
+    ///
+    /// # Errors
+    /// [VerificationFailed](Error::VerificationFailed) if the display isn't in the given
+    /// state by the last poll that can be made before `timeout` expires.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{system::PowerState, Bravia, error::Result};
+    /// # use std::time::Duration;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// bravia.system().set_power_status(true).await?;
+    /// bravia
+    ///     .await_power_state(PowerState::Active, Duration::from_secs(10))
+    ///     .await?;
+    /// bravia.av_content().select_hdmi(1, false).await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn await_power_state(&self, state: PowerState, timeout: Duration) -> Result<()> {
+        self.poll_power_state(&RequestOptions::default(), state, timeout)
+            .await
+    }
+
+    // Polls the power status with the given options, the timeout of each request
+    // is capped by the time left before the deadline.
+    pub(crate) async fn poll_power_state(
+        &self,
+        options: &RequestOptions,
+        state: PowerState,
+        timeout: Duration,
+    ) -> Result<()> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            // A request that hangs while the display changes state can't outlive the deadline
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let system = self.system().with_options(RequestOptions {
+                timeout: Some(options.timeout.map_or(remaining, |t| t.min(remaining))),
+                retry_policy: Some(RetryPolicy::none()),
+            });
+            let last = match system.get_power_status().await {
+                Ok(current) if current == state.as_str() => return Ok(()),
+                Ok(current) => format!("last status: {current}"),
+                Err(err) => format!("last error: {err}"),
+            };
+            // The next poll would start after the deadline
+            if Instant::now() + POWER_POLL_INTERVAL >= deadline {
+                return Err(Error::VerificationFailed(format!(
+                    "did not become {state} within {timeout:?}; {last}"
+                )));
+            }
+            runtime::sleep(POWER_POLL_INTERVAL).await;
+        }
+    }
+}
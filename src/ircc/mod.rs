@@ -0,0 +1,247 @@
+//! Sony's IRCC-IP remote control, used to send the same commands as the physical remote.\
+//! Many actions, like navigating the menus, are only possible with these commands.
+
+use crate::{
+    error::{CallContext, Error, Result},
+    vcr::Interaction,
+    Bravia, ServiceContext,
+};
+use reqwest::{header::CONTENT_TYPE, StatusCode};
+use serde_json::Value;
+use std::collections::HashMap;
+
+const ENDPOINT: &str = "ircc";
+const SOAP_ACTION: &str = "\"urn:schemas-sony-com:service:IRCC:1#X_SendIRCC\"";
+
+/// Keys of the remote control.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum RemoteKey {
+    Power,
+    PowerOff,
+    Home,
+    Return,
+    Options,
+    ActionMenu,
+    Display,
+    Guide,
+    Input,
+    Up,
+    Down,
+    Left,
+    Right,
+    Confirm,
+    Num0,
+    Num1,
+    Num2,
+    Num3,
+    Num4,
+    Num5,
+    Num6,
+    Num7,
+    Num8,
+    Num9,
+    VolumeUp,
+    VolumeDown,
+    Mute,
+    ChannelUp,
+    ChannelDown,
+    Play,
+    Pause,
+    Stop,
+    Forward,
+    Rewind,
+    Subtitle,
+    Hdmi1,
+    Hdmi2,
+    Hdmi3,
+    Hdmi4,
+    Netflix,
+    YouTube,
+}
+
+impl RemoteKey {
+    /// IRCC code of the key.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Power => "AAAAAQAAAAEAAAAVAw==",
+            Self::PowerOff => "AAAAAQAAAAEAAAAvAw==",
+            Self::Home => "AAAAAQAAAAEAAABgAw==",
+            Self::Return => "AAAAAgAAAJcAAAAjAw==",
+            Self::Options => "AAAAAgAAAJcAAAA2Aw==",
+            Self::ActionMenu => "AAAAAgAAAMQAAABLAw==",
+            Self::Display => "AAAAAQAAAAEAAAA6Aw==",
+            Self::Guide => "AAAAAgAAAKQAAABbAw==",
+            Self::Input => "AAAAAQAAAAEAAAAlAw==",
+            Self::Up => "AAAAAQAAAAEAAAB0Aw==",
+            Self::Down => "AAAAAQAAAAEAAAB1Aw==",
+            Self::Left => "AAAAAQAAAAEAAAA0Aw==",
+            Self::Right => "AAAAAQAAAAEAAAAzAw==",
+            Self::Confirm => "AAAAAQAAAAEAAABlAw==",
+            Self::Num0 => "AAAAAQAAAAEAAAAJAw==",
+            Self::Num1 => "AAAAAQAAAAEAAAAAAw==",
+            Self::Num2 => "AAAAAQAAAAEAAAABAw==",
+            Self::Num3 => "AAAAAQAAAAEAAAACAw==",
+            Self::Num4 => "AAAAAQAAAAEAAAADAw==",
+            Self::Num5 => "AAAAAQAAAAEAAAAEAw==",
+            Self::Num6 => "AAAAAQAAAAEAAAAFAw==",
+            Self::Num7 => "AAAAAQAAAAEAAAAGAw==",
+            Self::Num8 => "AAAAAQAAAAEAAAAHAw==",
+            Self::Num9 => "AAAAAQAAAAEAAAAIAw==",
+            Self::VolumeUp => "AAAAAQAAAAEAAAASAw==",
+            Self::VolumeDown => "AAAAAQAAAAEAAAATAw==",
+            Self::Mute => "AAAAAQAAAAEAAAAUAw==",
+            Self::ChannelUp => "AAAAAQAAAAEAAAAQAw==",
+            Self::ChannelDown => "AAAAAQAAAAEAAAARAw==",
+            Self::Play => "AAAAAgAAAJcAAAAaAw==",
+            Self::Pause => "AAAAAgAAAJcAAAAZAw==",
+            Self::Stop => "AAAAAgAAAJcAAAAYAw==",
+            Self::Forward => "AAAAAgAAAJcAAAAcAw==",
+            Self::Rewind => "AAAAAgAAAJcAAAAbAw==",
+            Self::Subtitle => "AAAAAgAAAJcAAAAoAw==",
+            Self::Hdmi1 => "AAAAAgAAABoAAABaAw==",
+            Self::Hdmi2 => "AAAAAgAAABoAAABbAw==",
+            Self::Hdmi3 => "AAAAAgAAABoAAABcAw==",
+            Self::Hdmi4 => "AAAAAgAAABoAAABdAw==",
+            Self::Netflix => "AAAAAgAAABoAAAB8Aw==",
+            Self::YouTube => "AAAAAgAAAMQAAABHAw==",
+        }
+    }
+}
+
+/// Provides access to the IRCC-IP remote control.\
+/// The requests are never retried, since a key could be pressed twice.
+pub struct IrccService(ServiceContext);
+
+impl_service_options!(IrccService);
+
+impl IrccService {
+    pub fn new(bravia: &Bravia) -> Self {
+        Self(ServiceContext::new(bravia))
+    }
+
+    /// Presses a key of the remote control.
+    ///
+    /// # Arguments
+    /// * `key` - Key to press.
+    ///
+    /// # Authentication Level
+    /// Private
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{ircc::RemoteKey, Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// bravia.ircc().send(RemoteKey::Home).await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn send(&self, key: RemoteKey) -> Result<()> {
+        self.send_ircc(key.code()).await
+    }
+
+    /// Sends a raw IRCC code, like the ones returned by
+    /// [getRemoteControllerInfo](crate::system::SystemService::get_remote_controller_info).
+    ///
+    /// # Arguments
+    /// * `code` - Base64 encoded IRCC code.
+    ///
+    /// # Errors
+    /// Returns [Error::InvalidParameter] if the code is not valid base64.
+    ///
+    /// # Authentication Level
+    /// Private
+    pub async fn send_code(&self, code: &str) -> Result<()> {
+        let is_base64 = |c: char| c.is_ascii_alphanumeric() || matches!(c, '+' | '/' | '=');
+        if code.is_empty() || !code.chars().all(is_base64) {
+            return Err(Error::InvalidParameter(format!(
+                "invalid IRCC code {code:?}"
+            )));
+        }
+        self.send_ircc(code).await
+    }
+
+    /// Provides the IRCC codes supported by the device, keyed by the name of the button.\
+    /// The codes can be sent with [send_code](Self::send_code).
+    ///
+    /// # Authentication Level
+    /// None
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// let ircc = bravia.ircc();
+    /// let codes = ircc.remote_codes().await?;
+    /// if let Some(code) = codes.get("Netflix") {
+    ///     ircc.send_code(code).await?;
+    /// }
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn remote_codes(&self) -> Result<HashMap<String, String>> {
+        let actions = self
+            .0
+            .bravia
+            .system()
+            .with_options(self.0.options.clone())
+            .get_remote_controller_info()
+            .await?;
+        Ok(actions
+            .into_iter()
+            .map(|action| (action.name, action.value))
+            .collect())
+    }
+
+    // Sends the SOAP request with the given IRCC code.
+    async fn send_ircc(&self, code: &str) -> Result<()> {
+        let bravia = &self.0.bravia;
+        let target = bravia.target();
+        let auth = target.auth.as_ref().ok_or(Error::BraviaAuthLevelError)?;
+        let body = format!(
+            "<?xml version=\"1.0\"?>\
+            <s:Envelope xmlns:s=\"http://schemas.xmlsoap.org/soap/envelope/\" \
+            s:encodingStyle=\"http://schemas.xmlsoap.org/soap/encoding/\">\
+            <s:Body><u:X_SendIRCC xmlns:u=\"urn:schemas-sony-com:service:IRCC:1\">\
+            <IRCCCode>{code}</IRCCCode>\
+            </u:X_SendIRCC></s:Body></s:Envelope>"
+        );
+        let url = format!("{}{}", target.base_url, ENDPOINT);
+        let call = || CallContext {
+            endpoint: String::from(ENDPOINT),
+            method: String::from("X_SendIRCC"),
+            version: String::from("1.0"),
+        };
+        let request = Value::from(body.as_str());
+
+        let _permit = bravia.request_slot().await;
+        let interaction = bravia
+            .replay_or_send(ENDPOINT, &request, call, async {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(url, code, "sending IRCC code");
+                let resp = bravia
+                    .post(&url, Some(auth), &self.0.options)
+                    .header(CONTENT_TYPE, "text/xml; charset=UTF-8")
+                    .header("SOAPACTION", SOAP_ACTION)
+                    .body(body)
+                    .send()
+                    .await?;
+                Ok(Interaction {
+                    endpoint: String::from(ENDPOINT),
+                    request: request.clone(),
+                    status: resp.status().as_u16(),
+                    response: resp.text().await?,
+                    cookies: Vec::new(),
+                })
+            })
+            .await?;
+        match StatusCode::from_u16(interaction.status) {
+            Ok(StatusCode::OK) => Ok(()),
+            Ok(status) => Err(Error::BadStatus(status, call())),
+            Err(_) => Err(Error::InvalidResponse("Invalid HTTP status.")),
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! Tolerant deserialization of the fields that the firmwares don't return consistently,
+//! like numbers returned as strings or flags returned as `"on"`.
+
+use crate::RequestGetElementType;
+use serde::{de::DeserializeOwned, Deserialize, Deserializer};
+use serde_json::Value;
+use std::{fmt::Display, str::FromStr};
+
+/// Optional flag returned as a boolean or as a string, unknown values are `None`.
+pub(crate) fn flag<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<bool>, D::Error> {
+    Ok(Option::<Value>::deserialize(deserializer)?.and_then(|value| parse_bool(&value)))
+}
+
+/// Flag returned as a boolean, a string or a number.
+pub(crate) fn bool<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<bool, D::Error> {
+    let value = Value::deserialize(deserializer)?;
+    parse_bool(&value)
+        .ok_or_else(|| serde::de::Error::custom(format!("expected a flag, found {value}")))
+}
+
+/// Number returned as a number or as a string.
+pub(crate) fn number<'de, D, T>(deserializer: D) -> std::result::Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned + FromStr,
+    T::Err: Display,
+{
+    match Value::deserialize(deserializer)? {
+        Value::String(text) => text.trim().parse().map_err(serde::de::Error::custom),
+        value => T::deserialize(value).map_err(serde::de::Error::custom),
+    }
+}
+
+/// Optional number returned as a number or as a string, empty strings are `None`.
+pub(crate) fn optional_number<'de, D, T>(
+    deserializer: D,
+) -> std::result::Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned + FromStr,
+    T::Err: Display,
+{
+    match Option::<Value>::deserialize(deserializer)? {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(text)) if text.trim().is_empty() => Ok(None),
+        Some(value) => number(value).map(Some).map_err(serde::de::Error::custom),
+    }
+}
+
+/// Text returned as a string or as a number, `null` is an empty string.
+pub(crate) fn string<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<String, D::Error> {
+    match Value::deserialize(deserializer)? {
+        Value::String(text) => Ok(text),
+        Value::Null => Ok(String::new()),
+        value @ (Value::Number(_) | Value::Bool(_)) => Ok(value.to_string()),
+        value => Err(serde::de::Error::custom(format!(
+            "expected a string, found {value}"
+        ))),
+    }
+}
+
+fn parse_bool(value: &Value) -> Option<bool> {
+    match value {
+        Value::Bool(flag) => Some(*flag),
+        Value::Number(number) => number.as_u64().map(|number| number != 0),
+        Value::String(text) => match text.trim().to_ascii_lowercase().as_str() {
+            "true" | "on" | "yes" | "1" => Some(true),
+            "false" | "off" | "no" | "0" => Some(false),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Reads the requested element of a result that some firmwares nest one level deeper,
+/// like `[[[{"uri": "..."}]]]` instead of `[[{"uri": "..."}]]`.\
+/// Returns `None` if the element isn't nested or still can't be deserialized.
+/// The named members are looked up in the nested arrays while parsing the envelope.
+pub(crate) fn unnest<T: DeserializeOwned>(
+    text: &str,
+    get: Option<&RequestGetElementType<'_>>,
+) -> Option<T> {
+    let mut body: Value = serde_json::from_str(text).ok()?;
+    let result = body.get_mut("result")?.take();
+    let element = match get? {
+        RequestGetElementType::All => result,
+        RequestGetElementType::Index(index) => result.get(*index)?.clone(),
+        RequestGetElementType::Text(_) | RequestGetElementType::Cookie(_) => return None,
+    };
+    match element {
+        Value::Array(mut elements) if elements.len() == 1 => T::deserialize(elements.pop()?).ok(),
+        _ => None,
+    }
+}
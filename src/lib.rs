@@ -18,39 +18,156 @@
 //! #    Ok(())
 //! # }
 //! ```
+//!
+//! # Features
+//! * `native-tls` - Enables `https://` addresses using the system TLS library.
+//! * `rustls-tls` - Enables `https://` addresses using rustls.
+//! * `chrono` - Adds [chrono](https://docs.rs/chrono) conversions of the dates returned by the server.
+//! * `cli` - Builds the `bravia` command line tool, see `bravia --help`.
+//! * `encryption` - Encrypts the parameters of the APIs that support it, like
+//!   [set_text_form_encrypted](app_control::AppControlService::set_text_form_encrypted).
+//! * `mqtt` - Enables the [MQTT bridge](mqtt), not available on `wasm32`.
+//! * `notifications` - Enables the [WebSocket notifications](notifications), not available on `wasm32`.
+//! * `simulator` - Enables the [simulated display](simulator), not available on `wasm32`.
+//! * `test-util` - Enables the [mock server](test_util) to test the applications, not available on `wasm32`.
+//! * `tracing` - Instruments the requests with [tracing](https://docs.rs/tracing) spans and events.
+//!
+//! # WebAssembly
+//! The crate can be compiled for the `wasm32-unknown-unknown` target, in which case the requests
+//! are made with the browser's `fetch` API. The timeouts and the proxy can't be configured
+//! on this target, since they are handled by the browser, and the displays can't be woken up
+//! with Wake-on-LAN.
 
 #![warn(clippy::all, clippy::unwrap_used)]
 #![allow(clippy::missing_errors_doc)]
 
-use app_control::AppControlService;
+use access_control::AccessControlService;
+use app_control::{AppControlService, Application};
 use audio::AudioService;
-use av_content::AvContentService;
+use av_content::{AvContentService, Content};
+use browser::BrowserService;
+use cec::CecService;
+use content_share::ContentShareService;
 use derive_builder::Builder;
+use device_info::DeviceInfo;
 use encryption::EncryptionService;
-use error::{Error, Result};
+use envelope::ResultEnvelope;
+use error::{CallContext, Error, Result};
 use guide::GuideService;
-use reqwest::{header::CONTENT_TYPE, Client, StatusCode};
-use serde::Serialize;
+use ircc::IrccService;
+use metrics::Recorder;
+#[cfg(all(feature = "notifications", not(target_arch = "wasm32")))]
+use notifications::NotificationService;
+use rate_limit::RateLimiter;
+use recording::RecordingService;
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::Proxy;
+use reqwest::{
+    header::{CONTENT_TYPE, COOKIE, SET_COOKIE},
+    Client, StatusCode, Url,
+};
+use retry::RetryPolicy;
+#[cfg(not(target_arch = "wasm32"))]
+use retry::WakePolicy;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use settings::SettingsService;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, PoisonError, RwLock},
+    time::Duration,
+};
 use system::SystemService;
+use tokio::sync::{OnceCell, Semaphore, SemaphorePermit};
+use vcr::{Interaction, Vcr};
 use video::VideoService;
 use video_screen::VideoScreenService;
 
+// Adds `with_options` and `with_timeout` to a service handle wrapping a [ServiceContext].
+macro_rules! impl_service_options {
+    ($service:ident) => {
+        impl $service {
+            /// Applies the given options to all the requests made through this handle.
+            pub fn with_options(mut self, options: $crate::RequestOptions) -> Self {
+                self.0.options = options;
+                self
+            }
+
+            /// Sets the timeout of all the requests made through this handle,
+            /// overriding the one configured on the client.
+            pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+                self.0.options.timeout = Some(timeout);
+                self
+            }
+        }
+    };
+}
+
+pub mod access_control;
 pub mod app_control;
 pub mod audio;
 pub mod av_content;
+pub mod browser;
+pub mod cec;
+pub mod content_share;
+pub mod credentials;
+pub mod device_info;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod discovery;
 pub mod encryption;
+mod envelope;
 pub mod error;
+pub mod group;
 pub mod guide;
+pub mod health;
+pub mod ircc;
+mod lenient;
+pub mod metrics;
+#[cfg(all(feature = "mqtt", not(target_arch = "wasm32")))]
+pub mod mqtt;
+#[cfg(all(feature = "notifications", not(target_arch = "wasm32")))]
+pub mod notifications;
+pub mod profile;
+mod rate_limit;
+pub mod recording;
+pub mod retry;
+mod runtime;
+pub mod settings;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod simple_ip;
+#[cfg(all(feature = "simulator", not(target_arch = "wasm32")))]
+pub mod simulator;
+pub mod snapshot;
 pub mod system;
+#[cfg(all(feature = "test-util", not(target_arch = "wasm32")))]
+pub mod test_util;
+pub mod tv;
+pub mod vcr;
 pub mod video;
 pub mod video_screen;
+pub mod watch;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod wol;
+
+/// Re-export of the HTTP client library, useful to build a client for [BraviaBuilder::client].
+pub use reqwest;
 
 type VersionsVec = Vec<String>;
 type APIsMap = HashMap<String, VersionsVec>;
 type ServicesMap = HashMap<String, APIsMap>;
 
+// APIs used to wake up the display, they never trigger the wake policy.
+#[cfg(not(target_arch = "wasm32"))]
+const WAKE_METHODS: [&str; 4] = [
+    "getSupportedApiInfo",
+    "getPowerStatus",
+    "getSystemSupportedFunction",
+    "getSystemInformation",
+];
+
+/// Default maximum number of requests that can be in flight at the same time.
+pub const DEFAULT_MAX_IN_FLIGHT_REQUESTS: usize = 4;
+
 #[derive(Serialize, Builder, Clone, Default)]
 #[builder(build_fn(error = "derive_builder::UninitializedFieldError"))]
 struct RequestBody<'a> {
@@ -62,6 +179,16 @@ struct RequestBody<'a> {
     params: Vec<Value>,
 }
 
+impl RequestBody<'_> {
+    fn call_context(&self, endpoint: &str) -> CallContext {
+        CallContext {
+            endpoint: endpoint.to_string(),
+            method: self.method.to_string(),
+            version: self.version.to_string(),
+        }
+    }
+}
+
 impl<'a> RequestBodyBuilder<'a> {
     fn version(&mut self, value: Option<&'a str>) -> &mut Self {
         self.version = if value.is_none() { Some("1.0") } else { value };
@@ -72,6 +199,12 @@ impl<'a> RequestBodyBuilder<'a> {
         self.params = Some(vec![values]);
         self
     }
+
+    // Used by the APIs that take more than one parameter.
+    fn params_list(&mut self, values: Vec<Value>) -> &mut Self {
+        self.params = Some(values);
+        self
+    }
 }
 
 // Used to get a specific element from the response.
@@ -81,6 +214,10 @@ enum RequestGetElementType<'a> {
     Index(usize),
     // Get the element by name from the result vector.
     Text(&'a str),
+    // Get the whole result vector.
+    All,
+    // Get the value of a cookie set by the response.
+    Cookie(&'a str),
 }
 
 impl<'a> From<&'a str> for RequestGetElementType<'a> {
@@ -108,6 +245,12 @@ struct Request<'a> {
     has_result: bool,
     #[builder(default = "RequestGetElementType::Index(0)")]
     get: RequestGetElementType<'a>,
+    // Indicates if the request can't be sent again when it fails, like destructive calls.
+    #[builder(setter(custom), default)]
+    no_retry: bool,
+    // PIN code sent with HTTP Basic authentication, used during the registration.
+    #[builder(setter(strip_option), default)]
+    pin: Option<&'a str>,
 }
 
 impl<'a> RequestBuilder<'a> {
@@ -121,39 +264,712 @@ impl<'a> RequestBuilder<'a> {
         self
     }
 
-    #[allow(clippy::unwrap_used)]
-    async fn make(&mut self, bravia: &Bravia) -> Result<Value> {
+    fn no_retry(&mut self) -> &mut Self {
+        self.no_retry = Some(true);
+        self
+    }
+
+    async fn make(&mut self, context: &ServiceContext) -> Result<Value> {
+        self.make_as(context).await
+    }
+
+    // Same as make but deserializes the result straight from the response body.
+    async fn make_as<T: DeserializeOwned>(&mut self, context: &ServiceContext) -> Result<T> {
         let request = self.build()?;
-        Ok(bravia.make_request(request).await.unwrap())
+        context.bravia.make_request(request, &context.options).await
+    }
+}
+
+/// Options applied to the requests made through a service handle,
+/// they override the ones configured on the [Bravia] client.
+///
+/// # Examples
+/// ```no_run
+/// # use bravia_api::{Bravia, error::Result};
+/// # use std::time::Duration;
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+/// let status = bravia
+///     .system()
+///     .with_timeout(Duration::from_millis(500))
+///     .get_power_status()
+///     .await?;
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RequestOptions {
+    /// Timeout of the request, from when the request starts connecting
+    /// until the response body has finished.\
+    /// Ignored on `wasm32` targets.
+    pub timeout: Option<Duration>,
+    /// Policy used to retry the request when it fails.
+    pub retry_policy: Option<RetryPolicy>,
+}
+
+// Client and options used by a service handle to make its requests.
+#[derive(Debug, Clone)]
+struct ServiceContext {
+    bravia: Bravia,
+    options: RequestOptions,
+}
+
+impl ServiceContext {
+    fn new(bravia: &Bravia) -> Self {
+        Self {
+            bravia: bravia.clone(),
+            options: RequestOptions::default(),
+        }
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
+/// Credentials used to access the APIs with an authentication level other than `None`.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Auth {
+    /// Pre-Shared Key set on the display.
+    Psk(String),
+    /// Value of the `auth` cookie obtained by registering the client with
+    /// [actRegister](access_control::AccessControlService::act_register).
+    Cookie(String),
+}
+
+/// Versions of each API and notification supported by a server, grouped by service.\
+/// It can be saved with [to_json](Self::to_json) and given back to [BraviaBuilder::api_support],
+/// so that short-lived clients don't ask the server for the supported APIs every time.
+///
+/// # Examples
+/// ```no_run
+/// # use bravia_api::{ApiSupport, Bravia, error::Result};
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// let bravia = match std::fs::read_to_string("api_support.json") {
+///     Ok(json) => Bravia::with_cached_api_support(
+///         "ADDRESS",
+///         Some("PASSWORD"),
+///         ApiSupport::from_json(&json)?,
+///     )?,
+///     Err(_) => {
+///         let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+///         let api_support = bravia.cached_api_support().await?;
+///         std::fs::write("api_support.json", api_support.to_json()?).unwrap();
+///         bravia
+///     }
+/// };
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(from = "ApiSupportFormat")]
+pub struct ApiSupport {
+    apis: ServicesMap,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    notifications: ServicesMap,
+}
+
+// Formats of the saved supported APIs, the first versions only had the APIs.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ApiSupportFormat {
+    Current {
+        apis: ServicesMap,
+        #[serde(default)]
+        notifications: ServicesMap,
+    },
+    ApisOnly(ServicesMap),
+}
+
+impl From<ApiSupportFormat> for ApiSupport {
+    fn from(format: ApiSupportFormat) -> Self {
+        match format {
+            ApiSupportFormat::Current {
+                apis,
+                notifications,
+            } => Self {
+                apis,
+                notifications,
+            },
+            ApiSupportFormat::ApisOnly(apis) => Self {
+                apis,
+                notifications: HashMap::new(),
+            },
+        }
+    }
+}
+
+impl ApiSupport {
+    /// Serializes the supported APIs to JSON.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Loads the supported APIs from JSON created by [to_json](Self::to_json).
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Returns the supported versions of an API,
+    /// or `None` if the service or the API are not supported.
+    ///
+    /// # Arguments
+    /// * `service` - Name of the service (e.g. `avContent`).
+    /// * `api` - Name of the API (e.g. `setPlayContent`).
+    pub fn versions(&self, service: &str, api: &str) -> Option<&[String]> {
+        versions(&self.apis, service, api)
+    }
+
+    /// Returns the supported versions of a notification,
+    /// or `None` if the service or the notification are not supported.
+    ///
+    /// # Arguments
+    /// * `service` - Name of the service (e.g. `audio`).
+    /// * `notification` - Name of the notification (e.g. `notifyVolumeInformation`).
+    pub fn notification_versions(&self, service: &str, notification: &str) -> Option<&[String]> {
+        versions(&self.notifications, service, notification)
+    }
+
+    /// Returns the names of the notifications of a service, empty if it has none.
+    pub fn notifications(&self, service: &str) -> impl Iterator<Item = &str> + '_ {
+        self.notifications
+            .get(service)
+            .into_iter()
+            .flat_map(HashMap::keys)
+            .map(String::as_str)
+    }
+
+    /// Returns the names of the supported services.
+    pub fn services(&self) -> impl Iterator<Item = &str> + '_ {
+        self.apis.keys().map(String::as_str)
+    }
+}
+
+// Versions of an API or a notification of a service.
+fn versions<'a>(map: &'a ServicesMap, service: &str, name: &str) -> Option<&'a [String]> {
+    map.get(service)
+        .and_then(|names| names.get(name))
+        .map(Vec::as_slice)
+}
+
+/// Body of a response, returned by [last_raw_response](Bravia::last_raw_response).
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawResponse {
+    /// API call that got the response.
+    pub call: CallContext,
+    /// Whole body, as a string if it isn't valid JSON.
+    pub body: Value,
+}
+
+/// Used to configure a [Bravia] client before connecting to the server.
+///
+/// # Examples
+/// ```no_run
+/// # use bravia_api::{Bravia, error::Result};
+/// # use std::time::Duration;
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// let bravia = Bravia::builder("192.168.1.10")
+///     .auth("PASSWORD")
+///     .port(8080)
+///     .timeout(Duration::from_secs(5))
+///     .connect_timeout(Duration::from_secs(1))
+///     .max_in_flight_requests(1)
+///     .build()
+///     .await?;
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BraviaBuilder {
+    address: String,
+    auth: Option<Auth>,
+    max_in_flight_requests: usize,
+    scheme: Option<String>,
+    port: Option<u16>,
+    #[cfg(not(target_arch = "wasm32"))]
+    timeout: Option<Duration>,
+    #[cfg(not(target_arch = "wasm32"))]
+    connect_timeout: Option<Duration>,
+    client: Option<Client>,
+    #[cfg(not(target_arch = "wasm32"))]
+    proxy: Option<String>,
+    #[cfg(not(target_arch = "wasm32"))]
+    proxy_auth: Option<(String, String)>,
+    retry_policy: RetryPolicy,
+    rate_limit: Option<(u32, Duration)>,
+    app_list_ttl: Option<Duration>,
+    api_support: Option<ApiSupport>,
+    vcr: Option<Vcr>,
+    capture_raw_responses: bool,
+    #[cfg(not(target_arch = "wasm32"))]
+    wake_policy: Option<WakePolicy>,
+    #[cfg(all(
+        any(feature = "native-tls", feature = "rustls-tls"),
+        not(target_arch = "wasm32")
+    ))]
+    accept_invalid_certs: bool,
+    #[cfg(all(
+        any(feature = "native-tls", feature = "rustls-tls"),
+        not(target_arch = "wasm32")
+    ))]
+    root_certificates: Vec<reqwest::Certificate>,
+}
+
+impl BraviaBuilder {
+    /// # Arguments
+    /// * `address` - Server address.\
+    ///   If the address has no scheme `http` is used.
+    pub fn new(address: &str) -> Self {
+        Self {
+            address: address.to_string(),
+            auth: None,
+            max_in_flight_requests: DEFAULT_MAX_IN_FLIGHT_REQUESTS,
+            scheme: None,
+            port: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            timeout: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            connect_timeout: None,
+            client: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            proxy: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            proxy_auth: None,
+            retry_policy: RetryPolicy::none(),
+            rate_limit: None,
+            app_list_ttl: None,
+            api_support: None,
+            vcr: None,
+            capture_raw_responses: false,
+            #[cfg(not(target_arch = "wasm32"))]
+            wake_policy: None,
+            #[cfg(all(
+                any(feature = "native-tls", feature = "rustls-tls"),
+                not(target_arch = "wasm32")
+            ))]
+            accept_invalid_certs: false,
+            #[cfg(all(
+                any(feature = "native-tls", feature = "rustls-tls"),
+                not(target_arch = "wasm32")
+            ))]
+            root_certificates: Vec::new(),
+        }
+    }
+
+    /// Server password.\
+    /// Only needed when the API authentication level is not `None`.
+    pub fn auth(mut self, auth: &str) -> Self {
+        self.auth = Some(Auth::Psk(auth.to_string()));
+        self
+    }
+
+    /// Value of the `auth` cookie obtained by registering the client with
+    /// [actRegister](access_control::AccessControlService::act_register),
+    /// used instead of the [password](Self::auth).
+    pub fn auth_cookie(mut self, cookie: &str) -> Self {
+        self.auth = Some(Auth::Cookie(cookie.to_string()));
+        self
+    }
+
+    /// Maximum number of requests that can be sent to the server at the same time.\
+    /// Excess requests are queued locally until a slot is free.
+    /// The default value is [DEFAULT_MAX_IN_FLIGHT_REQUESTS], a value of `0` is treated as `1`.
+    pub fn max_in_flight_requests(mut self, limit: usize) -> Self {
+        self.max_in_flight_requests = limit.max(1);
+        self
+    }
+
+    /// URL scheme used to reach the server (`http` or `https`).\
+    /// Overrides the scheme of the address, if any.
+    pub fn scheme(mut self, scheme: &str) -> Self {
+        self.scheme = Some(scheme.to_string());
+        self
+    }
+
+    /// Port of the server.\
+    /// Overrides the port of the address, if any.
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// Timeout applied to every request, from when the request starts connecting
+    /// until the response body has finished.\
+    /// The default value is no timeout.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Timeout for the connect phase of every request.\
+    /// The default value is no timeout.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// URL of the proxy used for all the requests.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn proxy(mut self, url: &str) -> Self {
+        self.proxy = Some(url.to_string());
+        self
+    }
+
+    /// Credentials for the HTTP Basic authentication of the [proxy](Self::proxy).
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn proxy_basic_auth(mut self, username: &str, password: &str) -> Self {
+        self.proxy_auth = Some((username.to_string(), password.to_string()));
+        self
+    }
+
+    /// Policy used to retry the requests that failed because of transient errors.\
+    /// The default value is [RetryPolicy::none].
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Limits the requests sent to the server to `requests` every `period`,
+    /// using a token bucket that allows bursts of up to `requests` requests.\
+    /// Excess requests are delayed locally. The default value is no limit.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # use std::time::Duration;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// // At most 5 requests per second
+    /// let bravia = Bravia::builder("ADDRESS")
+    ///     .rate_limit(5, Duration::from_secs(1))
+    ///     .build()
+    ///     .await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn rate_limit(mut self, requests: u32, period: Duration) -> Self {
+        self.rate_limit = Some((requests, period));
+        self
+    }
+
+    /// How long the application list cached by
+    /// [cached_application_list](app_control::AppControlService::cached_application_list)
+    /// stays valid before it is read again.\
+    /// The default value is no expiry, the list is kept until
+    /// [clear_application_cache](app_control::AppControlService::clear_application_cache) is called.
+    pub fn app_list_ttl(mut self, ttl: Duration) -> Self {
+        self.app_list_ttl = Some(ttl);
+        self
+    }
+
+    /// Supported APIs saved from a previous session, used instead of asking the server.\
+    /// See [ApiSupport] and [fetch_api_support](Bravia::fetch_api_support) to keep them up to date.
+    pub fn api_support(mut self, api_support: ApiSupport) -> Self {
+        self.api_support = Some(api_support);
+        self
+    }
+
+    /// Records the requests and the responses, or replays recorded ones without
+    /// contacting the server, see [Vcr].\
+    /// Disabled by default.
+    pub fn vcr(mut self, vcr: Vcr) -> Self {
+        self.vcr = Some(vcr);
+        self
+    }
+
+    /// Keeps the body of the last response received, see [last_raw_response](Bravia::last_raw_response).\
+    /// Disabled by default, since each body is parsed twice.
+    pub fn capture_raw_responses(mut self, enabled: bool) -> Self {
+        self.capture_raw_responses = enabled;
+        self
+    }
+
+    /// Wakes up the display with Wake-on-LAN when a request fails because it is turned off,
+    /// see [WakePolicy].\
+    /// Disabled by default.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn wake_policy(mut self, policy: WakePolicy) -> Self {
+        self.wake_policy = Some(policy);
+        self
+    }
+
+    /// Accepts invalid TLS certificates, like the self-signed ones used by some displays.\
+    /// The default value is `false`.
+    ///
+    /// # Warning
+    /// Any certificate for any site will be trusted, this exposes the connection to
+    /// man-in-the-middle attacks. Prefer [add_root_certificate](Self::add_root_certificate)
+    /// when the certificate of the display is known.
+    #[cfg(all(
+        any(feature = "native-tls", feature = "rustls-tls"),
+        not(target_arch = "wasm32")
+    ))]
+    pub fn danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.accept_invalid_certs = accept;
+        self
+    }
+
+    /// Adds a custom root certificate, used to trust the certificate of the display.
+    #[cfg(all(
+        any(feature = "native-tls", feature = "rustls-tls"),
+        not(target_arch = "wasm32")
+    ))]
+    pub fn add_root_certificate(mut self, certificate: reqwest::Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Preconfigured HTTP client used for all the requests
+    /// (for example with a custom DNS resolver, local address or user agent).\
+    /// When set, the timeouts, the proxy and the TLS settings of this builder
+    /// are ignored and must be configured on the client itself.
+    pub fn client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Creates the client and populates the supported API cache,
+    /// unless it was given with [api_support](Self::api_support).
+    pub async fn build(self) -> Result<Bravia> {
+        let bravia = self.build_unchecked()?;
+        bravia.api_support().await?;
+        Ok(bravia)
+    }
+
+    /// Creates the client without contacting the server.\
+    /// The supported API cache is populated on the first request,
+    /// so the client can be created while the display is off or unreachable.
+    pub fn build_unchecked(self) -> Result<Bravia> {
+        let base_url = base_url(&self.address, self.scheme.as_deref(), self.port)?;
+        let client = match self.client {
+            Some(client) => client,
+            None => {
+                #[allow(unused_mut)]
+                let mut client = Client::builder();
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    if let Some(timeout) = self.timeout {
+                        client = client.timeout(timeout);
+                    }
+                    if let Some(timeout) = self.connect_timeout {
+                        client = client.connect_timeout(timeout);
+                    }
+                    if let Some(url) = &self.proxy {
+                        let mut proxy = Proxy::all(url).map_err(|_| {
+                            Error::InvalidParameter(format!("invalid proxy URL {url:?}"))
+                        })?;
+                        if let Some((username, password)) = &self.proxy_auth {
+                            proxy = proxy.basic_auth(username, password);
+                        }
+                        client = client.proxy(proxy);
+                    }
+                }
+                #[cfg(all(
+                    any(feature = "native-tls", feature = "rustls-tls"),
+                    not(target_arch = "wasm32")
+                ))]
+                {
+                    client = client.danger_accept_invalid_certs(self.accept_invalid_certs);
+                    for certificate in self.root_certificates {
+                        client = client.add_root_certificate(certificate);
+                    }
+                }
+                client.build()?
+            }
+        };
+
+        let target = Target::new(base_url, self.auth);
+        if let Some(api_support) = self.api_support {
+            // The cell is empty, so this can't fail
+            let _ = target.api_support.set(Arc::new(api_support));
+        }
+        Ok(Bravia {
+            shared: Arc::new(Shared {
+                target: RwLock::new(Arc::new(target)),
+                scheme: self.scheme,
+                port: self.port,
+                client,
+                request_slots: Semaphore::new(self.max_in_flight_requests),
+                retry_policy: self.retry_policy,
+                rate_limiter: self
+                    .rate_limit
+                    .map(|(requests, period)| RateLimiter::new(requests, period)),
+                app_list_ttl: self.app_list_ttl,
+                metrics: Recorder::default(),
+                vcr: self.vcr,
+                last_raw_response: self.capture_raw_responses.then(Mutex::default),
+                #[cfg(not(target_arch = "wasm32"))]
+                wake_policy: self.wake_policy,
+            }),
+        })
+    }
+}
+
+/// Client of a server.\
+/// Cloning it is cheap and all the clones share the same HTTP client, metrics,
+/// request limits, server address, credentials and supported API cache,
+/// so it can be moved into spawned tasks.
+/// The service handles own a clone of the client, so they can be moved too.
+///
+/// # Examples
+/// ```no_run
+/// # use bravia_api::{Bravia, error::Result};
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+/// let system = bravia.system();
+/// let handle = tokio::spawn(async move { system.get_power_status().await });
+/// # handle.await.unwrap()?;
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
 pub struct Bravia {
+    // Shared by all the clones.
+    shared: Arc<Shared>,
+}
+
+// Server the requests are sent to and what is known about it.
+#[derive(Debug)]
+struct Target {
     base_url: String,
-    auth: Option<String>,
-    api_support: ServicesMap,
+    auth: Option<Auth>,
+    // Populated by the first request, or during the build.
+    api_support: OnceCell<Arc<ApiSupport>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    wol_mac: OnceCell<String>,
+    // Populated by device_info.
+    device_info: OnceCell<DeviceInfo>,
+    // Channels of each TV source, populated by the channel helpers.
+    channels: Mutex<HashMap<String, Vec<Content>>>,
+    // Installed applications and when they were read, populated by the application list cache.
+    applications: Mutex<Option<(runtime::Instant, Vec<Application>)>>,
+}
+
+impl Target {
+    fn new(base_url: String, auth: Option<Auth>) -> Self {
+        Self {
+            base_url,
+            auth,
+            api_support: OnceCell::new(),
+            #[cfg(not(target_arch = "wasm32"))]
+            wol_mac: OnceCell::new(),
+            device_info: OnceCell::new(),
+            channels: Mutex::new(HashMap::new()),
+            applications: Mutex::new(None),
+        }
+    }
+}
+
+// Settings and resources that don't depend on the server address.
+#[derive(Debug)]
+struct Shared {
+    // Replaced when the address or the credentials change.
+    target: RwLock<Arc<Target>>,
+    scheme: Option<String>,
+    port: Option<u16>,
+    // Shared between all the requests to reuse the connection pool.
+    client: Client,
+    // Limits the number of concurrent requests sent to the server.
+    request_slots: Semaphore,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<RateLimiter>,
+    // How long the cached application list stays valid, forever if `None`.
+    app_list_ttl: Option<Duration>,
+    metrics: Recorder,
+    vcr: Option<Vcr>,
+    // Set when the raw responses are captured.
+    last_raw_response: Option<Mutex<Option<RawResponse>>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    wake_policy: Option<WakePolicy>,
 }
 
 impl Bravia {
     /// # Arguments
     /// * `address` - Server address.
     /// * `auth` - Server password.\
-    /// Only needed when the API authentication level is not `None`.
+    ///   Only needed when the API authentication level is not `None`.
     pub async fn new(address: &str, auth: Option<&str>) -> Result<Self> {
-        let mut ret = Bravia {
-            base_url: format!("{address}/sony/"),
-            auth: auth.map(str::to_string),
-            api_support: HashMap::new(),
-        };
-        ret.create_supported_api_cache().await?;
-        Ok(ret)
+        let mut builder = Self::builder(address);
+        if let Some(auth) = auth {
+            builder = builder.auth(auth);
+        }
+        builder.build().await
+    }
+
+    /// Same as [new](Self::new) but all the requests are sent with the given HTTP client.
+    ///
+    /// # Arguments
+    /// * `address` - Server address.
+    /// * `auth` - Server password.\
+    ///   Only needed when the API authentication level is not `None`.
+    /// * `client` - Preconfigured HTTP client.
+    pub async fn with_client(address: &str, auth: Option<&str>, client: Client) -> Result<Self> {
+        let mut builder = Self::builder(address).client(client);
+        if let Some(auth) = auth {
+            builder = builder.auth(auth);
+        }
+        builder.build().await
+    }
+
+    /// Returns a [BraviaBuilder] to configure the client before connecting to the server.
+    ///
+    /// # Arguments
+    /// * `address` - Server address.
+    pub fn builder(address: &str) -> BraviaBuilder {
+        BraviaBuilder::new(address)
+    }
+
+    /// Changes the server address, for example after the display got a new DHCP lease.\
+    /// The scheme and the port set with the [builder](BraviaBuilder) are kept
+    /// and the supported API cache is populated again on the next request.
+    /// All the clones of this client, like the ones owned by the service handles,
+    /// send their next requests to the new address.
+    ///
+    /// # Arguments
+    /// * `address` - Server address.
+    pub fn set_address(&mut self, address: &str) -> Result<()> {
+        let base_url = base_url(address, self.shared.scheme.as_deref(), self.shared.port)?;
+        self.replace_target(|target| Target::new(base_url, target.auth.clone()));
+        Ok(())
+    }
+
+    /// Changes the credentials, for example after the Pre-Shared Key has been rotated.\
+    /// The supported API cache is populated again on the next request.
+    /// All the clones of this client send their next requests with the new credentials.
+    ///
+    /// # Arguments
+    /// * `auth` - Pre-Shared Key or cookie.\
+    ///   Only needed when the API authentication level is not `None`.
+    pub fn set_auth(&mut self, auth: Option<Auth>) {
+        self.replace_target(|target| Target::new(target.base_url.clone(), auth));
+    }
+
+    // Server the requests are currently sent to.
+    fn target(&self) -> Arc<Target> {
+        let target = self.shared.target.read();
+        Arc::clone(&target.unwrap_or_else(PoisonError::into_inner))
+    }
+
+    fn replace_target(&self, f: impl FnOnce(&Target) -> Target) {
+        let mut target = self
+            .shared
+            .target
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        *target = Arc::new(f(&target));
     }
 
     pub fn guide(&self) -> GuideService {
         GuideService::new(self)
     }
 
+    pub fn access_control(&self) -> AccessControlService {
+        AccessControlService::new(self)
+    }
+
     pub fn app_control(&self) -> AppControlService {
         AppControlService::new(self)
     }
@@ -166,10 +982,40 @@ impl Bravia {
         AvContentService::new(self)
     }
 
+    pub fn browser(&self) -> BrowserService {
+        BrowserService::new(self)
+    }
+
+    pub fn cec(&self) -> CecService {
+        CecService::new(self)
+    }
+
+    pub fn content_share(&self) -> ContentShareService {
+        ContentShareService::new(self)
+    }
+
     pub fn encryption(&self) -> EncryptionService {
         EncryptionService::new(self)
     }
 
+    pub fn ircc(&self) -> IrccService {
+        IrccService::new(self)
+    }
+
+    #[cfg(all(feature = "notifications", not(target_arch = "wasm32")))]
+    pub fn notifications(&self) -> NotificationService {
+        NotificationService::new(self)
+    }
+
+    pub fn recording(&self) -> RecordingService {
+        RecordingService::new(self)
+    }
+
+    /// Generic settings APIs of `service`, like `system` or `video`.
+    pub fn settings(&self, service: &str) -> SettingsService {
+        SettingsService::new(self, service)
+    }
+
     pub fn system(&self) -> SystemService {
         SystemService::new(self)
     }
@@ -182,9 +1028,289 @@ impl Bravia {
         VideoScreenService::new(self)
     }
 
-    // Populates the `api_support` HashMap.
-    async fn create_supported_api_cache(&mut self) -> Result<()> {
-        let services = self.guide().get_supported_api_info(None).await?;
+    /// Same as [new](Self::new) but the server is not contacted,
+    /// the supported API cache is populated on the first request.\
+    /// Useful to create the client while the display is off or unreachable.
+    ///
+    /// # Arguments
+    /// * `address` - Server address.
+    /// * `auth` - Server password.\
+    ///   Only needed when the API authentication level is not `None`.
+    pub fn new_unchecked(address: &str, auth: Option<&str>) -> Result<Self> {
+        let mut builder = Self::builder(address);
+        if let Some(auth) = auth {
+            builder = builder.auth(auth);
+        }
+        builder.build_unchecked()
+    }
+
+    /// Same as [new](Self::new) but the supported APIs are not asked to the server,
+    /// so the server is not contacted.
+    ///
+    /// # Arguments
+    /// * `address` - Server address.
+    /// * `auth` - Server password.\
+    ///   Only needed when the API authentication level is not `None`.
+    /// * `api_support` - Supported APIs saved from a previous session.
+    pub fn with_cached_api_support(
+        address: &str,
+        auth: Option<&str>,
+        api_support: ApiSupport,
+    ) -> Result<Self> {
+        let mut builder = Self::builder(address).api_support(api_support);
+        if let Some(auth) = auth {
+            builder = builder.auth(auth);
+        }
+        builder.build_unchecked()
+    }
+
+    /// Calls an API that is not wrapped by this crate.\
+    /// The request goes through the same checks of the other APIs,
+    /// the password is sent if it has been provided.
+    ///
+    /// # Arguments
+    /// * `service` - Name of the service (e.g. `avContent`).
+    /// * `method` - Name of the API (e.g. `getSchemeList`).
+    /// * `version` - Version of the API (e.g. `1.0`).
+    /// * `params` - Parameters object of the API, `Value::Null` if the API has no parameters.
+    ///
+    /// # Returns
+    /// The whole `result` array returned by the server.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # use serde_json::json;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// let result = bravia
+    ///     .raw_call("avContent", "getSourceList", "1.0", json!({"scheme": "extInput"}))
+    ///     .await?;
+    /// println!("{result}");
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn raw_call(
+        &self,
+        service: &str,
+        method: &str,
+        version: &str,
+        params: Value,
+    ) -> Result<Value> {
+        let mut body = RequestBodyBuilder::default();
+        body.id(1).method(method).version(Some(version));
+        if !params.is_null() {
+            body.params(params);
+        }
+        let mut req = RequestBuilder::default();
+        req.endpoint(service)
+            .body(body.build()?)
+            .has_result()
+            .get(RequestGetElementType::All);
+        if self.target().auth.is_some() {
+            req.is_protected();
+        }
+        req.make(&ServiceContext::new(self)).await
+    }
+
+    /// Body of the last response received by this client and its clones, to compare the typed
+    /// results with what the server actually returned.\
+    /// Returns `None` if no response was received yet or if the responses are not captured,
+    /// see [capture_raw_responses](BraviaBuilder::capture_raw_responses).
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::builder("ADDRESS")
+    ///     .auth("PASSWORD")
+    ///     .capture_raw_responses(true)
+    ///     .build()
+    ///     .await?;
+    /// let content = bravia.av_content().get_playing_content_info().await?;
+    /// if let Some(raw) = bravia.last_raw_response() {
+    ///     println!("{content:?} was parsed from {} in {}", raw.body, raw.call);
+    /// }
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn last_raw_response(&self) -> Option<RawResponse> {
+        self.shared
+            .last_raw_response
+            .as_ref()?
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Checks if the server supports the given version of an API.
+    ///
+    /// # Arguments
+    /// * `service` - Name of the service (e.g. `avContent`).
+    /// * `api` - Name of the API (e.g. `setPlayContent`).
+    /// * `version` - Version of the API (e.g. `1.0`).
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// let version = if bravia.supports("system", "getCurrentTime", "1.1").await? {
+    ///     "1.1"
+    /// } else {
+    ///     "1.0"
+    /// };
+    /// let time = bravia.system().get_current_time(Some(version)).await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn supports(&self, service: &str, api: &str, version: &str) -> Result<bool> {
+        Ok(self
+            .supported_versions(service, api)
+            .await?
+            .is_some_and(|versions| versions.iter().any(|x| x == version)))
+    }
+
+    /// Returns the versions of an API supported by the server,
+    /// or `None` if the service or the API are not supported.
+    ///
+    /// # Arguments
+    /// * `service` - Name of the service (e.g. `avContent`).
+    /// * `api` - Name of the API (e.g. `setPlayContent`).
+    pub async fn supported_versions(
+        &self,
+        service: &str,
+        api: &str,
+    ) -> Result<Option<Vec<String>>> {
+        Ok(self
+            .api_support()
+            .await?
+            .versions(service, api)
+            .map(<[String]>::to_vec))
+    }
+
+    /// Returns the names of the services supported by the server.
+    pub async fn supported_services(&self) -> Result<Vec<String>> {
+        Ok(self
+            .api_support()
+            .await?
+            .services()
+            .map(String::from)
+            .collect())
+    }
+
+    /// Checks if the server sends the given version of a notification.
+    ///
+    /// # Arguments
+    /// * `service` - Name of the service (e.g. `audio`).
+    /// * `notification` - Name of the notification (e.g. `notifyVolumeInformation`).
+    /// * `version` - Version of the notification (e.g. `1.0`).
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// if bravia
+    ///     .supports_notification("audio", "notifyVolumeInformation", "1.0")
+    ///     .await?
+    /// {
+    ///     println!("The volume changes can be followed without polling");
+    /// }
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn supports_notification(
+        &self,
+        service: &str,
+        notification: &str,
+        version: &str,
+    ) -> Result<bool> {
+        Ok(self
+            .supported_notification_versions(service, notification)
+            .await?
+            .is_some_and(|versions| versions.iter().any(|x| x == version)))
+    }
+
+    /// Returns the versions of a notification sent by the server,
+    /// or `None` if the service or the notification are not supported.
+    ///
+    /// # Arguments
+    /// * `service` - Name of the service (e.g. `audio`).
+    /// * `notification` - Name of the notification (e.g. `notifyVolumeInformation`).
+    pub async fn supported_notification_versions(
+        &self,
+        service: &str,
+        notification: &str,
+    ) -> Result<Option<Vec<String>>> {
+        Ok(self
+            .api_support()
+            .await?
+            .notification_versions(service, notification)
+            .map(<[String]>::to_vec))
+    }
+
+    /// Returns the names of the notifications sent by a service of the server.
+    pub async fn supported_notifications(&self, service: &str) -> Result<Vec<String>> {
+        Ok(self
+            .api_support()
+            .await?
+            .notifications(service)
+            .map(String::from)
+            .collect())
+    }
+
+    /// Returns a copy of the supported APIs used by this client, populating the cache if needed.\
+    /// They can be saved and given to [with_cached_api_support](Self::with_cached_api_support).
+    pub async fn cached_api_support(&self) -> Result<ApiSupport> {
+        Ok(ApiSupport::clone(&*self.api_support().await?))
+    }
+
+    /// Asks the server for the supported APIs, even if they are already cached.\
+    /// The cache of this client is not changed, this is meant to refresh a saved copy
+    /// in the background, like after a software update of the display.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{ApiSupport, Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// # let api_support = ApiSupport::default();
+    /// let bravia = Bravia::with_cached_api_support("ADDRESS", Some("PASSWORD"), api_support)?;
+    /// let client = bravia.clone();
+    /// let refresh = tokio::spawn(async move {
+    ///     if let Ok(api_support) = client.fetch_api_support().await {
+    ///         std::fs::write("api_support.json", api_support.to_json()?).unwrap();
+    ///     }
+    ///     Ok::<(), bravia_api::error::Error>(())
+    /// });
+    /// bravia.system().get_power_status().await?;
+    /// # refresh.await.unwrap()?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_api_support(&self) -> Result<ApiSupport> {
+        self.fetch_services_map().await
+    }
+
+    // Returns the supported API cache, populating it if needed.
+    async fn api_support(&self) -> Result<Arc<ApiSupport>> {
+        self.target()
+            .api_support
+            .get_or_try_init(|| async { self.fetch_services_map().await.map(Arc::new) })
+            .await
+            .cloned()
+    }
+
+    // Fetches the supported APIs and notifications of all the services.
+    async fn fetch_services_map(&self) -> Result<ApiSupport> {
+        // Boxed because the request itself goes through the cache check
+        let services = Box::pin(self.guide().get_supported_api_info(None)).await?;
+        let mut api_support = ApiSupport::default();
         for service in services {
             let mut service_apis = HashMap::new();
             for api in service.apis {
@@ -192,14 +1318,28 @@ impl Bravia {
                     api.versions.iter().map(|x| x.version.to_string()).collect();
                 service_apis.insert(api.name.to_string(), api_versions);
             }
-            self.api_support.insert(service.service, service_apis);
+            let mut service_notifications = HashMap::new();
+            for notification in service.notifications.unwrap_or_default() {
+                let versions: Vec<String> = notification
+                    .versions
+                    .iter()
+                    .map(|x| x.version.to_string())
+                    .collect();
+                service_notifications.insert(notification.name, versions);
+            }
+            if !service_notifications.is_empty() {
+                api_support
+                    .notifications
+                    .insert(service.service.clone(), service_notifications);
+            }
+            api_support.apis.insert(service.service, service_apis);
         }
-        Ok(())
+        Ok(api_support)
     }
 
     /// Checks if the API is supported by checking the cached API level.
-    fn is_api_supported(&self, service: &str, api: &str, api_level: &str) -> Result<()> {
-        if let Some(service) = self.api_support.get(service) {
+    async fn is_api_supported(&self, service: &str, api: &str, api_level: &str) -> Result<()> {
+        if let Some(service) = self.api_support().await?.apis.get(service) {
             if let Some(api) = service.get(api) {
                 if api.iter().any(|x| x == api_level) {
                     Ok(())
@@ -215,62 +1355,359 @@ impl Bravia {
     }
 
     /// Makes the API request and parses the result.
-    async fn make_request<'a>(&self, req: Request<'a>) -> Result<Value> {
-        let url = format!("{}{}", self.base_url, req.endpoint);
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(
+                endpoint = req.endpoint,
+                method = req.body.method,
+                version = req.body.version,
+                id = req.body.id,
+            )
+        )
+    )]
+    async fn make_request<'a, T: DeserializeOwned>(
+        &self,
+        req: Request<'a>,
+        options: &RequestOptions,
+    ) -> Result<T> {
+        let target = self.target();
+        let url = format!("{}{}", target.base_url, req.endpoint);
 
         // Checks if the requested API is supported by the server
         if req.body.method != "getSupportedApiInfo" {
-            self.is_api_supported(req.endpoint, req.body.method, req.body.version)?;
+            self.is_api_supported(req.endpoint, req.body.method, req.body.version)
+                .await?;
         };
 
-        // If no authentication is required it uses an empty &str
-        let auth: &str = if req.is_protected {
-            if let Some(value) = &self.auth {
-                value.as_str()
+        // If no authentication is required no credentials are sent
+        let auth = if req.is_protected {
+            if let Some(value) = &target.auth {
+                Some(value)
             } else {
                 return Err(Error::BraviaAuthLevelError);
             }
         } else {
-            ""
+            None
         };
 
-        // Creates and sends the request
-        let resp = Client::new()
-            .post(url)
-            .header("X-Auth-PSK", auth)
-            .header(CONTENT_TYPE, "application/json")
-            .body(serde_json::to_string(&req.body)?)
-            .send()
-            .await;
-
-        match resp {
-            Ok(resp) => {
-                match resp.status() {
-                    StatusCode::OK => {
-                        let mut parsed = resp.json::<serde_json::Value>().await?;
-                        if let Some(result) = parsed.get_mut("result") {
-                            if req.has_result {
-                                let result = match req.get {
-                                    RequestGetElementType::Index(value) => result.get_mut(value),
-                                    RequestGetElementType::Text(value) => result[0].get_mut(value),
-                                };
-                                Ok(result.ok_or(Error::MissingValue("result values"))?.take())
-                            } else {
-                                // Anyway this result will not be used anywhere
-                                Ok(serde_json::Value::Null)
-                            }
-                        } else if let Some(error) = parsed.get_mut("error") {
-                            let api_error = error.take();
-                            let err = serde_json::from_value(api_error)?;
-                            Err(Error::BraviaError(err))
-                        } else {
-                            Err(Error::InvalidResponse("Missing result and error fields."))
-                        }
+        let resp = self.send_with_retry(&url, auth, &req, options).await;
+
+        // Wakes up the display and sends the request again if it failed because it is turned off
+        #[cfg(not(target_arch = "wasm32"))]
+        if let (Err(err), Some(policy)) = (&resp, &self.shared.wake_policy) {
+            if policy.should_wake(err)
+                && !WAKE_METHODS.contains(&req.body.method)
+                && Box::pin(self.wake_up(policy)).await
+            {
+                return self.send_with_retry(&url, auth, &req, options).await;
+            }
+        }
+        resp
+    }
+
+    /// Sends the request following the retry policy.
+    async fn send_with_retry<'a, T: DeserializeOwned>(
+        &self,
+        url: &str,
+        auth: Option<&Auth>,
+        req: &Request<'a>,
+        options: &RequestOptions,
+    ) -> Result<T> {
+        let retry_policy = options
+            .retry_policy
+            .as_ref()
+            .unwrap_or(&self.shared.retry_policy);
+        let mut attempt = 1;
+        loop {
+            match self.send_request(url, auth, req, options).await {
+                Err(err)
+                    if !req.no_retry
+                        && attempt < retry_policy.max_attempts
+                        && retry_policy.should_retry(&err) =>
+                {
+                    let backoff = retry_policy.backoff(attempt);
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(attempt, ?backoff, error = %err, "retrying request");
+                    runtime::sleep(backoff).await;
+                    attempt += 1;
+                }
+                resp => return resp,
+            }
+        }
+    }
+
+    /// Sends a Wake-on-LAN packet and waits for the display to turn on.\
+    /// Returns `false` if the display is still off when the policy timeout expires.
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn wake_up(&self, policy: &WakePolicy) -> bool {
+        let mac = match &policy.mac_address {
+            Some(mac) => Ok(mac.clone()),
+            None => self.wol_mac().await,
+        };
+        if let Err(_err) =
+            mac.and_then(|mac| wol::send_magic_packet(&mac, policy.broadcast_address))
+        {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(error = %_err, "can't send the Wake-on-LAN packet");
+            return false;
+        }
+
+        let system = self.system().with_options(RequestOptions {
+            timeout: Some(policy.poll_interval),
+            retry_policy: Some(RetryPolicy::none()),
+        });
+        let deadline = runtime::Instant::now() + policy.wake_timeout;
+        loop {
+            if matches!(system.get_power_status().await, Ok(status) if status == "active") {
+                return true;
+            }
+            if runtime::Instant::now() >= deadline {
+                return false;
+            }
+            runtime::sleep(policy.poll_interval).await;
+        }
+    }
+
+    /// Returns the MAC address used for Wake-on-LAN, it's read from the server only once.
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn wol_mac(&self) -> Result<String> {
+        let target = self.target();
+        let mac = target
+            .wol_mac
+            .get_or_try_init(|| async {
+                let system = self.system();
+                if let Ok(functions) = system.get_system_supported_function().await {
+                    if let Some(wol) = functions.into_iter().find(|f| f.option == "WOL") {
+                        return Ok(wol.value);
                     }
-                    _ => Err(Error::BadStatus(resp.status())),
                 }
+                let info = system.get_system_information().await?;
+                match info.mac_addr.value() {
+                    Some(mac) => Ok(mac.to_string()),
+                    None => Err(Error::MissingValue("macAddr")),
+                }
+            })
+            .await?;
+        Ok(mac.clone())
+    }
+
+    /// Waits for the rate limiter and for a free request slot.
+    async fn request_slot(&self) -> Option<SemaphorePermit<'_>> {
+        if let Some(rate_limiter) = &self.shared.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+        // The semaphore is never closed so this can't fail
+        self.shared.request_slots.acquire().await.ok()
+    }
+
+    /// Creates a POST request with the given credentials and the timeout of the options.
+    fn post(
+        &self,
+        url: &str,
+        auth: Option<&Auth>,
+        #[allow(unused_variables)] options: &RequestOptions,
+    ) -> reqwest::RequestBuilder {
+        let mut builder = self.shared.client.post(url);
+        builder = match auth {
+            Some(Auth::Cookie(cookie)) => builder.header(COOKIE, format!("auth={cookie}")),
+            Some(Auth::Psk(psk)) => builder.header("X-Auth-PSK", psk),
+            None => builder.header("X-Auth-PSK", ""),
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(timeout) = options.timeout {
+            builder = builder.timeout(timeout);
+        }
+        builder
+    }
+
+    /// Sends the request once when a slot is free, recording its metrics.
+    async fn send_request<'a, T: DeserializeOwned>(
+        &self,
+        url: &str,
+        auth: Option<&Auth>,
+        req: &Request<'a>,
+        options: &RequestOptions,
+    ) -> Result<T> {
+        let _permit = self.request_slot().await;
+        let start = runtime::Instant::now();
+        let resp = self.exchange(url, auth, req, options).await;
+        self.shared.metrics.record(
+            req.endpoint,
+            req.body.method,
+            start.elapsed(),
+            resp.as_ref().err(),
+        );
+        resp
+    }
+
+    /// Sends the request, or replays it, and parses the result.
+    async fn exchange<'a, T: DeserializeOwned>(
+        &self,
+        url: &str,
+        auth: Option<&Auth>,
+        req: &Request<'a>,
+        options: &RequestOptions,
+    ) -> Result<T> {
+        let request = serde_json::to_value(&req.body)?;
+        let interaction = self
+            .replay_or_send(
+                req.endpoint,
+                &request,
+                || req.body.call_context(req.endpoint),
+                self.transmit(url, auth, req, &request, options),
+            )
+            .await?;
+        if let Some(last_raw_response) = &self.shared.last_raw_response {
+            let body = serde_json::from_str(&interaction.response)
+                .unwrap_or_else(|_| Value::from(interaction.response.as_str()));
+            *last_raw_response
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner) = Some(RawResponse {
+                call: req.body.call_context(req.endpoint),
+                body,
+            });
+        }
+        parse_response(req, &interaction)
+    }
+
+    /// Answers a request with the recorded interaction when the [Vcr] is replaying,
+    /// otherwise sends it with `send` and records the interaction if there is a [Vcr].
+    pub(crate) async fn replay_or_send(
+        &self,
+        endpoint: &str,
+        request: &Value,
+        call: impl FnOnce() -> CallContext,
+        send: impl std::future::Future<Output = Result<Interaction>>,
+    ) -> Result<Interaction> {
+        match &self.shared.vcr {
+            Some(vcr) if vcr.is_replaying() => vcr
+                .replay_interaction(endpoint, request)
+                .ok_or_else(|| Error::NotRecorded(call())),
+            vcr => {
+                let interaction = send.await?;
+                if let Some(vcr) = vcr {
+                    vcr.record_interaction(interaction.clone());
+                }
+                Ok(interaction)
             }
-            Err(err) => Err(Error::NetworkError(err)),
         }
     }
+
+    /// Sends the request to the server.
+    async fn transmit<'a>(
+        &self,
+        url: &str,
+        auth: Option<&Auth>,
+        req: &Request<'a>,
+        request: &Value,
+        options: &RequestOptions,
+    ) -> Result<Interaction> {
+        let body = request.to_string();
+        #[cfg(feature = "tracing")]
+        tracing::debug!(url, body, "sending request");
+        let mut builder = self
+            .post(url, auth, options)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body);
+        if let Some(pin) = req.pin {
+            builder = builder.basic_auth("", Some(pin));
+        }
+        let resp = builder.send().await.map_err(Error::NetworkError)?;
+        #[cfg(feature = "tracing")]
+        tracing::debug!(status = %resp.status(), "response received");
+
+        let status = resp.status().as_u16();
+        let cookies = resp
+            .headers()
+            .get_all(SET_COOKIE)
+            .iter()
+            .filter_map(|header| header.to_str().ok())
+            .map(str::to_string)
+            .collect();
+        let response = resp.text().await?;
+        #[cfg(feature = "tracing")]
+        tracing::debug!(body = response, "response body");
+        Ok(Interaction {
+            endpoint: req.endpoint.to_string(),
+            request: request.clone(),
+            status,
+            response,
+            cookies,
+        })
+    }
+}
+
+// Parses the result of a response, either received or replayed.
+fn parse_response<T: DeserializeOwned>(req: &Request<'_>, interaction: &Interaction) -> Result<T> {
+    let call = || req.body.call_context(req.endpoint);
+    match StatusCode::from_u16(interaction.status) {
+        Ok(StatusCode::OK) => {}
+        Ok(status) => return Err(Error::BadStatus(status, call())),
+        Err(_) => return Err(Error::InvalidResponse("Invalid HTTP status.")),
+    }
+
+    let get = req.has_result.then_some(&req.get);
+    let envelope = match ResultEnvelope::<T>::parse(&interaction.response, get) {
+        Ok(envelope) => envelope,
+        Err(err) if err.is_data() => {
+            return lenient::unnest(&interaction.response, get).ok_or_else(|| {
+                Error::UnexpectedPayload(err, call(), interaction.response.clone())
+            });
+        }
+        Err(err) => return Err(Error::DeserializeError(err, Some(call()))),
+    };
+    match envelope {
+        ResultEnvelope {
+            result: Some(result),
+            ..
+        } => {
+            let value = match (&req.get, req.has_result) {
+                // Anyway this result will not be used anywhere
+                (_, false) => Value::Null,
+                (RequestGetElementType::Cookie(name), _) => Value::from(
+                    find_cookie(&interaction.cookies, name).ok_or(Error::MissingValue("cookie"))?,
+                ),
+                _ => return result.ok_or(Error::MissingValue("result values")),
+            };
+            T::deserialize(value)
+                .map_err(|err| Error::UnexpectedPayload(err, call(), interaction.response.clone()))
+        }
+        ResultEnvelope {
+            error: Some(error), ..
+        } => Err(Error::BraviaError(error, call())),
+        _ => Err(Error::InvalidResponse("Missing result and error fields.")),
+    }
+}
+
+// Returns the value of the cookie with the given name from the `Set-Cookie` headers.
+fn find_cookie(cookies: &[String], name: &str) -> Option<String> {
+    cookies
+        .iter()
+        .filter_map(|header| header.split(';').next()?.split_once('='))
+        .find(|(key, _)| key.trim() == name)
+        .map(|(_, value)| value.trim().to_string())
+}
+
+// Builds the base URL of the services from the address, scheme and port.
+fn base_url(address: &str, scheme: Option<&str>, port: Option<u16>) -> Result<String> {
+    let invalid = || Error::InvalidParameter(format!("invalid address {address:?}"));
+    let full_address = if address.contains("://") {
+        address.to_string()
+    } else {
+        format!("http://{address}")
+    };
+
+    let mut url = Url::parse(&full_address).map_err(|_| invalid())?;
+    if let Some(scheme) = scheme {
+        url.set_scheme(scheme).map_err(|_| invalid())?;
+    }
+    if let Some(port) = port {
+        url.set_port(Some(port)).map_err(|_| invalid())?;
+    }
+    let path = format!("{}/sony/", url.path().trim_end_matches('/'));
+    url.set_path(&path);
+    Ok(url.to_string())
 }
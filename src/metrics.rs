@@ -0,0 +1,193 @@
+//! Counters and latencies of the requests sent by a client, to spot the displays that misbehave.
+
+use crate::{error::Error, Bravia};
+use std::{
+    collections::HashMap,
+    sync::{Mutex, PoisonError},
+    time::Duration,
+};
+
+/// Upper bounds of the latency buckets, the last bucket counts the slower requests.
+pub const LATENCY_BUCKETS: [Duration; 8] = [
+    Duration::from_millis(10),
+    Duration::from_millis(25),
+    Duration::from_millis(50),
+    Duration::from_millis(100),
+    Duration::from_millis(250),
+    Duration::from_millis(500),
+    Duration::from_secs(1),
+    Duration::from_secs(5),
+];
+
+/// Category of a failed request.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ErrorKind {
+    /// The server couldn't be reached or the connection failed.
+    Network,
+    /// The server answered with an HTTP status other than `200`.
+    Status,
+    /// The server returned an error for the API call.
+    Api,
+    /// The response couldn't be parsed.
+    Response,
+    /// Any other error.
+    Other,
+}
+
+impl ErrorKind {
+    fn of(err: &Error) -> Self {
+        match err {
+            Error::NetworkError(_) => Self::Network,
+            Error::BadStatus(..) => Self::Status,
+            Error::BraviaError(..) => Self::Api,
+            Error::DeserializeError(..)
+            | Error::UnexpectedPayload(..)
+            | Error::InvalidResponse(_)
+            | Error::MissingValue(_) => Self::Response,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Distribution of the time taken by the server to answer.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct LatencyHistogram {
+    // One more than LATENCY_BUCKETS, for the requests slower than the last bound.
+    buckets: [u64; LATENCY_BUCKETS.len() + 1],
+    total: Duration,
+}
+
+impl LatencyHistogram {
+    fn record(&mut self, latency: Duration) {
+        let index = LATENCY_BUCKETS
+            .iter()
+            .position(|bound| latency <= *bound)
+            .unwrap_or(LATENCY_BUCKETS.len());
+        self.buckets[index] += 1;
+        self.total += latency;
+    }
+
+    /// Number of requests of each bucket, with the upper bound of the bucket
+    /// (see [LATENCY_BUCKETS]) or `None` for the requests slower than all the bounds.
+    pub fn buckets(&self) -> impl Iterator<Item = (Option<Duration>, u64)> + '_ {
+        LATENCY_BUCKETS
+            .iter()
+            .copied()
+            .map(Some)
+            .chain([None])
+            .zip(self.buckets.iter().copied())
+    }
+
+    /// Number of recorded requests.
+    pub fn count(&self) -> u64 {
+        self.buckets.iter().sum()
+    }
+
+    /// Average latency, `None` if no request was recorded.
+    pub fn mean(&self) -> Option<Duration> {
+        let count = u32::try_from(self.count()).unwrap_or(u32::MAX);
+        (count > 0).then(|| self.total / count)
+    }
+}
+
+/// Statistics of an API of a service.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct MethodStats {
+    /// Requests sent, including the retries.
+    pub requests: u64,
+    /// Requests that failed.
+    pub errors: u64,
+    /// Latency of the requests that got an answer, even with an error.
+    pub latency: LatencyHistogram,
+}
+
+/// Statistics of the requests sent by a client since it was created,
+/// returned by [metrics](crate::Bravia::metrics).
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Metrics {
+    /// Requests sent, including the retries.
+    pub requests: u64,
+    /// Failed requests of each kind.
+    pub errors: HashMap<ErrorKind, u64>,
+    /// Statistics of each API, by service and method name, like `("system", "getPowerStatus")`.
+    pub methods: HashMap<(String, String), MethodStats>,
+}
+
+impl Metrics {
+    /// Total number of failed requests.
+    pub fn error_count(&self) -> u64 {
+        self.errors.values().sum()
+    }
+}
+
+impl Bravia {
+    /// Statistics of the requests sent by this client and its clones.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// bravia.system().get_power_status().await?;
+    /// let metrics = bravia.metrics();
+    /// println!("{} requests, {} errors", metrics.requests, metrics.error_count());
+    /// for ((service, method), stats) in &metrics.methods {
+    ///     println!("{service}.{method}: {:?}", stats.latency.mean());
+    /// }
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn metrics(&self) -> Metrics {
+        self.shared.metrics.snapshot()
+    }
+
+    /// Clears the [metrics](Self::metrics) of this client and its clones.
+    pub fn reset_metrics(&self) {
+        self.shared.metrics.reset();
+    }
+}
+
+// Shared by the clones of a client.
+#[derive(Debug, Default)]
+pub(crate) struct Recorder(Mutex<Metrics>);
+
+impl Recorder {
+    pub(crate) fn record(
+        &self,
+        service: &str,
+        method: &str,
+        latency: Duration,
+        error: Option<&Error>,
+    ) {
+        let mut metrics = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        metrics.requests += 1;
+        let kind = error.map(ErrorKind::of);
+        if let Some(kind) = kind {
+            *metrics.errors.entry(kind).or_default() += 1;
+        }
+        let stats = metrics
+            .methods
+            .entry((service.to_string(), method.to_string()))
+            .or_default();
+        stats.requests += 1;
+        if kind.is_some() {
+            stats.errors += 1;
+        }
+        // Requests that didn't reach the server have no meaningful latency
+        if kind != Some(ErrorKind::Network) {
+            stats.latency.record(latency);
+        }
+    }
+
+    pub(crate) fn snapshot(&self) -> Metrics {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    pub(crate) fn reset(&self) {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner) = Metrics::default();
+    }
+}
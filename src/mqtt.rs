@@ -0,0 +1,240 @@
+//! Bridge that exposes a display over MQTT, for home automation systems like Home Assistant or Node-RED.
+//!
+//! The state is published, retained, to these topics below the prefix:
+//! * `power` - Power status, like `active` or `standby`.
+//! * `volume` - Volume of the speakers.
+//! * `mute` - `true` if the audio is muted.
+//! * `input` - URI of the external input shown, empty when something else is shown.
+//! * `content` - Content shown as JSON, empty when nothing is playing.
+//!
+//! The commands are received on these topics below the prefix:
+//! * `power/set` - `on` or `off`.
+//! * `volume/set` - Volume level, in the formats accepted by [set_audio_volume](crate::audio::AudioService::set_audio_volume).
+//! * `mute/set` - `on` or `off`.
+//! * `input/set` - URI of the external input, like `extInput:hdmi?port=1`.
+//! * `app/set` - URI of the application to launch.
+
+use crate::{
+    error::{Error, Result},
+    watch::ChangeEvent,
+    Bravia,
+};
+use rumqttc::{AsyncClient, Event, Packet};
+pub use rumqttc::{MqttOptions, QoS};
+use std::time::Duration;
+use tokio::sync::{broadcast::error::RecvError, mpsc};
+
+// Number of messages that can be queued before the event loop sends them.
+const CHANNEL_CAPACITY: usize = 32;
+
+/// Publishes the state of a display and forwards the commands it receives.\
+/// The state is read with a [Watcher](crate::watch::Watcher).
+///
+/// # Examples
+/// ```no_run
+/// # use bravia_api::{mqtt::{MqttBridge, MqttOptions}, Bravia, error::Result};
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+/// let options = MqttOptions::new("bravia-bridge", "BROKER_ADDRESS", 1883);
+/// MqttBridge::new(&bravia, options)
+///     .topic_prefix("home/living_room/tv")
+///     .run()
+///     .await?;
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MqttBridge {
+    bravia: Bravia,
+    options: MqttOptions,
+    prefix: String,
+    poll_interval: Duration,
+    qos: QoS,
+}
+
+impl MqttBridge {
+    /// # Arguments
+    /// * `bravia` - Client used to read the state and send the commands.
+    /// * `options` - Connection options of the MQTT broker.
+    pub fn new(bravia: &Bravia, options: MqttOptions) -> Self {
+        Self {
+            bravia: bravia.clone(),
+            options,
+            prefix: String::from("bravia"),
+            poll_interval: Duration::from_secs(2),
+            qos: QoS::AtLeastOnce,
+        }
+    }
+
+    /// Prefix of all the topics, `bravia` by default.
+    pub fn topic_prefix(mut self, prefix: &str) -> Self {
+        self.prefix = prefix.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Delay between two polls of the state, 2 seconds by default.
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Quality of service of the published and subscribed topics, at least once by default.
+    pub fn qos(mut self, qos: QoS) -> Self {
+        self.qos = qos;
+        self
+    }
+
+    /// Runs the bridge until the connection to the broker fails.\
+    /// The commands that fail are skipped.
+    ///
+    /// # Errors
+    /// [MqttError](Error::MqttError) if the connection to the broker fails.
+    pub async fn run(self) -> Result<()> {
+        let (client, mut event_loop) = AsyncClient::new(self.options.clone(), CHANNEL_CAPACITY);
+        client
+            .subscribe(format!("{}/+/set", self.prefix), self.qos)
+            .await
+            .map_err(|err| Error::MqttError(err.to_string()))?;
+
+        // The event loop is driven by its own future, since polling it isn't cancel safe
+        let (commands_sender, mut commands) = mpsc::unbounded_channel();
+        let connection = async move {
+            loop {
+                match event_loop.poll().await {
+                    Ok(Event::Incoming(Packet::Publish(publish))) => {
+                        // The receiver lives as long as this future
+                        let _ = commands_sender.send(publish);
+                    }
+                    Ok(_) => {}
+                    Err(err) => return Err(Error::MqttError(err.to_string())),
+                }
+            }
+        };
+
+        let watcher = self.bravia.watch(self.poll_interval);
+        let mut changes = watcher.subscribe();
+        let bridge = async {
+            let watch = watcher.run();
+            tokio::pin!(watch);
+            loop {
+                tokio::select! {
+                    // Never completes since `changes` is alive
+                    _ = &mut watch => return Ok(()),
+                    change = changes.recv() => match change {
+                        Ok(change) => self.publish(&client, change),
+                        Err(RecvError::Lagged(_)) => continue,
+                        Err(RecvError::Closed) => return Ok(()),
+                    },
+                    Some(publish) = commands.recv() => {
+                        let payload = String::from_utf8_lossy(&publish.payload);
+                        if let Err(_err) = self.handle_command(&publish.topic, payload.trim()).await {
+                            #[cfg(feature = "tracing")]
+                            tracing::debug!(topic = publish.topic, error = %_err, "MQTT command failed");
+                        }
+                    }
+                }
+            }
+        };
+
+        tokio::select! {
+            result = connection => result,
+            result = bridge => result,
+        }
+    }
+
+    // Publishes the topics affected by a change.
+    fn publish(&self, client: &AsyncClient, change: ChangeEvent) {
+        let messages = match change {
+            ChangeEvent::PowerStatus(status) => vec![("power", status)],
+            ChangeEvent::Volume(info) => {
+                match info
+                    .iter()
+                    .find(|info| info.target == "speaker")
+                    .or(info.first())
+                {
+                    Some(info) => vec![
+                        ("volume", info.volume.to_string()),
+                        ("mute", info.mute.to_string()),
+                    ],
+                    None => Vec::new(),
+                }
+            }
+            ChangeEvent::PlayingContent(content) => {
+                let input = content
+                    .as_ref()
+                    .filter(|content| content.uri.starts_with("extInput:"))
+                    .map(|content| content.uri.clone())
+                    .unwrap_or_default();
+                let content = content
+                    .and_then(|content| serde_json::to_string(&content).ok())
+                    .unwrap_or_default();
+                vec![("input", input), ("content", content)]
+            }
+        };
+        for (topic, payload) in messages {
+            // Fails only when the queue is full, the next change publishes the state again
+            let _ = client.try_publish(
+                format!("{}/{}", self.prefix, topic),
+                self.qos,
+                true,
+                payload,
+            );
+        }
+    }
+
+    // Forwards a command to the display.
+    async fn handle_command(&self, topic: &str, payload: &str) -> Result<()> {
+        let command = topic
+            .strip_prefix(&self.prefix)
+            .and_then(|topic| topic.strip_prefix('/'))
+            .and_then(|topic| topic.strip_suffix("/set"));
+        match command {
+            Some("power") => {
+                self.bravia
+                    .system()
+                    .set_power_status(parse_switch(payload)?)
+                    .await
+            }
+            Some("volume") => {
+                self.bravia
+                    .audio()
+                    .set_audio_volume(Some("speaker"), payload.to_string(), None, None)
+                    .await
+            }
+            Some("mute") => {
+                self.bravia
+                    .audio()
+                    .set_audio_mute(parse_switch(payload)?)
+                    .await
+            }
+            Some("input") => {
+                self.bravia
+                    .av_content()
+                    .set_play_content(payload.to_string())
+                    .await
+            }
+            Some("app") => {
+                self.bravia
+                    .app_control()
+                    .set_active_app(payload.to_string())
+                    .await
+            }
+            _ => Err(Error::InvalidParameter(format!(
+                "unknown topic {:?}",
+                topic
+            ))),
+        }
+    }
+}
+
+fn parse_switch(payload: &str) -> Result<bool> {
+    match payload.to_ascii_lowercase().as_str() {
+        "on" | "true" | "1" => Ok(true),
+        "off" | "false" | "0" => Ok(false),
+        _ => Err(Error::InvalidParameter(format!(
+            "expected on or off, got {:?}",
+            payload
+        ))),
+    }
+}
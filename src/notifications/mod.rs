@@ -0,0 +1,284 @@
+//! Push notifications sent by the server over WebSocket, an alternative to polling the APIs.\
+//! Each service has its own WebSocket endpoint, the notifications are enabled with `switchNotifications`.
+
+use crate::{
+    audio::VolumeInformation,
+    av_content::{ExternalInputStatus, PlayingContentInfo},
+    error::{BraviaErrorCode, CallContext, Error, Result},
+    Auth, Bravia, ServiceContext,
+};
+use futures_core::Stream;
+use futures_util::{SinkExt, StreamExt};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::{
+    collections::VecDeque,
+    io,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio::net::TcpStream;
+use tokio_tungstenite::{
+    connect_async,
+    tungstenite::{client::IntoClientRequest, http::HeaderValue, Message},
+    MaybeTlsStream, WebSocketStream,
+};
+
+/// Notification sent by the server.\
+/// The payloads are deserialized into the same types returned by the matching getters.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Notification {
+    /// `notifyPowerStatus` - Power status, like `active` or `standby`.
+    PowerStatus(String),
+    /// `notifyVolumeInformation` - Volume or mute status changed.\
+    /// The volume range is not included, so `max_volume` and `min_volume` are `0`.
+    Volume(VolumeInformation),
+    /// `notifyPlayingContentInfo` - Content shown changed.
+    PlayingContent(PlayingContentInfo),
+    /// `notifyExternalTerminalStatus` - External input connected or disconnected.
+    ExternalTerminalStatus(ExternalInputStatus),
+    /// Notifications without a dedicated type, or that couldn't be parsed.
+    Other {
+        /// Notification name.
+        method: String,
+        /// Notification version.
+        version: String,
+        /// Raw parameters.
+        params: Vec<Value>,
+    },
+}
+
+// Message pushed by the server.
+#[derive(Deserialize)]
+struct RawNotification {
+    method: String,
+    #[serde(default)]
+    version: String,
+    #[serde(default)]
+    params: Vec<Value>,
+}
+
+impl From<RawNotification> for Notification {
+    fn from(raw: RawNotification) -> Self {
+        #[derive(Deserialize)]
+        struct PowerStatus {
+            status: String,
+        }
+
+        let first = raw.params.first().cloned().unwrap_or_default();
+        let typed = match raw.method.as_str() {
+            "notifyPowerStatus" => serde_json::from_value::<PowerStatus>(first)
+                .map(|power| Self::PowerStatus(power.status))
+                .ok(),
+            "notifyVolumeInformation" => serde_json::from_value(first).map(Self::Volume).ok(),
+            "notifyPlayingContentInfo" => {
+                serde_json::from_value(first).map(Self::PlayingContent).ok()
+            }
+            "notifyExternalTerminalStatus" => serde_json::from_value(first)
+                .map(Self::ExternalTerminalStatus)
+                .ok(),
+            _ => None,
+        };
+        typed.unwrap_or(Self::Other {
+            method: raw.method,
+            version: raw.version,
+            params: raw.params,
+        })
+    }
+}
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Stream of the notifications sent by a service, returned by
+/// [subscribe](NotificationService::subscribe).\
+/// The stream ends when the server closes the connection.
+pub struct Notifications {
+    socket: Socket,
+    // Notifications received while enabling them.
+    pending: VecDeque<Notification>,
+}
+
+impl Stream for Notifications {
+    type Item = Result<Notification>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if let Some(notification) = self.pending.pop_front() {
+            return Poll::Ready(Some(Ok(notification)));
+        }
+        loop {
+            match self.socket.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(Message::Text(text)))) => match parse_message(&text) {
+                    Ok(Incoming::Notification(notification)) => {
+                        return Poll::Ready(Some(Ok(*notification)))
+                    }
+                    // Results of previous calls are not relevant anymore
+                    Ok(Incoming::Response(..)) => continue,
+                    Err(err) => return Poll::Ready(Some(Err(err))),
+                },
+                Poll::Ready(Some(Ok(Message::Close(_)))) | Poll::Ready(None) => {
+                    return Poll::Ready(None)
+                }
+                // Pings are answered by the WebSocket implementation
+                Poll::Ready(Some(Ok(_))) => continue,
+                Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err.into()))),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+// Messages received on the WebSocket.
+enum Incoming {
+    Notification(Box<Notification>),
+    Response(usize, std::result::Result<Value, BraviaErrorCode>),
+}
+
+fn parse_message(text: &str) -> Result<Incoming> {
+    let mut parsed: Value = serde_json::from_str(text)?;
+    if parsed.get("method").is_some() {
+        let raw: RawNotification = serde_json::from_value(parsed)?;
+        return Ok(Incoming::Notification(Box::new(raw.into())));
+    }
+    let id = parsed.get("id").and_then(Value::as_u64).unwrap_or_default() as usize;
+    if let Some(result) = parsed.get_mut("result") {
+        Ok(Incoming::Response(id, Ok(result.take())))
+    } else if let Some(error) = parsed.get_mut("error") {
+        Ok(Incoming::Response(
+            id,
+            Err(serde_json::from_value(error.take())?),
+        ))
+    } else {
+        Err(Error::InvalidResponse("Missing result and error fields."))
+    }
+}
+
+/// Provides access to the WebSocket notifications.\
+/// The subscriptions are never retried, the timeout of the handle limits the time
+/// to connect and enable the notifications.
+pub struct NotificationService(ServiceContext);
+
+impl_service_options!(NotificationService);
+
+impl NotificationService {
+    pub fn new(bravia: &Bravia) -> Self {
+        Self(ServiceContext::new(bravia))
+    }
+
+    /// Connects to the WebSocket endpoint of a service and enables its notifications.
+    ///
+    /// # Arguments
+    /// * `service` - Service name, like `system`, `audio` or `avContent`.
+    /// * `notifications` - Names of the notifications to enable, like `notifyPowerStatus`.\
+    ///   The default value is `None`, which means all the notifications supported by the service.
+    ///
+    /// # Authentication Level
+    /// Generic
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # use futures_util::StreamExt;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// let mut notifications = bravia.notifications().subscribe("system", None).await?;
+    /// while let Some(notification) = notifications.next().await {
+    ///     println!("{:?}", notification?);
+    /// }
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn subscribe(
+        &self,
+        service: &str,
+        notifications: Option<&[&str]>,
+    ) -> Result<Notifications> {
+        let subscribe = self.connect(service, notifications);
+        match self.0.options.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, subscribe)
+                .await
+                .map_err(|_| {
+                    Error::IoError(io::Error::new(
+                        io::ErrorKind::TimedOut,
+                        "timed out while subscribing to the notifications",
+                    ))
+                })?,
+            None => subscribe.await,
+        }
+    }
+
+    async fn connect(
+        &self,
+        service: &str,
+        notifications: Option<&[&str]>,
+    ) -> Result<Notifications> {
+        let target = self.0.bravia.target();
+        let url = format!("{}{}", target.base_url, service);
+        let url = url
+            .strip_prefix("http")
+            .map(|url| format!("ws{url}"))
+            .unwrap_or(url);
+        let mut request = url.as_str().into_client_request()?;
+        let header = match &target.auth {
+            Some(Auth::Psk(psk)) => Some(("X-Auth-PSK", psk.clone())),
+            Some(Auth::Cookie(cookie)) => Some(("Cookie", format!("auth={cookie}"))),
+            None => None,
+        };
+        if let Some((name, value)) = header {
+            let value = HeaderValue::from_str(&value)
+                .map_err(|_| Error::InvalidParameter(String::from("invalid credentials")))?;
+            request.headers_mut().insert(name, value);
+        }
+
+        let (socket, _) = connect_async(request).await?;
+        let mut stream = Notifications {
+            socket,
+            pending: VecDeque::new(),
+        };
+        let call = CallContext {
+            endpoint: service.to_string(),
+            method: String::from("switchNotifications"),
+            version: String::from("1.0"),
+        };
+
+        // Reads the notifications supported by the service
+        let current = stream.call(1, json!({}), &call).await?;
+        let enabled: Vec<Value> = ["enabled", "disabled"]
+            .iter()
+            .filter_map(|key| current.get(0)?.get(key)?.as_array())
+            .flatten()
+            .filter(|item| {
+                let name = item.get("name").and_then(Value::as_str).unwrap_or_default();
+                notifications.is_none_or(|names| names.contains(&name))
+            })
+            .cloned()
+            .collect();
+        stream.call(2, json!({ "enabled": enabled }), &call).await?;
+        Ok(stream)
+    }
+}
+
+impl Notifications {
+    // Calls switchNotifications and waits for its result.
+    async fn call(&mut self, id: usize, params: Value, call: &CallContext) -> Result<Value> {
+        let body = json!({
+            "method": call.method,
+            "id": id,
+            "params": [params],
+            "version": call.version,
+        });
+        self.socket.send(Message::text(body.to_string())).await?;
+        while let Some(message) = self.socket.next().await {
+            if let Message::Text(text) = message? {
+                match parse_message(&text)? {
+                    Incoming::Notification(notification) => self.pending.push_back(*notification),
+                    Incoming::Response(response_id, result) if response_id == id => {
+                        return result.map_err(|err| Error::BraviaError(err, call.clone()));
+                    }
+                    Incoming::Response(..) => {}
+                }
+            }
+        }
+        Err(Error::InvalidResponse("Connection closed by the server."))
+    }
+}
@@ -0,0 +1,151 @@
+//! Settings that can be saved and applied again later, like after a calibration or a demo.
+
+use crate::{
+    audio::{SoundSettings, SpeakerSettings},
+    error::{Error, Result},
+    system::{LEDIndicatorStatus, PowerSavingMode},
+    video::PictureQualitySettingsRequest,
+    Bravia,
+};
+use serde::{Deserialize, Serialize};
+
+/// Values of the settings of a display, read by [settings_profile](Bravia::settings_profile)
+/// and applied by [apply_settings_profile](Bravia::apply_settings_profile).\
+/// Settings that the display doesn't support are left empty and aren't applied.
+///
+/// # Examples
+/// ```no_run
+/// # use bravia_api::{profile::SettingsProfile, Bravia, error::Result};
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+/// let profile = bravia.settings_profile().await?;
+/// std::fs::write("profile.json", profile.to_json()?).unwrap();
+///
+/// let json = std::fs::read_to_string("profile.json").unwrap();
+/// bravia.apply_settings_profile(&SettingsProfile::from_json(&json)?).await?;
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SettingsProfile {
+    /// Picture quality settings that are currently available.
+    #[serde(default)]
+    pub picture_quality: Vec<PictureQualitySettingsRequest>,
+    /// Sound settings.
+    #[serde(default)]
+    pub sound: Vec<SoundSettings>,
+    /// Speaker settings.
+    #[serde(default)]
+    pub speaker: Vec<SpeakerSettings>,
+    /// Power saving mode.
+    #[serde(default)]
+    pub power_saving_mode: Option<PowerSavingMode>,
+    /// LED indicator mode.
+    #[serde(default)]
+    pub led_indicator: Option<LEDIndicatorStatus>,
+}
+
+impl SettingsProfile {
+    /// Serializes the profile to JSON.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Loads the profile from JSON created by [to_json](Self::to_json).
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+impl Bravia {
+    /// Reads the current picture quality, sound, speaker, power saving and LED indicator settings.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn settings_profile(&self) -> Result<SettingsProfile> {
+        let (video, audio, system) = (self.video(), self.audio(), self.system());
+        let (picture_quality, sound, speaker, power_saving_mode, led_indicator) = tokio::join!(
+            video.get_picture_quality_settings(None),
+            audio.get_sound_settings(None),
+            audio.get_speaker_settings(None),
+            system.get_power_saving_mode(),
+            system.get_led_indicator_status(),
+        );
+
+        let picture_quality = unsupported_as_none(picture_quality)?
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|setting| setting.is_available)
+            .map(|setting| {
+                PictureQualitySettingsRequest::new(
+                    Some(setting.target),
+                    Some(&setting.current_value),
+                )
+            })
+            .collect();
+        Ok(SettingsProfile {
+            picture_quality,
+            sound: unsupported_as_none(sound)?.unwrap_or_default(),
+            speaker: unsupported_as_none(speaker)?.unwrap_or_default(),
+            power_saving_mode: unsupported_as_none(power_saving_mode)?,
+            led_indicator: unsupported_as_none(led_indicator)?,
+        })
+    }
+
+    /// Applies the settings of a profile, skipping the empty ones.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn apply_settings_profile(&self, profile: &SettingsProfile) -> Result<()> {
+        if !profile.picture_quality.is_empty() {
+            self.video()
+                .set_picture_quality_settings(profile.picture_quality.clone())
+                .await?;
+        }
+        if !profile.sound.is_empty() {
+            self.audio()
+                .set_sound_settings(profile.sound.clone())
+                .await?;
+        }
+        if !profile.speaker.is_empty() {
+            self.audio()
+                .set_speaker_settings(profile.speaker.clone())
+                .await?;
+        }
+        if let Some(mode) = &profile.power_saving_mode {
+            self.system().set_power_saving_mode(mode.clone()).await?;
+        }
+        if let Some(led_indicator) = &profile.led_indicator {
+            // The status is only applied by the version 1.1
+            let versions = self
+                .supported_versions("system", "setLEDIndicatorStatus")
+                .await?
+                .unwrap_or_default();
+            let version = if versions.iter().any(|x| x == "1.1") {
+                "1.1"
+            } else {
+                "1.0"
+            };
+            self.system()
+                .set_led_indicator_status(led_indicator.clone(), Some(version))
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+// Errors returned by the server for the settings that the display doesn't have:
+// No Such Method, Unsupported Version and Unsupported Operation.
+const UNSUPPORTED_CODES: [usize; 3] = [12, 14, 15];
+
+// The settings that the display doesn't have are empty,
+// the other errors, like when the display is turned off, are returned.
+fn unsupported_as_none<T>(result: Result<T>) -> Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(Error::BraviaError(code, _)) if UNSUPPORTED_CODES.contains(&code.code) => Ok(None),
+        Err(Error::BraviaApiServiceNotFound | Error::BraviaApiNotFound) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
@@ -0,0 +1,55 @@
+//! Token bucket used to limit the rate of the requests sent to the server.
+
+use crate::runtime::{sleep, Instant};
+use std::{
+    sync::{Mutex, PoisonError},
+    time::Duration,
+};
+
+#[derive(Debug)]
+struct State {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+/// Allows bursts up to `capacity` requests, then one request every `period / capacity`.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    capacity: f64,
+    // Tokens added each second.
+    refill_rate: f64,
+    state: Mutex<State>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(requests: u32, period: Duration) -> Self {
+        let capacity = f64::from(requests.max(1));
+        Self {
+            capacity,
+            refill_rate: capacity / period.as_secs_f64().max(f64::EPSILON),
+            state: Mutex::new(State {
+                tokens: capacity,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Waits until a request can be sent.
+    pub(crate) async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+                let now = Instant::now();
+                let elapsed = now.duration_since(state.last_refill).as_secs_f64();
+                state.tokens = (state.tokens + elapsed * self.refill_rate).min(self.capacity);
+                state.last_refill = now;
+                if state.tokens >= 1.0 {
+                    state.tokens -= 1.0;
+                    return;
+                }
+                Duration::from_secs_f64((1.0 - state.tokens) / self.refill_rate)
+            };
+            sleep(wait).await;
+        }
+    }
+}
@@ -0,0 +1,297 @@
+//! APIs related to the recording of broadcast programs, available on the models with PVR support.
+
+use crate::{error::Result, lenient, Bravia, RequestBodyBuilder, RequestBuilder, ServiceContext};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+const ENDPOINT: &str = "recording";
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Schedule {
+    /// Identifier of the schedule, used by [deleteSchedule](RecordingService::delete_schedule).
+    pub id: String,
+    /// URI of the channel to record.
+    pub uri: String,
+    /// Title of the program.
+    #[serde(default)]
+    pub title: Option<String>,
+    /// Name of the channel.
+    #[serde(default)]
+    pub channel_name: Option<String>,
+    /// Start time in ISO 8601 format, like `2018-10-03T20:00:00+0100`.
+    pub start_date_time: String,
+    /// Duration of the recording in seconds.
+    #[serde(deserialize_with = "lenient::number")]
+    pub duration_sec: u64,
+    /// Repetition of the schedule, one of the values returned by
+    /// [getSupportedRepeatType](RecordingService::get_supported_repeat_type).
+    pub repeat_type: String,
+    /// Schedule status.
+    /// * `scheduled` - The recording will start at the scheduled time.
+    /// * `recording` - The program is being recorded.
+    /// * `conflict` - The schedule overlaps with another one.
+    #[serde(default)]
+    pub status: Option<String>,
+}
+
+/// Parameters of a new schedule, see [addSchedule](RecordingService::add_schedule).
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleRequest {
+    /// URI of the channel to record.
+    pub uri: String,
+    /// Start time in ISO 8601 format, like `2018-10-03T20:00:00+0100`.
+    pub start_date_time: String,
+    /// Duration of the recording in seconds.
+    pub duration_sec: u64,
+    /// Repetition of the schedule, `None` records only once.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repeat_type: Option<String>,
+    /// Title of the program, `None` lets the server take it from the program guide.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+impl ScheduleRequest {
+    pub fn new(
+        uri: impl Into<String>,
+        start_date_time: impl Into<String>,
+        duration_sec: u64,
+    ) -> Self {
+        Self {
+            uri: uri.into(),
+            start_date_time: start_date_time.into(),
+            duration_sec,
+            repeat_type: None,
+            title: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordedProgram {
+    /// URI to identify the recorded content.
+    pub uri: String,
+    /// Title of the program.
+    pub title: String,
+    /// Name of the channel.
+    #[serde(default)]
+    pub channel_name: Option<String>,
+    /// Start time of the recording in ISO 8601 format.
+    pub start_date_time: String,
+    /// Duration of the recording in seconds.
+    #[serde(deserialize_with = "lenient::number")]
+    pub duration_sec: u64,
+}
+
+/// Provides access to recording service APIs.
+pub struct RecordingService(ServiceContext);
+
+impl_service_options!(RecordingService);
+
+impl RecordingService {
+    pub fn new(bravia: &Bravia) -> Self {
+        Self(ServiceContext::new(bravia))
+    }
+
+    /// Provides the recording status of the device.
+    /// * `recording` - A program is being recorded.
+    /// * `notRecording` - Nothing is being recorded.
+    ///
+    /// # Authentication Level
+    /// Private
+    pub async fn get_recording_status(&self) -> Result<String> {
+        let body = RequestBodyBuilder::default()
+            .id(150)
+            .method("getRecordingStatus")
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .has_result()
+            .get("status".into())
+            .make_as(&self.0)
+            .await
+    }
+
+    /// Provides the repetitions that can be used for the schedules, like `weekly`.
+    ///
+    /// # Authentication Level
+    /// Private
+    pub async fn get_supported_repeat_type(&self) -> Result<Vec<String>> {
+        let body = RequestBodyBuilder::default()
+            .id(151)
+            .method("getSupportedRepeatType")
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .has_result()
+            .make_as(&self.0)
+            .await
+    }
+
+    /// Provides the list of the recorded programs.
+    ///
+    /// # Arguments
+    /// * `st_idx` - Start index to get list items. The default value is 0.
+    /// * `cnt` - Count of the maximum number of items that can be listed, starting from `st_idx`.
+    ///
+    /// # Authentication Level
+    /// Private
+    pub async fn get_history_list(
+        &self,
+        st_idx: Option<u32>,
+        cnt: Option<u32>,
+    ) -> Result<Vec<RecordedProgram>> {
+        let body = RequestBodyBuilder::default()
+            .id(152)
+            .method("getHistoryList")
+            .params(page_params(st_idx, cnt))
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .has_result()
+            .make_as(&self.0)
+            .await
+    }
+
+    /// Provides the list of the recording schedules.
+    ///
+    /// # Arguments
+    /// * `st_idx` - Start index to get list items. The default value is 0.
+    /// * `cnt` - Count of the maximum number of items that can be listed, starting from `st_idx`.
+    ///
+    /// # Authentication Level
+    /// Private
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// for schedule in bravia.recording().get_schedule_list(None, Some(20)).await? {
+    ///     println!("{} {:?}", schedule.start_date_time, schedule.title);
+    /// }
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn get_schedule_list(
+        &self,
+        st_idx: Option<u32>,
+        cnt: Option<u32>,
+    ) -> Result<Vec<Schedule>> {
+        let body = RequestBodyBuilder::default()
+            .id(153)
+            .method("getScheduleList")
+            .params(page_params(st_idx, cnt))
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .has_result()
+            .make_as(&self.0)
+            .await
+    }
+
+    /// Provides the list of the schedules that overlap with other schedules.
+    ///
+    /// # Arguments
+    /// * `st_idx` - Start index to get list items. The default value is 0.
+    /// * `cnt` - Count of the maximum number of items that can be listed, starting from `st_idx`.
+    ///
+    /// # Authentication Level
+    /// Private
+    pub async fn get_conflict_schedule_list(
+        &self,
+        st_idx: Option<u32>,
+        cnt: Option<u32>,
+    ) -> Result<Vec<Schedule>> {
+        let body = RequestBodyBuilder::default()
+            .id(154)
+            .method("getConflictScheduleList")
+            .params(page_params(st_idx, cnt))
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .has_result()
+            .make_as(&self.0)
+            .await
+    }
+
+    /// Adds a recording schedule and returns its identifier.
+    ///
+    /// # Authentication Level
+    /// Private
+    ///
+    /// # Note
+    /// This request is never retried, regardless of the [RetryPolicy](crate::retry::RetryPolicy).
+    pub async fn add_schedule(&self, schedule: &ScheduleRequest) -> Result<String> {
+        let body = RequestBodyBuilder::default()
+            .id(155)
+            .method("addSchedule")
+            .params(serde_json::to_value(schedule)?)
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .no_retry()
+            .has_result()
+            .get("id".into())
+            .make_as(&self.0)
+            .await
+    }
+
+    /// Deletes a recording schedule.
+    ///
+    /// # Arguments
+    /// * `id` - Identifier of the schedule, returned by [addSchedule](Self::add_schedule)
+    ///   or [getScheduleList](Self::get_schedule_list).
+    ///
+    /// # Authentication Level
+    /// Private
+    ///
+    /// # Note
+    /// This request is never retried, regardless of the [RetryPolicy](crate::retry::RetryPolicy).
+    pub async fn delete_schedule(&self, id: impl Into<String>) -> Result<()> {
+        let mut params = Map::new();
+        params.insert(String::from("id"), Value::from(id.into()));
+
+        let body = RequestBodyBuilder::default()
+            .id(156)
+            .method("deleteSchedule")
+            .params(Value::from(params))
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .no_retry()
+            .make(&self.0)
+            .await?;
+        Ok(())
+    }
+}
+
+// Pagination parameters of the list APIs.
+fn page_params(st_idx: Option<u32>, cnt: Option<u32>) -> Value {
+    let mut params = Map::new();
+    if let Some(st_idx) = st_idx {
+        params.insert(String::from("stIdx"), Value::from(st_idx));
+    }
+    if let Some(cnt) = cnt {
+        params.insert(String::from("cnt"), Value::from(cnt));
+    }
+    Value::from(params)
+}
@@ -0,0 +1,167 @@
+//! Policy used to retry the requests that failed because of transient errors.
+
+use crate::error::Error;
+use rand::Rng;
+#[cfg(not(target_arch = "wasm32"))]
+use std::net::SocketAddr;
+use std::time::Duration;
+
+/// Describes when and how many times a failed request is sent again.\
+/// The delay between two attempts grows exponentially, starting from
+/// [initial_backoff](Self::initial_backoff) up to [max_backoff](Self::max_backoff).
+///
+/// # Examples
+/// ```no_run
+/// # use bravia_api::{retry::RetryPolicy, Bravia, error::Result};
+/// # use std::time::Duration;
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// let bravia = Bravia::builder("ADDRESS")
+///     .retry_policy(RetryPolicy {
+///         max_attempts: 5,
+///         initial_backoff: Duration::from_millis(500),
+///         ..Default::default()
+///     })
+///     .build()
+///     .await?;
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one.
+    pub max_attempts: u32,
+    /// Delay before the first retry.
+    pub initial_backoff: Duration,
+    /// Maximum delay between two attempts.
+    pub max_backoff: Duration,
+    /// Factor applied to the delay after each retry.
+    pub multiplier: f64,
+    /// Randomizes each delay between half and the whole computed value,
+    /// so that several clients don't retry at the same time.
+    pub jitter: bool,
+    /// Retries the requests that failed to reach the server or timed out.
+    pub retry_on_network_errors: bool,
+    /// Retries the requests that received a `5xx` status code.
+    pub retry_on_server_errors: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2.0,
+            jitter: true,
+            retry_on_network_errors: true,
+            retry_on_server_errors: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Policy that never retries a request.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Checks if the request that returned `err` can be sent again.
+    pub(crate) fn should_retry(&self, err: &Error) -> bool {
+        match err {
+            Error::NetworkError(err) => {
+                self.retry_on_network_errors
+                    && (err.is_timeout() || err.is_request() || is_connect(err))
+            }
+            Error::BadStatus(status, _) => self.retry_on_server_errors && status.is_server_error(),
+            _ => false,
+        }
+    }
+
+    /// Delay before the given retry (starting from `1`).
+    pub(crate) fn backoff(&self, retry: u32) -> Duration {
+        let exponent = i32::try_from(retry.saturating_sub(1)).unwrap_or(i32::MAX);
+        let backoff = self
+            .initial_backoff
+            .mul_f64(self.multiplier.max(1.0).powi(exponent).min(u32::MAX.into()))
+            .min(self.max_backoff);
+        if self.jitter {
+            backoff.mul_f64(rand::thread_rng().gen_range(0.5..=1.0))
+        } else {
+            backoff
+        }
+    }
+}
+
+/// Describes how to wake up a display in standby when a request fails because it is turned off.\
+/// A Wake-on-LAN packet is sent, then the power status is polled until it is `active`
+/// and the failed request is sent again once.
+///
+/// The MAC address is read with [getSystemSupportedFunction](crate::system::SystemService::get_system_supported_function)
+/// or [getSystemInformation](crate::system::SystemService::get_system_information)
+/// the first time it is needed, unless it is set in the policy.
+///
+/// # Examples
+/// ```no_run
+/// # use bravia_api::{retry::WakePolicy, Bravia, error::Result};
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// let bravia = Bravia::builder("ADDRESS")
+///     .auth("PASSWORD")
+///     .wake_policy(WakePolicy::default())
+///     .build()
+///     .await?;
+/// #    Ok(())
+/// # }
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct WakePolicy {
+    /// MAC address of the display, read from the server when `None`.
+    pub mac_address: Option<String>,
+    /// Address the Wake-on-LAN packet is sent to.
+    pub broadcast_address: SocketAddr,
+    /// Maximum time to wait for the display to turn on.
+    pub wake_timeout: Duration,
+    /// Delay between two power status checks.
+    pub poll_interval: Duration,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Default for WakePolicy {
+    fn default() -> Self {
+        Self {
+            mac_address: None,
+            broadcast_address: crate::wol::DEFAULT_BROADCAST_ADDRESS,
+            wake_timeout: Duration::from_secs(20),
+            poll_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl WakePolicy {
+    /// Checks if the request that returned `err` failed because the display is turned off.
+    pub(crate) fn should_wake(&self, err: &Error) -> bool {
+        match err {
+            // Display Is Turned off
+            Error::BraviaError(code, _) => code.code == 40005,
+            Error::NetworkError(err) => err.is_timeout(),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn is_connect(err: &reqwest::Error) -> bool {
+    err.is_connect()
+}
+
+// Connection errors can't be distinguished on `wasm32` targets.
+#[cfg(target_arch = "wasm32")]
+fn is_connect(_err: &reqwest::Error) -> bool {
+    false
+}
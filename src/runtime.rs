@@ -0,0 +1,8 @@
+//! Timer utilities that work both on native targets (with tokio) and on `wasm32` targets.
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) use gloo_timers::future::sleep;
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use tokio::time::{sleep, Instant};
+#[cfg(target_arch = "wasm32")]
+pub(crate) use web_time::Instant;
@@ -0,0 +1,180 @@
+//! Generic settings APIs exposed by the newer devices, used to read and change
+//! any setting target without a dedicated wrapper.
+//!
+//! # Note
+//! The services that provide these APIs and the available targets depend on the device,
+//! use [get_settings_tree](SettingsService::get_settings_tree) to discover them.
+
+use crate::{
+    error::Result, lenient, video::Candidate, Bravia, RequestBodyBuilder, RequestBuilder,
+    ServiceContext,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+fn bool_true() -> bool {
+    true
+}
+
+/// Node of the settings tree, like a menu of the settings UI.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsTreeNode {
+    /// Target name, only set for the nodes that can be read or changed.
+    pub target: Option<String>,
+    /// Title of the node to be recognized by the user.
+    pub title: Option<String>,
+    /// This node is currently available or not.
+    #[serde(default = "bool_true", deserialize_with = "lenient::bool")]
+    pub is_available: bool,
+    /// Nodes contained in this node.
+    #[serde(default)]
+    pub children: Vec<SettingsTreeNode>,
+}
+
+impl SettingsTreeNode {
+    /// Returns the targets of this node and of all the nodes it contains.
+    pub fn targets(&self) -> Vec<&str> {
+        let mut targets: Vec<&str> = self.target.as_deref().into_iter().collect();
+        for child in &self.children {
+            targets.extend(child.targets());
+        }
+        targets
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Setting {
+    /// Target name.
+    pub target: String,
+    /// Title of the target to be recognized by the user.
+    pub title: Option<String>,
+    /// Current value of target.
+    pub current_value: String,
+    /// This target is currently available or not.
+    #[serde(default = "bool_true", deserialize_with = "lenient::bool")]
+    pub is_available: bool,
+    /// Type of the value, like `enumeration`, `integerTarget` or `booleanTarget`.
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+    /// Values accepted by the target.
+    pub candidate: Option<Vec<Candidate>>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SettingValue {
+    /// Target name.
+    pub target: String,
+    /// The value to set for target name.
+    pub value: String,
+}
+
+impl SettingValue {
+    pub fn new(target: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            target: target.into(),
+            value: value.into(),
+        }
+    }
+}
+
+/// Provides access to the generic settings APIs of a service.
+pub struct SettingsService(ServiceContext, String);
+
+impl_service_options!(SettingsService);
+
+impl SettingsService {
+    /// Creates a handle to the settings APIs of `service`, like `system` or `video`.
+    pub fn new(bravia: &Bravia, service: &str) -> Self {
+        Self(ServiceContext::new(bravia), service.to_string())
+    }
+
+    /// Provides the structure of the settings of the service.
+    ///
+    /// # Authentication Level
+    /// Generic
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// let settings = bravia.settings("system");
+    /// for node in settings.get_settings_tree().await? {
+    ///     for target in node.targets() {
+    ///         println!("{:?}", settings.get_settings(Some(target)).await?);
+    ///     }
+    /// }
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn get_settings_tree(&self) -> Result<Vec<SettingsTreeNode>> {
+        let body = RequestBodyBuilder::default()
+            .id(180)
+            .method("getSettingsTree")
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(&self.1)
+            .body(body)
+            .is_protected()
+            .has_result()
+            .make_as(&self.0)
+            .await
+    }
+
+    /// Provides the current value and the candidates of the settings.
+    ///
+    /// # Arguments
+    /// `target`
+    /// * `None` - This indicates the settings of all targets.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn get_settings(&self, target: Option<&str>) -> Result<Vec<Setting>> {
+        let mut params = Map::new();
+        params.insert(String::from("target"), Value::from(target));
+
+        let body = RequestBodyBuilder::default()
+            .id(181)
+            .method("getSettings")
+            .params(Value::from(params))
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(&self.1)
+            .body(body)
+            .is_protected()
+            .has_result()
+            .make_as(&self.0)
+            .await
+    }
+
+    /// Provides the function to change the value of the settings.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn set_settings(
+        &self,
+        settings: impl IntoIterator<Item = SettingValue>,
+    ) -> Result<()> {
+        let mut params = Map::new();
+        params.insert(
+            String::from("settings"),
+            serde_json::to_value(settings.into_iter().collect::<Vec<_>>())?,
+        );
+
+        let body = RequestBodyBuilder::default()
+            .id(182)
+            .method("setSettings")
+            .params(Value::from(params))
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(&self.1)
+            .body(body)
+            .is_protected()
+            .make(&self.0)
+            .await?;
+        Ok(())
+    }
+}
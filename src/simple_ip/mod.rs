@@ -0,0 +1,206 @@
+//! Client for Sony's Simple IP Control protocol, available on the professional displays
+//! over TCP port 20060 even when the REST API is disabled.
+//!
+//! Each message is 24 bytes long: `*S`, the message type, a four letters command,
+//! sixteen characters of parameters and a line feed.
+
+use crate::error::{Error, Result};
+use std::{fmt, time::Duration};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+};
+
+/// Default port of the Simple IP Control protocol.
+pub const DEFAULT_PORT: u16 = 20060;
+
+const MESSAGE_LEN: usize = 24;
+const ERROR_PARAMETER: &str = "FFFFFFFFFFFFFFFF";
+const ENQUIRY_PARAMETER: &str = "################";
+const INVALID_ANSWER: Error = Error::InvalidResponse("Invalid Simple IP Control answer.");
+
+/// Input source of the display.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Input {
+    Hdmi(u32),
+    Scart(u32),
+    Composite(u32),
+    Component(u32),
+    ScreenMirroring(u32),
+    Pc(u32),
+}
+
+impl Input {
+    // Input type and port as defined by the protocol.
+    fn to_parts(self) -> (u32, u32) {
+        match self {
+            Self::Hdmi(port) => (1, port),
+            Self::Scart(port) => (2, port),
+            Self::Composite(port) => (3, port),
+            Self::Component(port) => (4, port),
+            Self::ScreenMirroring(port) => (5, port),
+            Self::Pc(port) => (6, port),
+        }
+    }
+
+    fn from_parts(kind: u32, port: u32) -> Option<Self> {
+        match kind {
+            1 => Some(Self::Hdmi(port)),
+            2 => Some(Self::Scart(port)),
+            3 => Some(Self::Composite(port)),
+            4 => Some(Self::Component(port)),
+            5 => Some(Self::ScreenMirroring(port)),
+            6 => Some(Self::Pc(port)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (name, port) = match self {
+            Self::Hdmi(port) => ("HDMI", port),
+            Self::Scart(port) => ("SCART", port),
+            Self::Composite(port) => ("Composite", port),
+            Self::Component(port) => ("Component", port),
+            Self::ScreenMirroring(port) => ("Screen mirroring", port),
+            Self::Pc(port) => ("PC", port),
+        };
+        write!(f, "{name} {port}")
+    }
+}
+
+/// Simple IP Control connection to a display.
+///
+/// # Examples
+/// ```no_run
+/// # use bravia_api::{simple_ip::{Input, SimpleIpClient}, error::Result};
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// let mut client = SimpleIpClient::connect("192.168.1.10").await?;
+/// client.set_power(true).await?;
+/// client.set_input(Input::Hdmi(2)).await?;
+/// client.set_volume(15).await?;
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct SimpleIpClient {
+    stream: TcpStream,
+    timeout: Duration,
+}
+
+impl SimpleIpClient {
+    /// # Arguments
+    /// * `address` - Server address, the [default port](DEFAULT_PORT) is used if not specified.
+    pub async fn connect(address: &str) -> Result<Self> {
+        let stream = if address.contains(':') && !address.ends_with(']') {
+            TcpStream::connect(address).await?
+        } else {
+            let host = address.trim_start_matches('[').trim_end_matches(']');
+            TcpStream::connect((host, DEFAULT_PORT)).await?
+        };
+        Ok(Self {
+            stream,
+            timeout: Duration::from_secs(5),
+        })
+    }
+
+    /// Maximum time to wait for the answer to a command.\
+    /// The default value is 5 seconds.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Turns the display on or off.
+    pub async fn set_power(&mut self, on: bool) -> Result<()> {
+        self.control("POWR", &number(u32::from(on))).await
+    }
+
+    /// Returns `true` if the display is on.
+    pub async fn power(&mut self) -> Result<bool> {
+        Ok(self.enquiry_number("POWR").await? == 1)
+    }
+
+    /// Sets the volume of the speakers.
+    pub async fn set_volume(&mut self, volume: u32) -> Result<()> {
+        self.control("VOLU", &number(volume)).await
+    }
+
+    /// Returns the volume of the speakers.
+    pub async fn volume(&mut self) -> Result<u32> {
+        self.enquiry_number("VOLU").await
+    }
+
+    /// Mutes or unmutes the speakers.
+    pub async fn set_mute(&mut self, mute: bool) -> Result<()> {
+        self.control("AMUT", &number(u32::from(mute))).await
+    }
+
+    /// Returns `true` if the speakers are muted.
+    pub async fn mute(&mut self) -> Result<bool> {
+        Ok(self.enquiry_number("AMUT").await? == 1)
+    }
+
+    /// Switches to the given input.
+    pub async fn set_input(&mut self, input: Input) -> Result<()> {
+        let (kind, port) = input.to_parts();
+        self.control("INPT", &format!("{kind:08}{port:08}")).await
+    }
+
+    /// Returns the current input.
+    pub async fn input(&mut self) -> Result<Input> {
+        let answer = self.send('E', "INPT", ENQUIRY_PARAMETER).await?;
+        let kind = answer.get(..8).and_then(|kind| kind.parse().ok());
+        let port = answer.get(8..).and_then(|port| port.parse().ok());
+        kind.zip(port)
+            .and_then(|(kind, port)| Input::from_parts(kind, port))
+            .ok_or(INVALID_ANSWER)
+    }
+
+    async fn control(&mut self, command: &str, parameter: &str) -> Result<()> {
+        self.send('C', command, parameter).await.map(|_| ())
+    }
+
+    async fn enquiry_number(&mut self, command: &str) -> Result<u32> {
+        let answer = self.send('E', command, ENQUIRY_PARAMETER).await?;
+        answer.parse().map_err(|_| INVALID_ANSWER)
+    }
+
+    // Sends a message and returns the parameter of its answer.
+    async fn send(&mut self, kind: char, command: &str, parameter: &str) -> Result<String> {
+        let message = format!("*S{kind}{command}{parameter}\n");
+        let exchange = async {
+            self.stream.write_all(message.as_bytes()).await?;
+            loop {
+                let mut buffer = [0; MESSAGE_LEN];
+                self.stream.read_exact(&mut buffer).await?;
+                // Notifications sent by the display in the meantime are skipped
+                if buffer.starts_with(b"*SA") && &buffer[3..7] == command.as_bytes() {
+                    return Ok(String::from_utf8_lossy(&buffer[7..23]).into_owned());
+                }
+            }
+        };
+        let answer: Result<String> =
+            tokio::time::timeout(self.timeout, exchange)
+                .await
+                .map_err(|_| {
+                    Error::IoError(std::io::Error::new(
+                        std::io::ErrorKind::TimedOut,
+                        "timed out while waiting for the answer",
+                    ))
+                })?;
+        let answer = answer?;
+        if answer == ERROR_PARAMETER {
+            Err(Error::SimpleIpError(command.to_string()))
+        } else {
+            Ok(answer)
+        }
+    }
+}
+
+// Formats a number as a parameter.
+fn number(value: u32) -> String {
+    format!("{value:016}")
+}
@@ -0,0 +1,379 @@
+//! In-process display that keeps its state between the calls, to run the examples and the tests
+//! of the applications through whole flows without the hardware. Requires the `simulator` feature.
+//!
+//! Only the APIs to turn the display on and off, change the volume, switch the input
+//! and launch the applications are simulated, the other calls fail with the error `12`
+//! (No Such Method). While the display is off the calls fail with the error `40005`,
+//! like on a real display.
+//!
+//! # Examples
+//! ```
+//! # use bravia_api::{error::Result, simulator::Simulator};
+//! # #[tokio::main]
+//! # async fn main() -> Result<()> {
+//! let simulator = Simulator::start().await?;
+//! let bravia = simulator.client(Some("PASSWORD")).await?;
+//!
+//! bravia.system().set_power_status(true).await?;
+//! bravia.av_content().set_play_content("extInput:hdmi?port=2").await?;
+//! bravia
+//!     .audio()
+//!     .set_audio_volume(Some("speaker"), "30", None, None)
+//!     .await?;
+//!
+//! let state = simulator.state();
+//! assert_eq!(Some("extInput:hdmi?port=2"), state.input.as_deref());
+//! assert_eq!(30, state.volume);
+//! #    Ok(())
+//! # }
+//! ```
+
+use crate::{app_control::Application, av_content::ExternalInputStatus, error::Result, Bravia};
+use hyper::{
+    body::to_bytes,
+    service::{make_service_fn, service_fn},
+    Body, Method, Request, Response, Server, StatusCode,
+};
+use serde_json::{json, Value};
+use std::{
+    convert::Infallible,
+    io,
+    net::{SocketAddr, TcpListener},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+use tokio::sync::oneshot;
+
+// Name and versions of an API.
+type Api = (&'static str, &'static [&'static str]);
+
+// Simulated APIs of each service.
+const SUPPORTED_APIS: [(&str, &[Api]); 4] = [
+    (
+        "system",
+        &[("getPowerStatus", &["1.0"]), ("setPowerStatus", &["1.0"])],
+    ),
+    (
+        "audio",
+        &[
+            ("getVolumeInformation", &["1.0"]),
+            ("setAudioVolume", &["1.0", "1.2"]),
+            ("setAudioMute", &["1.0"]),
+        ],
+    ),
+    (
+        "avContent",
+        &[
+            ("getCurrentExternalInputsStatus", &["1.0", "1.1"]),
+            ("getPlayingContentInfo", &["1.0"]),
+            ("setPlayContent", &["1.0"]),
+        ],
+    ),
+    (
+        "appControl",
+        &[("getApplicationList", &["1.0"]), ("setActiveApp", &["1.0"])],
+    ),
+];
+
+// Error codes returned by the displays.
+const ILLEGAL_ARGUMENT: usize = 3;
+const ILLEGAL_STATE: usize = 7;
+const NO_SUCH_METHOD: usize = 12;
+const UNSUPPORTED_VERSION: usize = 14;
+const DISPLAY_OFF: usize = 40005;
+
+/// State of the simulated display.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DeviceState {
+    /// Whether the display is turned on.
+    pub power: bool,
+    /// Volume of the speakers.
+    pub volume: usize,
+    /// Max volume level.
+    pub max_volume: usize,
+    /// Whether the sound is muted.
+    pub mute: bool,
+    /// External inputs of the display.
+    pub inputs: Vec<ExternalInputStatus>,
+    /// URI of the input shown, `None` when an application is shown.
+    pub input: Option<String>,
+    /// Installed applications.
+    pub applications: Vec<Application>,
+    /// URI of the application shown, `None` when an input is shown.
+    pub application: Option<String>,
+}
+
+impl Default for DeviceState {
+    /// A display in standby showing the first of its 4 HDMI inputs, with two applications.
+    fn default() -> Self {
+        let inputs = (1..=4)
+            .map(|port| ExternalInputStatus {
+                icon: String::from("meta:hdmi"),
+                connection: true,
+                label: String::new(),
+                title: format!("HDMI {port}"),
+                uri: format!("extInput:hdmi?port={port}"),
+                status: Some(true),
+            })
+            .collect::<Vec<_>>();
+        let applications = [("YouTube", "youtube"), ("Netflix", "netflix")]
+            .into_iter()
+            .map(|(title, name)| Application {
+                title: title.to_string(),
+                uri: format!("com.sony.dtv.{name}"),
+                icon: String::new(),
+            })
+            .collect();
+        Self {
+            power: false,
+            volume: 20,
+            max_volume: 100,
+            mute: false,
+            input: inputs.first().map(|input| input.uri.clone()),
+            inputs,
+            applications,
+            application: None,
+        }
+    }
+}
+
+/// Simulated display listening on a local port, stopped when dropped.
+pub struct Simulator {
+    address: SocketAddr,
+    state: Arc<Mutex<DeviceState>>,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl Simulator {
+    /// Starts a display with the [default](DeviceState::default) state.
+    pub async fn start() -> Result<Self> {
+        Self::with_state(DeviceState::default()).await
+    }
+
+    /// Starts a display with the given state.
+    pub async fn with_state(state: DeviceState) -> Result<Self> {
+        let listener = TcpListener::bind(("127.0.0.1", 0))?;
+        listener.set_nonblocking(true)?;
+        let address = listener.local_addr()?;
+        let state = Arc::new(Mutex::new(state));
+
+        let service_state = Arc::clone(&state);
+        let make_service = make_service_fn(move |_| {
+            let state = Arc::clone(&service_state);
+            async move { Ok::<_, Infallible>(service_fn(move |req| serve(Arc::clone(&state), req))) }
+        });
+        let (shutdown, stopped) = oneshot::channel::<()>();
+        let server = Server::from_tcp(listener)
+            .map_err(io::Error::other)?
+            .serve(make_service)
+            .with_graceful_shutdown(async {
+                stopped.await.ok();
+            });
+        tokio::spawn(server);
+
+        Ok(Self {
+            address,
+            state,
+            shutdown: Some(shutdown),
+        })
+    }
+
+    /// Address of the display.
+    pub fn uri(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// Creates a client connected to the display.
+    ///
+    /// # Arguments
+    /// * `auth` - Password sent by the client, the display accepts any value.
+    pub async fn client(&self, auth: Option<&str>) -> Result<Bravia> {
+        Bravia::new(&self.uri(), auth).await
+    }
+
+    /// Current state of the display.
+    pub fn state(&self) -> DeviceState {
+        lock(&self.state).clone()
+    }
+
+    /// Changes the state of the display, like a user with the remote control would.
+    pub fn update_state(&self, update: impl FnOnce(&mut DeviceState)) {
+        update(&mut lock(&self.state));
+    }
+}
+
+impl Drop for Simulator {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            // The server may be already stopped
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+fn lock(state: &Mutex<DeviceState>) -> MutexGuard<'_, DeviceState> {
+    state.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+// Answers an HTTP request, the API calls are answered with the status `200` like the displays do.
+async fn serve(
+    state: Arc<Mutex<DeviceState>>,
+    req: Request<Body>,
+) -> std::result::Result<Response<Body>, hyper::Error> {
+    let service = match (req.method(), req.uri().path().strip_prefix("/sony/")) {
+        (&Method::POST, Some(service)) => service.to_string(),
+        _ => return Ok(status_response(StatusCode::NOT_FOUND)),
+    };
+    let body = to_bytes(req.into_body()).await?;
+    let Ok(body) = serde_json::from_slice::<Value>(&body) else {
+        return Ok(status_response(StatusCode::BAD_REQUEST));
+    };
+
+    let id = body.get("id").cloned().unwrap_or(Value::Null);
+    let response = match call(&mut lock(&state), &service, &body) {
+        Ok(result) => json!({ "result": result, "id": id }),
+        Err((code, message)) => json!({ "error": [code, message], "id": id }),
+    };
+    Ok(Response::builder()
+        .header("Content-Type", "application/json")
+        .body(Body::from(response.to_string()))
+        .unwrap_or_default())
+}
+
+fn status_response(status: StatusCode) -> Response<Body> {
+    let mut response = Response::new(Body::empty());
+    *response.status_mut() = status;
+    response
+}
+
+// Runs an API call, returning the result or the error code and message.
+fn call(
+    state: &mut DeviceState,
+    service: &str,
+    body: &Value,
+) -> std::result::Result<Value, (usize, &'static str)> {
+    let method = body["method"].as_str().unwrap_or_default();
+    let version = body["version"].as_str().unwrap_or_default();
+    let params = &body["params"][0];
+
+    if (service, method) == ("guide", "getSupportedApiInfo") {
+        return Ok(json!([supported_api_info()]));
+    }
+    let versions = SUPPORTED_APIS
+        .iter()
+        .filter(|(name, _)| *name == service)
+        .flat_map(|(_, apis)| apis.iter())
+        .find(|(name, _)| *name == method)
+        .map(|(_, versions)| *versions);
+    match versions {
+        Some(versions) if versions.contains(&version) => {}
+        Some(_) => return Err((UNSUPPORTED_VERSION, "Unsupported Version")),
+        None => return Err((NO_SUCH_METHOD, "No Such Method")),
+    }
+    if !state.power && !matches!(method, "getPowerStatus" | "setPowerStatus") {
+        return Err((DISPLAY_OFF, "Display Is Turned off"));
+    }
+    let illegal_argument = (ILLEGAL_ARGUMENT, "Illegal Argument");
+
+    match method {
+        "getPowerStatus" => {
+            let status = if state.power { "active" } else { "standby" };
+            Ok(json!([{ "status": status }]))
+        }
+        "setPowerStatus" => {
+            state.power = params["status"].as_bool().ok_or(illegal_argument)?;
+            Ok(json!([]))
+        }
+        "getVolumeInformation" => Ok(json!([[{
+            "target": "speaker",
+            "volume": state.volume,
+            "mute": state.mute,
+            "maxVolume": state.max_volume,
+            "minVolume": 0,
+        }]])),
+        "setAudioVolume" => {
+            if !matches!(params["target"].as_str(), None | Some("" | "speaker")) {
+                return Err(illegal_argument);
+            }
+            let volume = params["volume"].as_str().ok_or(illegal_argument)?;
+            let level = match volume.strip_prefix('+') {
+                Some(step) => step.parse().map(|step: usize| state.volume + step),
+                None => match volume.strip_prefix('-') {
+                    Some(step) => step
+                        .parse()
+                        .map(|step: usize| state.volume.saturating_sub(step)),
+                    None => volume.parse(),
+                },
+            };
+            state.volume = level.map_err(|_| illegal_argument)?.min(state.max_volume);
+            Ok(json!([]))
+        }
+        "setAudioMute" => {
+            state.mute = params["status"].as_bool().ok_or(illegal_argument)?;
+            Ok(json!([0]))
+        }
+        "getCurrentExternalInputsStatus" => {
+            let mut inputs = serde_json::to_value(&state.inputs).unwrap_or_default();
+            if version == "1.0" {
+                inputs
+                    .as_array_mut()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(Value::as_object_mut)
+                    .for_each(|input| {
+                        input.remove("status");
+                    });
+            }
+            Ok(json!([inputs]))
+        }
+        "getPlayingContentInfo" => {
+            let input = state
+                .input
+                .as_ref()
+                .and_then(|uri| state.inputs.iter().find(|input| input.uri == *uri))
+                .ok_or((ILLEGAL_STATE, "Illegal State"))?;
+            let source = input.uri.split('?').next().unwrap_or_default();
+            Ok(json!([{ "source": source, "title": input.title, "uri": input.uri }]))
+        }
+        "setPlayContent" => {
+            let uri = params["uri"].as_str().ok_or(illegal_argument)?;
+            if !state.inputs.iter().any(|input| input.uri == uri) {
+                return Err(illegal_argument);
+            }
+            state.input = Some(uri.to_string());
+            state.application = None;
+            Ok(json!([]))
+        }
+        "getApplicationList" => Ok(json!([state.applications])),
+        "setActiveApp" => {
+            let uri = params["uri"].as_str().ok_or(illegal_argument)?;
+            if !state.applications.iter().any(|app| app.uri == uri) {
+                return Err(illegal_argument);
+            }
+            state.application = Some(uri.to_string());
+            state.input = None;
+            Ok(json!([]))
+        }
+        _ => Err((NO_SUCH_METHOD, "No Such Method")),
+    }
+}
+
+// Result of getSupportedApiInfo for the simulated APIs.
+fn supported_api_info() -> Value {
+    let services: Vec<Value> = SUPPORTED_APIS
+        .iter()
+        .map(|(service, apis)| {
+            let apis: Vec<Value> = apis
+                .iter()
+                .map(|(name, versions)| {
+                    let versions: Vec<Value> = versions
+                        .iter()
+                        .map(|version| json!({ "version": version }))
+                        .collect();
+                    json!({ "name": name, "versions": versions })
+                })
+                .collect();
+            json!({ "service": service, "protocols": ["xhrpost:jsonizer"], "apis": apis })
+        })
+        .collect();
+    Value::from(services)
+}
@@ -0,0 +1,64 @@
+//! Combined status of a display, fetched with concurrent requests.
+
+use crate::{
+    audio::VolumeInformation,
+    av_content::{ExternalInputStatus, PlayingContentInfo},
+    error::{Error, Result},
+    Bravia,
+};
+
+/// Status of a display returned by [snapshot](Bravia::snapshot).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DeviceSnapshot {
+    /// Current power status, like `active` or `standby`.
+    pub power: String,
+    /// Volume of each output.
+    pub volume: Vec<VolumeInformation>,
+    /// Content currently shown.\
+    /// `None` if the server reports that nothing is playing, like when an application is open.
+    pub playing_content: Option<PlayingContentInfo>,
+    /// Status of the external inputs.
+    pub external_inputs: Vec<ExternalInputStatus>,
+}
+
+impl Bravia {
+    /// Fetches the power status, the volume, the playing content and the external inputs status
+    /// at the same time.
+    ///
+    /// # Authentication Level
+    /// Private
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// let snapshot = bravia.snapshot().await?;
+    /// println!("{} {:?}", snapshot.power, snapshot.playing_content);
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn snapshot(&self) -> Result<DeviceSnapshot> {
+        let (system, audio, av_content) = (self.system(), self.audio(), self.av_content());
+        let (power, volume, playing_content, external_inputs) = tokio::join!(
+            system.get_power_status(),
+            audio.get_volume_information(),
+            av_content.get_playing_content_info(),
+            av_content.get_current_external_input_status(None),
+        );
+
+        let playing_content = match playing_content {
+            Ok(content) => Some(content),
+            // The server returns an error when there is no content to describe
+            Err(Error::BraviaError(..)) => None,
+            Err(err) => return Err(err),
+        };
+        Ok(DeviceSnapshot {
+            power: power?,
+            volume: volume?,
+            playing_content,
+            external_inputs: external_inputs?,
+        })
+    }
+}
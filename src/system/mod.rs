@@ -1,8 +1,19 @@
 //! APIs that are related to basic device functions.
 
-use crate::{error::Result, Bravia, RequestBodyBuilder, RequestBuilder};
-use serde::{Deserialize, Serialize};
+use crate::{
+    error::{Error, Result},
+    lenient,
+    settings::{Setting, SettingValue, SettingsService},
+    Bravia, RequestBodyBuilder, RequestBuilder, ServiceContext,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::{Map, Value};
+use std::{
+    fmt,
+    net::IpAddr,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 const ENDPOINT: &str = "system";
 
@@ -13,12 +24,143 @@ pub struct Time {
     pub date_time: String,
     /// Timezone offset (unit: minute, range: ±(23*60+59)).\
     /// Not available with API version 1.0.
-    pub time_zone_offset_minute: Option<usize>,
+    #[serde(default, deserialize_with = "lenient::optional_number")]
+    pub time_zone_offset_minute: Option<i32>,
     /// DST offset (unit: minute, range: ±(23*60+59)).\
     /// Not available with API version 1.0.
-    pub dst_offset_minute: Option<usize>,
+    #[serde(default, deserialize_with = "lenient::optional_number")]
+    pub dst_offset_minute: Option<i32>,
 }
 
+impl Time {
+    /// Offset of the local time from UTC, including the DST offset (unit: minute).\
+    /// Not available with API version 1.0.
+    pub fn utc_offset_minute(&self) -> Option<i32> {
+        Some(self.time_zone_offset_minute? + self.dst_offset_minute?)
+    }
+
+    /// Whether DST is currently applied.\
+    /// Not available with API version 1.0.
+    pub fn is_dst(&self) -> Option<bool> {
+        Some(self.dst_offset_minute? != 0)
+    }
+
+    /// Current time set in the device as a UTC instant, and the offset of its local time
+    /// from UTC (unit: minute), if the time is valid.\
+    /// The instant is not shifted by the offset, add it to get the local wall-clock time.\
+    /// The offset is [utc_offset_minute](Self::utc_offset_minute),
+    /// or the one of [date_time](Self::date_time) with API version 1.0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bravia_api::system::Time;
+    /// # use std::time::{Duration, UNIX_EPOCH};
+    /// let time = Time {
+    ///     date_time: String::from("2018-10-03T13:03:04+0100"),
+    ///     time_zone_offset_minute: Some(60),
+    ///     dst_offset_minute: Some(0),
+    /// };
+    /// let (utc, offset) = time.utc_and_offset().unwrap();
+    /// assert_eq!(UNIX_EPOCH + Duration::from_secs(1538568184), utc);
+    /// assert_eq!(60, offset);
+    /// ```
+    pub fn utc_and_offset(&self) -> Option<(SystemTime, i32)> {
+        let (seconds, offset) = parse_date_time(&self.date_time)?;
+        let time = match u64::try_from(seconds) {
+            Ok(seconds) => UNIX_EPOCH.checked_add(Duration::from_secs(seconds))?,
+            Err(_) => UNIX_EPOCH.checked_sub(Duration::from_secs(seconds.unsigned_abs()))?,
+        };
+        Some((time, self.utc_offset_minute().unwrap_or(offset)))
+    }
+
+    /// Current time set in the device, if valid.\
+    /// Requires the `chrono` feature.
+    #[cfg(feature = "chrono")]
+    pub fn to_date_time(&self) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+        chrono::DateTime::parse_from_str(&self.date_time, "%Y-%m-%dT%H:%M:%S%z").ok()
+    }
+
+    /// Timezone offset, not available with API version 1.0.\
+    /// Requires the `chrono` feature.
+    #[cfg(feature = "chrono")]
+    pub fn time_zone_offset(&self) -> Option<chrono::Duration> {
+        Some(chrono::Duration::minutes(
+            self.time_zone_offset_minute?.into(),
+        ))
+    }
+
+    /// DST offset, not available with API version 1.0.\
+    /// Requires the `chrono` feature.
+    #[cfg(feature = "chrono")]
+    pub fn dst_offset(&self) -> Option<chrono::Duration> {
+        Some(chrono::Duration::minutes(self.dst_offset_minute?.into()))
+    }
+}
+
+// Parses a time like `2018-10-03T13:03:04+0100`, returning the seconds since
+// the Unix epoch and the offset from UTC (unit: minute).
+fn parse_date_time(date_time: &str) -> Option<(i64, i32)> {
+    let (date, time) = date_time.split_once('T')?;
+    let mut date = date.splitn(3, '-').map(str::parse::<i64>);
+    let (year, month, day) = (date.next()?.ok()?, date.next()?.ok()?, date.next()?.ok()?);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    let (clock, zone) = time.split_at(time.find(['+', '-', 'Z'])?);
+    // The fractions of a second are ignored
+    let clock = clock.split('.').next()?;
+    let mut clock = clock.splitn(3, ':').map(str::parse::<i64>);
+    let (hour, minute, second) = (
+        clock.next()?.ok()?,
+        clock.next()?.ok()?,
+        clock.next()?.ok()?,
+    );
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    let offset = match zone {
+        "Z" => 0,
+        _ => {
+            let digits = zone[1..].replace(':', "");
+            if digits.len() != 4 || !digits.bytes().all(|x| x.is_ascii_digit()) {
+                return None;
+            }
+            let offset = digits[..2].parse::<i32>().ok()? * 60 + digits[2..].parse::<i32>().ok()?;
+            if zone.starts_with('-') {
+                -offset
+            } else {
+                offset
+            }
+        }
+    };
+
+    let seconds = days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second
+        - i64::from(offset) * 60;
+    Some((seconds, offset))
+}
+
+// Days between 1970-01-01 and a date of the Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Target of the generic settings with the power status after the power supply is restored,
+/// its values are `standby`, `on` or `lastState`.\
+/// Only available on the professional displays.
+pub const POWER_ON_STATUS_TARGET: &str = "powerOnStatus";
+
+/// Target of the generic settings with the input or application shown when the device
+/// is turned on, as a URI like `extInput:hdmi?port=1`.\
+/// Only available on the professional displays.
+pub const BOOTUP_TARGET: &str = "bootup";
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct InterfaceInfo {
@@ -35,53 +177,254 @@ pub struct InterfaceInfo {
     /// The composition of this parameter is `[X].[Y].[Z]`,
     /// where `[X]`, `[Y]`, and `[Z]` are strings each representing an integer and concatenated with periods "." in between.
     /// * `[X]`: This value is assigned and incremented so that the client can distinguish any significant differences
-    /// between devices or groups of devices within product_category.
-    /// How this value is assigned depends on each product_category.
+    ///   between devices or groups of devices within product_category.
+    ///   How this value is assigned depends on each product_category.
     /// * `[Y]`: This value represents the versions of API sets supported within `[X]`.
-    /// This version must be incremented if supported APIs are added or deleted.
+    ///   This version must be incremented if supported APIs are added or deleted.
     /// * `[Z]`: This value must be incremented if any behavior of existing APIs is changed within `[X.Y]`.
     pub interface_version: String,
 }
 
+/// Functional meaning of the LED indicator.\
+/// Modes unknown to this crate are kept as [Unknown](Self::Unknown).
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum LedMode {
+    Demo,
+    AutoBrightnessAdjust,
+    Dark,
+    SimpleResponse,
+    Off,
+    /// Mode returned by the server that doesn't match the other variants.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct LEDIndicatorStatus {
     /// Functional meaning of the target LED.
-    /// * `Demo`
-    /// * `AutoBrightnessAdjust`
-    /// * `Dark`
-    /// * `SimpleResponse`
-    /// * `Off`
-    pub mode: String,
+    pub mode: LedMode,
     /// LED Indicator status.
     /// * `true` - On
     /// * `false` - Off
     /// * `None`
     ///     * input: the server decides the behavior
     ///     * output: unknown
-    pub status: Option<String>,
+    #[serde(default, deserialize_with = "lenient::flag")]
+    pub status: Option<bool>,
 }
 
 impl LEDIndicatorStatus {
-    pub fn new(mode: String, status: Option<String>) -> Self {
+    pub fn new(mode: LedMode, status: Option<bool>) -> Self {
         Self { mode, status }
     }
 }
 
+/// Power saving mode, used to adjust the device's power consumption.\
+/// Modes unknown to this crate are kept as [Unknown](Self::Unknown).
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum PowerSavingMode {
+    /// Power saving mode is disabled.
+    Off,
+    /// Power saving mode is enabled at a low level.
+    Low,
+    /// Power saving mode is enabled at a high level.
+    High,
+    /// Power saving mode is enabled with the panel output off.
+    PictureOff,
+    /// Mode returned by the server that doesn't match the other variants.
+    Unknown(String),
+}
+
+impl PowerSavingMode {
+    /// Name of the mode used by the server, like `pictureOff`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Off => "off",
+            Self::Low => "low",
+            Self::High => "high",
+            Self::PictureOff => "pictureOff",
+            Self::Unknown(mode) => mode,
+        }
+    }
+}
+
+impl From<String> for PowerSavingMode {
+    fn from(mode: String) -> Self {
+        match mode.as_str() {
+            "off" => Self::Off,
+            "low" => Self::Low,
+            "high" => Self::High,
+            "pictureOff" => Self::PictureOff,
+            _ => Self::Unknown(mode),
+        }
+    }
+}
+
+impl From<PowerSavingMode> for String {
+    fn from(mode: PowerSavingMode) -> Self {
+        match mode {
+            PowerSavingMode::Unknown(mode) => mode,
+            mode => mode.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for PowerSavingMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Power state of the device, as returned by [getPowerStatus](SystemService::get_power_status).
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum PowerState {
+    /// Device is in the power on state.
+    Active,
+    /// Device is in the power off state.
+    Standby,
+}
+
+impl PowerState {
+    /// Status used by the server, like `active`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Standby => "standby",
+        }
+    }
+}
+
+impl fmt::Display for PowerState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PowerStatus {
+    /// Current power status.
+    /// * `standby` - Device is in the power off state.
+    /// * `active` - Device is in the power on state.
+    pub status: String,
+    /// Detail of the standby state, the values depend on the device.\
+    /// Not available with API version 1.0.
+    pub standby_detail: Option<String>,
+}
+
+/// Value returned by the server as a string, kept as it is when it can't be parsed.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Parsed<T> {
+    /// The string was parsed successfully.
+    Value(T),
+    /// The string couldn't be parsed, like an empty string or an IPv6 address with a zone.
+    Raw(String),
+}
+
+impl<T> Parsed<T> {
+    /// Returns the parsed value, if any.
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            Self::Value(value) => Some(value),
+            Self::Raw(_) => None,
+        }
+    }
+}
+
+impl<T> Default for Parsed<T> {
+    fn default() -> Self {
+        Self::Raw(String::new())
+    }
+}
+
+impl<T: FromStr> From<String> for Parsed<T> {
+    fn from(value: String) -> Self {
+        value.parse().map_or(Self::Raw(value), Self::Value)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Parsed<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Value(value) => value.fmt(f),
+            Self::Raw(value) => f.write_str(value),
+        }
+    }
+}
+
+impl<T: fmt::Display> Serialize for Parsed<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de, T: FromStr> Deserialize<'de> for Parsed<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        Ok(String::deserialize(deserializer)?.into())
+    }
+}
+
+/// MAC address, parsed from six hex octets separated by `:` or `-`.
+///
+/// # Examples
+/// ```
+/// # use bravia_api::system::MacAddr;
+/// let mac: MacAddr = "fc-f1-52-00-11-22".parse().unwrap();
+/// assert_eq!(mac.to_string(), "FC:F1:52:00:11:22");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct MacAddr(pub [u8; 6]);
+
+impl MacAddr {
+    pub fn octets(&self) -> [u8; 6] {
+        self.0
+    }
+}
+
+impl FromStr for MacAddr {
+    type Err = Error;
+
+    fn from_str(mac: &str) -> Result<Self> {
+        let invalid = || Error::InvalidParameter(format!("invalid MAC address {mac:?}"));
+        let mut octets = [0; 6];
+        let mut parts = mac.split([':', '-']);
+        for octet in &mut octets {
+            let part = parts.next().ok_or_else(invalid)?;
+            if part.len() != 2 {
+                return Err(invalid());
+            }
+            *octet = u8::from_str_radix(part, 16).map_err(|_| invalid())?;
+        }
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+        Ok(Self(octets))
+    }
+}
+
+impl fmt::Display for MacAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b, c, d, e, g] = self.0;
+        write!(f, "{a:02X}:{b:02X}:{c:02X}:{d:02X}:{e:02X}:{g:02X}")
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NetworkSettings {
     /// Network Interface.
     pub netif: String,
     /// Hardware address (MAC Address).
-    pub hw_addr: String,
+    pub hw_addr: Parsed<MacAddr>,
     /// IP Address for IPV4.
-    pub ip_addr_v4: String,
+    pub ip_addr_v4: Parsed<IpAddr>,
     /// IP Address for IPV6.
-    pub ip_addr_v6: String,
+    pub ip_addr_v6: Parsed<IpAddr>,
     /// Netmask.
-    pub netmask: String,
+    pub netmask: Parsed<IpAddr>,
     /// Gateway.
-    pub gateway: String,
+    pub gateway: Parsed<IpAddr>,
     /// DNS.
     pub dns: Vec<String>,
 }
@@ -107,7 +450,74 @@ pub struct RemoteDeviceSettings {
     pub current_value: String,
 }
 
+/// Language of the device, used by [set_language](SystemService::set_language).
+///
+/// # Examples
+/// ```
+/// # use bravia_api::system::Language;
+/// let lang: Language = "CHT".parse().unwrap();
+/// assert_eq!(lang, Language::TraditionalChinese);
+/// assert!("english".parse::<Language>().is_err());
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Language {
+    /// Language code represented by ISO-639 alpha-3, like `eng`.\
+    /// Use [parse](str::parse) to validate the code.
+    Iso639(String),
+    /// Simplified Chinese, `CHS`.
+    SimplifiedChinese,
+    /// Traditional Chinese, `CHT`.
+    TraditionalChinese,
+    /// Code sent as it is, for the region-specific values.
+    Other(String),
+}
+
+impl Language {
+    /// Code used by the server, like `eng` or `CHS`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Iso639(code) | Self::Other(code) => code,
+            Self::SimplifiedChinese => "CHS",
+            Self::TraditionalChinese => "CHT",
+        }
+    }
+}
+
+impl FromStr for Language {
+    type Err = Error;
+
+    fn from_str(lang: &str) -> Result<Self> {
+        match lang {
+            "CHS" => Ok(Self::SimplifiedChinese),
+            "CHT" => Ok(Self::TraditionalChinese),
+            _ if lang.len() == 3 && lang.bytes().all(|b| b.is_ascii_lowercase()) => {
+                Ok(Self::Iso639(lang.to_string()))
+            }
+            _ => Err(Error::InvalidParameter(format!(
+                "invalid language code {:?}",
+                lang
+            ))),
+        }
+    }
+}
+
+impl From<Language> for String {
+    fn from(lang: Language) -> Self {
+        match lang {
+            Language::Iso639(code) | Language::Other(code) => code,
+            lang => lang.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct SystemInformation {
     /// Device category.
     pub product: String,
@@ -128,7 +538,7 @@ pub struct SystemInformation {
     /// Default value is `""` and in case server device can not send this parameter,
     /// empty string is returned.
     #[serde(default)]
-    pub mac_addr: String,
+    pub mac_addr: Parsed<MacAddr>,
     /// Product name.\
     /// This must be unique within each category.
     pub name: String,
@@ -155,11 +565,63 @@ pub struct SupportedFunction {
 }
 
 /// Provides access to system service APIs.
-pub struct SystemService<'a>(&'a Bravia);
+pub struct SystemService(ServiceContext);
+
+impl_service_options!(SystemService);
+
+impl SystemService {
+    pub fn new(bravia: &Bravia) -> Self {
+        Self(ServiceContext::new(bravia))
+    }
+
+    // Generic settings of this service, with the same options.
+    fn settings(&self) -> SettingsService {
+        SettingsService::new(&self.0.bravia, ENDPOINT).with_options(self.0.options.clone())
+    }
 
-impl<'a> SystemService<'a> {
-    pub fn new(bravia: &'a Bravia) -> Self {
-        Self(bravia)
+    /// Provides the settings applied when the device is turned on,
+    /// like the power status or the input to select.\
+    /// Only available on the professional displays.
+    ///
+    /// # Arguments
+    /// `target`
+    /// * `None` - This indicates [POWER_ON_STATUS_TARGET] and [BOOTUP_TARGET].
+    ///
+    /// # Authentication Level
+    /// Generic
+    ///
+    /// # Note
+    /// These are generic settings, see [SettingsService].
+    pub async fn get_bootup_settings(&self, target: Option<&str>) -> Result<Vec<Setting>> {
+        let settings = self.settings().get_settings(target).await?;
+        Ok(match target {
+            Some(_) => settings,
+            None => settings
+                .into_iter()
+                .filter(|x| [POWER_ON_STATUS_TARGET, BOOTUP_TARGET].contains(&x.target.as_str()))
+                .collect(),
+        })
+    }
+
+    /// Same as [get_current_time](Self::get_current_time), parsing the current time.\
+    /// Requires the `chrono` feature.
+    ///
+    /// # Arguments
+    /// * `version` - API version.
+    ///
+    /// # Authentication Level
+    /// None
+    #[cfg(feature = "chrono")]
+    pub async fn get_current_date_time(
+        &self,
+        version: Option<&str>,
+    ) -> Result<chrono::DateTime<chrono::FixedOffset>> {
+        self.get_current_time(version)
+            .await?
+            .to_date_time()
+            .ok_or(Error::InvalidResponse(
+                "dateTime is not a valid ISO8601 time",
+            ))
     }
 
     /// Provides the current time, parameters of timezone and DST offset information.
@@ -175,15 +637,11 @@ impl<'a> SystemService<'a> {
             .method("getCurrentTime")
             .version(version)
             .build()?;
-        let req = RequestBuilder::default()
-            .endpoint(ENDPOINT)
-            .body(body)
-            .has_result()
-            .make(self.0)
-            .await?;
+        let mut req = RequestBuilder::default();
+        req.endpoint(ENDPOINT).body(body).has_result();
 
         if version.is_none() || version == Some("1.0") {
-            let date_time: String = serde_json::from_value(req)?;
+            let date_time: String = req.make_as(&self.0).await?;
             let time = Time {
                 date_time,
                 time_zone_offset_minute: None,
@@ -191,7 +649,7 @@ impl<'a> SystemService<'a> {
             };
             Ok(time)
         } else {
-            Ok(serde_json::from_value(req)?)
+            req.make_as(&self.0).await
         }
     }
 
@@ -204,13 +662,12 @@ impl<'a> SystemService<'a> {
             .id(33)
             .method("getInterfaceInformation")
             .build()?;
-        let req = RequestBuilder::default()
+        RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .has_result()
-            .make(self.0)
-            .await?;
-        Ok(serde_json::from_value(req)?)
+            .make_as(&self.0)
+            .await
     }
 
     /// Provides function to get the LED Indicator mode.
@@ -222,28 +679,24 @@ impl<'a> SystemService<'a> {
             .id(45)
             .method("getLEDIndicatorStatus")
             .build()?;
-        let req = RequestBuilder::default()
+        RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
             .has_result()
-            .make(self.0)
-            .await?;
-        Ok(serde_json::from_value(req)?)
+            .make_as(&self.0)
+            .await
     }
 
     /// Provides information about network settings.
     ///
     /// # Arguments
     /// * `netif` - Network interface.\
-    /// The default value is `None`, this indicates all interfaces.
+    ///   The default value is `None`, this indicates all interfaces.
     ///
     /// # Authentication Level
     /// Generic
-    pub async fn get_network_settings(
-        &self,
-        netif: Option<String>,
-    ) -> Result<Vec<NetworkSettings>> {
+    pub async fn get_network_settings(&self, netif: Option<&str>) -> Result<Vec<NetworkSettings>> {
         let mut params = Map::new();
         if let Some(netif) = netif {
             params.insert(String::from("netif"), Value::from(netif));
@@ -254,40 +707,51 @@ impl<'a> SystemService<'a> {
             .method("getNetworkSettings")
             .params(Value::from(params))
             .build()?;
-        let req = RequestBuilder::default()
+        RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
             .has_result()
-            .make(self.0)
-            .await?;
-        Ok(serde_json::from_value(req)?)
+            .make_as(&self.0)
+            .await
     }
 
-    /// Provides the setting of the power saving mode to adjust the device's power consumption.
+    /// Provides the postal code of the area where the device is located.\
+    /// Only available on some models, it is used for regional services like the EPG.
     ///
-    /// # Returns
-    /// Current power saving mode, the following values are defined:
-    /// * `off` - Power saving mode is disabled.
-    /// * `low` - Power saving mode is enabled at a low level.
-    /// * `high` - Power saving mode is enabled at a high level.
-    /// * `pictureOff` - Power saving mode is enabled with the panel output off.
+    /// # Authentication Level
+    /// Generic
+    pub async fn get_postal_code(&self) -> Result<String> {
+        let body = RequestBodyBuilder::default()
+            .id(57)
+            .method("getPostalCode")
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .has_result()
+            .get("postalCode".into())
+            .make_as(&self.0)
+            .await
+    }
+
+    /// Provides the setting of the power saving mode to adjust the device's power consumption.
     ///
     /// # Authentication Level
     /// None
-    pub async fn get_power_saving_mode(&self) -> Result<String> {
+    pub async fn get_power_saving_mode(&self) -> Result<PowerSavingMode> {
         let body = RequestBodyBuilder::default()
             .id(51)
             .method("getPowerSavingMode")
             .build()?;
-        let req = RequestBuilder::default()
+        RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .has_result()
             .get("mode".into())
-            .make(self.0)
-            .await?;
-        Ok(serde_json::from_value(req)?)
+            .make_as(&self.0)
+            .await
     }
 
     /// Provides the current power status of the device.
@@ -307,14 +771,40 @@ impl<'a> SystemService<'a> {
             .id(50)
             .method("getPowerStatus")
             .build()?;
-        let req = RequestBuilder::default()
+        RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .has_result()
             .get("status".into())
-            .make(self.0)
+            .make_as(&self.0)
+            .await
+    }
+
+    /// Provides the current power status of the device, with the details returned by the newer API versions.\
+    /// See [get_power_status](Self::get_power_status) to only read the status.
+    ///
+    /// # Arguments
+    /// * `version` - API version.
+    ///
+    /// # Authentication Level
+    /// None
+    pub async fn get_power_status_detail(&self, version: Option<&str>) -> Result<PowerStatus> {
+        let body = RequestBodyBuilder::default()
+            .id(50)
+            .method("getPowerStatus")
+            .version(version)
+            .build()?;
+        let mut status: PowerStatus = RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .has_result()
+            .make_as(&self.0)
             .await?;
-        Ok(serde_json::from_value(req)?)
+
+        if version.is_none() || version == Some("1.0") {
+            status.standby_detail = None;
+        }
+        Ok(status)
     }
 
     /// Provides the information of the device's remote controller.
@@ -326,14 +816,13 @@ impl<'a> SystemService<'a> {
             .id(54)
             .method("getRemoteControllerInfo")
             .build()?;
-        let req = RequestBuilder::default()
+        RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .has_result()
             .get(1.into())
-            .make(self.0)
-            .await?;
-        Ok(serde_json::from_value(req)?)
+            .make_as(&self.0)
+            .await
     }
 
     /// Provides the current settings and supported settings related to remote devices, which can access the server device from outside the door.
@@ -341,14 +830,14 @@ impl<'a> SystemService<'a> {
     /// # Arguments
     /// * `target` - Target name.
     /// * `accessPermission` - Sets whether to permit access from remote devices,
-    /// which can access the server device from outside the door.
+    ///   which can access the server device from outside the door.
     /// * `None` - Settings of all targets.
     ///
     /// # Authentication Level
     /// None
     pub async fn get_remote_device_settings(
         &self,
-        target: Option<String>,
+        target: Option<&str>,
     ) -> Result<Vec<RemoteDeviceSettings>> {
         let mut params = Map::new();
         if let Some(target) = target {
@@ -360,13 +849,12 @@ impl<'a> SystemService<'a> {
             .method("getRemoteDeviceSettings")
             .params(Value::from(params))
             .build()?;
-        let req = RequestBuilder::default()
+        RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .has_result()
-            .make(self.0)
-            .await?;
-        Ok(serde_json::from_value(req)?)
+            .make_as(&self.0)
+            .await
     }
 
     /// Provides general information on the device.
@@ -378,14 +866,13 @@ impl<'a> SystemService<'a> {
             .id(33)
             .method("getSystemInformation")
             .build()?;
-        let req = RequestBuilder::default()
+        RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
             .has_result()
-            .make(self.0)
-            .await?;
-        Ok(serde_json::from_value(req)?)
+            .make_as(&self.0)
+            .await
     }
 
     /// Provides the list of device capabilities within the scope of system service handling.
@@ -397,13 +884,12 @@ impl<'a> SystemService<'a> {
             .id(55)
             .method("getSystemSupportedFunction")
             .build()?;
-        let req = RequestBuilder::default()
+        RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .has_result()
-            .make(self.0)
-            .await?;
-        Ok(serde_json::from_value(req)?)
+            .make_as(&self.0)
+            .await
     }
 
     /// Provides information on the device's WoL (Wake-on-LAN) mode settings.\
@@ -416,21 +902,23 @@ impl<'a> SystemService<'a> {
             .id(50)
             .method("getWolMode")
             .build()?;
-        let req = RequestBuilder::default()
+        RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
             .has_result()
             .get("enabled".into())
-            .make(self.0)
-            .await?;
-        Ok(serde_json::from_value(req)?)
+            .make_as(&self.0)
+            .await
     }
 
     /// Provides the function to reboot the device.
     ///
     /// # Authentication Level
     /// Generic
+    ///
+    /// # Note
+    /// This request is never retried, regardless of the [RetryPolicy](crate::retry::RetryPolicy).
     pub async fn request_reboot(&self) -> Result<()> {
         let body = RequestBodyBuilder::default()
             .id(10)
@@ -440,14 +928,110 @@ impl<'a> SystemService<'a> {
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
-            .make(self.0)
+            .no_retry()
+            .make(&self.0)
+            .await?;
+        Ok(())
+    }
+
+    /// Provides the function to change the settings applied when the device is turned on,
+    /// with the targets [POWER_ON_STATUS_TARGET] and [BOOTUP_TARGET].\
+    /// Only available on the professional displays.
+    ///
+    /// # Authentication Level
+    /// Generic
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result, settings::SettingValue, system::POWER_ON_STATUS_TARGET};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// let setting = SettingValue::new(POWER_ON_STATUS_TARGET, "lastState");
+    /// bravia.system().set_bootup_settings([setting]).await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn set_bootup_settings(
+        &self,
+        settings: impl IntoIterator<Item = SettingValue>,
+    ) -> Result<()> {
+        self.settings().set_settings(settings).await
+    }
+
+    /// Provides the function to set the current time, timezone and DST offset of the device.\
+    /// Useful for the devices that can't read the time from the network.
+    ///
+    /// # Arguments
+    /// * `date_time` - Current time (ISO8601 format), like `2018-10-03T13:03:04+0100`.
+    /// * `time_zone_offset_minute` - Timezone offset (unit: minute, range: ±(23*60+59)).
+    /// * `dst_offset_minute` - DST offset (unit: minute, range: ±(23*60+59)).
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn set_current_time(
+        &self,
+        date_time: impl Into<String>,
+        time_zone_offset_minute: i32,
+        dst_offset_minute: i32,
+    ) -> Result<()> {
+        let mut params = Map::new();
+        params.insert(String::from("dateTime"), Value::from(date_time.into()));
+        params.insert(
+            String::from("timeZoneOffsetMinute"),
+            Value::from(time_zone_offset_minute),
+        );
+        params.insert(
+            String::from("dstOffsetMinute"),
+            Value::from(dst_offset_minute),
+        );
+        let body = RequestBodyBuilder::default()
+            .id(56)
+            .method("setCurrentTime")
+            .params(Value::from(params))
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .make(&self.0)
             .await?;
         Ok(())
     }
 
+    /// Same as [set_current_time](Self::set_current_time), the timezone offset is the
+    /// offset of `date_time` without the DST offset.\
+    /// Requires the `chrono` feature.
+    ///
+    /// # Arguments
+    /// * `date_time` - Current time, in the timezone of the device.
+    /// * `dst_offset_minute` - DST offset (unit: minute), included in the offset of `date_time`.
+    ///
+    /// # Authentication Level
+    /// Generic
+    #[cfg(feature = "chrono")]
+    pub async fn set_current_date_time(
+        &self,
+        date_time: &chrono::DateTime<chrono::FixedOffset>,
+        dst_offset_minute: i32,
+    ) -> Result<()> {
+        let offset_minute = date_time.offset().local_minus_utc() / 60;
+        self.set_current_time(
+            date_time.format("%Y-%m-%dT%H:%M:%S%z").to_string(),
+            offset_minute - dst_offset_minute,
+            dst_offset_minute,
+        )
+        .await
+    }
+
     /// Provides the function to light up a specific LED Indicator,
     /// usually equipped in the front of the device to show the current device status to the user.
     ///
+    /// # Arguments
+    /// * `led_status` - Mode and status of the LED indicator.\
+    ///   The status is only sent with API version 1.1.
+    /// * `version` - API version.
+    ///
     /// # Authentication Level
     /// Generic
     ///
@@ -455,18 +1039,30 @@ impl<'a> SystemService<'a> {
     /// When requesting to change the LED indicator status with this API,
     /// you should take care not to return it to its original status
     /// when terminating your application.
-    pub async fn set_led_indicator_status(&self, led_status: LEDIndicatorStatus) -> Result<()> {
+    pub async fn set_led_indicator_status(
+        &self,
+        led_status: LEDIndicatorStatus,
+        version: Option<&str>,
+    ) -> Result<()> {
+        let mut params = Map::new();
+        params.insert(String::from("mode"), serde_json::to_value(led_status.mode)?);
+        if version == Some("1.1") {
+            if let Some(status) = led_status.status {
+                params.insert(String::from("status"), Value::from(status.to_string()));
+            }
+        }
+
         let body = RequestBodyBuilder::default()
             .id(53)
             .method("setLEDIndicatorStatus")
-            .version(Some("1.1"))
-            .params(serde_json::to_value(led_status)?)
+            .version(version)
+            .params(Value::from(params))
             .build()?;
         RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
-            .make(self.0)
+            .make(&self.0)
             .await?;
         Ok(())
     }
@@ -474,19 +1070,16 @@ impl<'a> SystemService<'a> {
     /// Provides the language setting of the device.
     ///
     /// # Arguments
-    /// `lang` - Language code represented by ISO-639 alpha-3 to set in the device.
+    /// `lang` - Language to set in the device.
     ///
     /// # Authentication Level
     /// Generic
     ///
     /// # Notes
-    /// * A special value is required for language to distinguish Chinese characters, as seen below:
-    ///     * "CHS": means Simplified Chinese.
-    ///     * "CHT": means Traditional Chinese.
-    /// * The available values of “language” depend on region or country settings on TV.
-    pub async fn set_language(&self, lang: String) -> Result<()> {
+    /// The available values of “language” depend on region or country settings on TV.
+    pub async fn set_language(&self, lang: Language) -> Result<()> {
         let mut params = Map::new();
-        params.insert(String::from("language"), Value::from(lang));
+        params.insert(String::from("language"), Value::from(String::from(lang)));
         let body = RequestBodyBuilder::default()
             .id(55)
             .method("setLanguage")
@@ -496,7 +1089,32 @@ impl<'a> SystemService<'a> {
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
-            .make(self.0)
+            .make(&self.0)
+            .await?;
+        Ok(())
+    }
+
+    /// Provides the function to change the postal code of the area where the device is located.\
+    /// Only available on some models.
+    ///
+    /// # Arguments
+    /// `code` - Postal code to set in the device.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn set_postal_code(&self, code: impl Into<String>) -> Result<()> {
+        let mut params = Map::new();
+        params.insert(String::from("postalCode"), Value::from(code.into()));
+        let body = RequestBodyBuilder::default()
+            .id(58)
+            .method("setPostalCode")
+            .params(Value::from(params))
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .make(&self.0)
             .await?;
         Ok(())
     }
@@ -505,17 +1123,13 @@ impl<'a> SystemService<'a> {
     /// and adjust the device's power consumption.
     ///
     /// # Arguments
-    /// `mode` - Current power saving mode. The following values are defined:
-    /// * `off` - Power saving mode is disabled.
-    /// * `low` - Power saving mode is enabled at a low level.
-    /// * `high` - Power saving mode is enabled at a high level.
-    /// * `pictureOff` - Power saving mode is enabled with the panel output off.
+    /// `mode` - Power saving mode to set.
     ///
     /// # Authentication Level
     /// Generic
-    pub async fn set_power_saving_mode(&self, mode: String) -> Result<()> {
+    pub async fn set_power_saving_mode(&self, mode: PowerSavingMode) -> Result<()> {
         let mut params = Map::new();
-        params.insert(String::from("mode"), Value::from(mode));
+        params.insert(String::from("mode"), Value::from(String::from(mode)));
         let body = RequestBodyBuilder::default()
             .id(52)
             .method("setPowerSavingMode")
@@ -525,7 +1139,7 @@ impl<'a> SystemService<'a> {
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
-            .make(self.0)
+            .make(&self.0)
             .await?;
         Ok(())
     }
@@ -549,7 +1163,7 @@ impl<'a> SystemService<'a> {
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
-            .make(self.0)
+            .make(&self.0)
             .await?;
         Ok(())
     }
@@ -574,8 +1188,49 @@ impl<'a> SystemService<'a> {
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
-            .make(self.0)
+            .make(&self.0)
             .await?;
         Ok(())
     }
+
+    /// Turns the device on with [setPowerStatus](Self::set_power_status),
+    /// then waits until [getPowerStatus](Self::get_power_status) returns `active`.\
+    /// The power status requests use the options of this handle, without retries
+    /// and with their timeout capped by the time left.
+    ///
+    /// # Arguments
+    /// * `timeout` - Maximum time to wait for the device to turn on.
+    ///
+    /// # Authentication Level
+    /// Generic
+    ///
+    /// # Errors
+    /// [VerificationFailed](Error::VerificationFailed) if the device isn't on when `timeout` expires.
+    pub async fn power_on(&self, timeout: Duration) -> Result<()> {
+        self.set_power_status(true).await?;
+        self.0
+            .bravia
+            .poll_power_state(&self.0.options, PowerState::Active, timeout)
+            .await
+    }
+
+    /// Puts the device in standby with [setPowerStatus](Self::set_power_status),
+    /// then waits until [getPowerStatus](Self::get_power_status) returns `standby`.\
+    /// The power status requests are made like in [power_on](Self::power_on).
+    ///
+    /// # Arguments
+    /// * `timeout` - Maximum time to wait for the device to go in standby.
+    ///
+    /// # Authentication Level
+    /// Generic
+    ///
+    /// # Errors
+    /// [VerificationFailed](Error::VerificationFailed) if the device isn't in standby when `timeout` expires.
+    pub async fn power_off(&self, timeout: Duration) -> Result<()> {
+        self.set_power_status(false).await?;
+        self.0
+            .bravia
+            .poll_power_state(&self.0.options, PowerState::Standby, timeout)
+            .await
+    }
 }
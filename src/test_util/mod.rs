@@ -0,0 +1,248 @@
+//! Mock server answering like a display, to test the applications built on this crate
+//! without the hardware. Requires the `test-util` feature.
+//!
+//! # Examples
+//! ```
+//! # use bravia_api::{error::Result, test_util::MockBravia};
+//! # use serde_json::json;
+//! # #[tokio::main]
+//! # async fn main() -> Result<()> {
+//! let mock = MockBravia::start().await;
+//! mock.stub("system", "getPowerStatus", json!([{ "status": "active" }]))
+//!     .await;
+//!
+//! let bravia = mock.client(Some("PASSWORD")).await?;
+//! assert_eq!("active", bravia.system().get_power_status().await?);
+//! #    Ok(())
+//! # }
+//! ```
+
+use crate::{error::Result, APIsMap, ApiSupport, Bravia};
+use serde_json::{json, Value};
+use std::{fs::File, io::BufReader, path::Path};
+use wiremock::{
+    matchers::{body_partial_json, method, path, BodyExactMatcher},
+    Mock, MockServer, ResponseTemplate,
+};
+
+/// Responses of getSupportedApiInfo used by the tests of each service,
+/// together they have all the APIs wrapped by this crate.
+const SAMPLE_API_INFO: [&str; 14] = [
+    include_str!("../../sample_payloads/access_control/supported_api_info.json"),
+    include_str!("../../sample_payloads/app_control/supported_api_info.json"),
+    include_str!("../../sample_payloads/audio/supported_api_info.json"),
+    include_str!("../../sample_payloads/av_content/supported_api_info.json"),
+    include_str!("../../sample_payloads/browser/supported_api_info.json"),
+    include_str!("../../sample_payloads/cec/supported_api_info.json"),
+    include_str!("../../sample_payloads/content_share/supported_api_info.json"),
+    include_str!("../../sample_payloads/encryption/supported_api_info.json"),
+    include_str!("../../sample_payloads/guide/supported_api_info.json"),
+    include_str!("../../sample_payloads/recording/supported_api_info.json"),
+    include_str!("../../sample_payloads/settings/supported_api_info.json"),
+    include_str!("../../sample_payloads/system/supported_api_info.json"),
+    include_str!("../../sample_payloads/video/supported_api_info.json"),
+    include_str!("../../sample_payloads/video_screen/supported_api_info.json"),
+];
+
+/// Server that answers [getSupportedApiInfo](crate::guide::GuideService::get_supported_api_info)
+/// and the API calls stubbed with [stub](Self::stub) and [stub_error](Self::stub_error).\
+/// The other requests are answered with the HTTP status `404`.
+pub struct MockBravia {
+    server: MockServer,
+    // Response body of getSupportedApiInfo.
+    api_support: Value,
+}
+
+impl MockBravia {
+    /// Starts a server that supports all the APIs wrapped by this crate.
+    pub async fn start() -> Self {
+        let mut services: Vec<Value> = Vec::new();
+        for api_info in SAMPLE_API_INFO {
+            // The embedded files are checked by the tests of each service
+            let api_info: Value = serde_json::from_str(api_info).unwrap_or_default();
+            let found = api_info["result"][0]
+                .as_array()
+                .cloned()
+                .unwrap_or_default();
+            for service in found {
+                match services
+                    .iter_mut()
+                    .find(|x| x["service"] == service["service"])
+                {
+                    Some(merged) => merge_apis(merged, &service),
+                    None => services.push(service),
+                }
+            }
+        }
+        Self::with_api_info(json!({ "result": [services], "id": 1 })).await
+    }
+
+    /// Starts a server that supports only the given APIs and notifications.
+    pub async fn with_api_support(api_support: ApiSupport) -> Self {
+        let services: Vec<Value> = api_support
+            .apis
+            .iter()
+            .map(|(service, apis)| {
+                let mut data = json!({
+                    "service": service,
+                    "protocols": ["xhrpost:jsonizer"],
+                    "apis": describe(apis),
+                });
+                if let Some(notifications) = api_support.notifications.get(service) {
+                    data["notifications"] = describe(notifications).into();
+                }
+                data
+            })
+            .collect();
+
+        Self::with_api_info(json!({ "result": [services], "id": 1 })).await
+    }
+
+    /// Starts a server that answers getSupportedApiInfo with the content of a file,
+    /// like a response captured from a display.
+    ///
+    /// # Panics
+    /// If the file can't be read or isn't JSON.
+    pub async fn with_api_info_file(json_path: impl AsRef<Path>) -> Self {
+        Self::with_api_info(read_json(json_path.as_ref())).await
+    }
+
+    async fn with_api_info(api_support: Value) -> Self {
+        let mock = Self {
+            server: MockServer::start().await,
+            api_support,
+        };
+        mock.mount_api_support().await;
+        mock
+    }
+
+    /// Address of the server.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// Creates a client connected to the server.
+    ///
+    /// # Arguments
+    /// * `auth` - Password sent by the client, the server accepts any value.
+    pub async fn client(&self, auth: Option<&str>) -> Result<Bravia> {
+        Bravia::new(&self.uri(), auth).await
+    }
+
+    /// Answers the calls to an API with the given `result`.\
+    /// When more stubs match a call the first one added is used, see [reset](Self::reset).
+    ///
+    /// # Arguments
+    /// * `service` - Name of the service (e.g. `avContent`).
+    /// * `api` - Name of the API (e.g. `getPlayingContentInfo`).
+    /// * `result` - Value of the `result` field of the response, usually an array.
+    pub async fn stub(&self, service: &str, api: &str, result: Value) {
+        self.respond(service, api, json!({ "result": result, "id": 1 }))
+            .await;
+    }
+
+    /// Answers the calls to an API with an error, like a display would.
+    ///
+    /// # Arguments
+    /// * `service` - Name of the service (e.g. `avContent`).
+    /// * `api` - Name of the API (e.g. `getPlayingContentInfo`).
+    /// * `code` - Error code, like `40005` when the display is turned off.
+    /// * `message` - Error message.
+    pub async fn stub_error(&self, service: &str, api: &str, code: usize, message: &str) {
+        self.respond(service, api, json!({ "error": [code, message], "id": 1 }))
+            .await;
+    }
+
+    /// Removes all the stubs, the supported APIs are kept.
+    pub async fn reset(&self) {
+        self.server.reset().await;
+        self.mount_api_support().await;
+    }
+
+    /// Underlying server, to add custom [Mock]s or to inspect the received requests.
+    pub fn server(&self) -> &MockServer {
+        &self.server
+    }
+
+    /// Takes the underlying server, that keeps answering getSupportedApiInfo.
+    pub fn into_server(self) -> MockServer {
+        self.server
+    }
+
+    async fn mount_api_support(&self) {
+        Mock::given(method("POST"))
+            .and(path("/sony/guide"))
+            .and(body_partial_json(
+                json!({ "method": "getSupportedApiInfo" }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&self.api_support))
+            .named("getSupportedApiInfo POST")
+            .mount(&self.server)
+            .await;
+    }
+
+    async fn respond(&self, service: &str, api: &str, body: Value) {
+        Mock::given(method("POST"))
+            .and(path(format!("/sony/{service}")))
+            .and(body_partial_json(json!({ "method": api })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .named(format!("{service}.{api} POST"))
+            .mount(&self.server)
+            .await;
+    }
+}
+
+// Names and versions in the format of getSupportedApiInfo.
+fn describe(names: &APIsMap) -> Vec<Value> {
+    names
+        .iter()
+        .map(|(name, versions)| {
+            let versions: Vec<Value> = versions
+                .iter()
+                .map(|version| json!({ "version": version }))
+                .collect();
+            json!({ "name": name, "versions": versions })
+        })
+        .collect()
+}
+
+// Adds to a service of getSupportedApiInfo the APIs of another one with the same name.
+fn merge_apis(merged: &mut Value, service: &Value) {
+    let Some(apis) = merged["apis"].as_array_mut() else {
+        return;
+    };
+    for api in service["apis"].as_array().into_iter().flatten() {
+        if !apis.iter().any(|x| x["name"] == api["name"]) {
+            apis.push(api.clone());
+        }
+    }
+}
+
+fn read_json(json_path: &Path) -> Value {
+    let file = File::open(json_path)
+        .unwrap_or_else(|err| panic!("can't open {}: {err}", json_path.display()));
+    serde_json::from_reader(BufReader::new(file))
+        .unwrap_or_else(|err| panic!("can't parse {}: {err}", json_path.display()))
+}
+
+/// Creates a [wiremock] value from a JSON file, like a request or a response
+/// captured from a display.
+pub trait FromFile {
+    /// # Panics
+    /// If the file can't be read or isn't JSON.
+    fn from_json_file(json_path: &str) -> Self;
+}
+
+impl FromFile for ResponseTemplate {
+    /// Response with the status `200` and the file as body.
+    fn from_json_file(json_path: &str) -> Self {
+        ResponseTemplate::new(200).set_body_json(read_json(Path::new(json_path)))
+    }
+}
+
+impl FromFile for BodyExactMatcher {
+    /// Matches the requests with the file as body.
+    fn from_json_file(json_path: &str) -> Self {
+        Self::json(read_json(Path::new(json_path)))
+    }
+}
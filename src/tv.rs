@@ -0,0 +1,85 @@
+//! Remote control style wrapper over the client, for those who don't need the whole API.
+
+use crate::{av_content::InputUri, error::Result, snapshot::DeviceSnapshot, Bravia};
+
+/// Controls a display like a remote control would,
+/// hiding the services and the API versions.
+///
+/// # Examples
+/// ```no_run
+/// # use bravia_api::{av_content::InputUri, tv::Tv, Bravia, error::Result};
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// let tv = Tv::new(Bravia::new("ADDRESS", Some("PASSWORD")).await?);
+/// tv.turn_on().await?;
+/// tv.select_input(InputUri::Hdmi(2)).await?;
+/// tv.set_volume(15).await?;
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tv(Bravia);
+
+impl Tv {
+    pub fn new(bravia: Bravia) -> Self {
+        Self(bravia)
+    }
+
+    /// Client used to make the requests, to access the whole API.
+    pub fn bravia(&self) -> &Bravia {
+        &self.0
+    }
+
+    /// Turns the display on.
+    pub async fn turn_on(&self) -> Result<()> {
+        self.0.system().set_power_status(true).await
+    }
+
+    /// Puts the display in standby.
+    pub async fn turn_off(&self) -> Result<()> {
+        self.0.system().set_power_status(false).await
+    }
+
+    /// Sets the volume of the speakers.
+    pub async fn set_volume(&self, volume: u8) -> Result<()> {
+        self.0
+            .audio()
+            .set_audio_volume(Some("speaker"), volume.to_string(), None, None)
+            .await
+    }
+
+    /// Mutes the audio.
+    pub async fn mute(&self) -> Result<()> {
+        self.0.audio().set_audio_mute(true).await
+    }
+
+    /// Unmutes the audio.
+    pub async fn unmute(&self) -> Result<()> {
+        self.0.audio().set_audio_mute(false).await
+    }
+
+    /// Switches to the given external input.
+    pub async fn select_input(&self, input: InputUri) -> Result<()> {
+        self.0.av_content().select_input(input, false).await
+    }
+
+    /// Launches the application with the given title, ignoring case.
+    ///
+    /// # Errors
+    /// [InvalidParameter](crate::error::Error::InvalidParameter) if no installed application has that title.
+    pub async fn launch_app(&self, title: &str) -> Result<()> {
+        self.0.app_control().launch_by_title(title, false).await
+    }
+
+    /// Fetches the power status, the volume and what is being shown,
+    /// see [snapshot](Bravia::snapshot).
+    pub async fn current_state(&self) -> Result<DeviceSnapshot> {
+        self.0.snapshot().await
+    }
+}
+
+impl From<Bravia> for Tv {
+    fn from(bravia: Bravia) -> Self {
+        Self::new(bravia)
+    }
+}
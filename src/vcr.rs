@@ -0,0 +1,152 @@
+//! Recording of the requests sent to a server and of its responses, that can be replayed later
+//! to run the tests of an application against a captured session without the display.
+//!
+//! # Examples
+//! ```no_run
+//! # use bravia_api::{Bravia, error::Result, vcr::{Cassette, Vcr}};
+//! # #[tokio::main]
+//! # async fn main() -> Result<()> {
+//! // Records a session with a real display
+//! let vcr = Vcr::record();
+//! let bravia = Bravia::builder("ADDRESS")
+//!     .auth("PASSWORD")
+//!     .vcr(vcr.clone())
+//!     .build()
+//!     .await?;
+//! bravia.system().get_power_status().await?;
+//! std::fs::write("session.json", vcr.cassette().to_json()?).unwrap();
+//!
+//! // Replays it, no request is sent
+//! let cassette = Cassette::from_json(&std::fs::read_to_string("session.json").unwrap())?;
+//! let bravia = Bravia::builder("ADDRESS")
+//!     .auth("PASSWORD")
+//!     .vcr(Vcr::replay(cassette))
+//!     .build()
+//!     .await?;
+//! assert_eq!("active", bravia.system().get_power_status().await?);
+//! #    Ok(())
+//! # }
+//! ```
+
+use crate::error::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// Request sent to the server and the response it got.\
+/// The credentials are not recorded.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// Service endpoint, like `system`.
+    pub endpoint: String,
+    /// Body of the request, a string with the SOAP envelope for the
+    /// [IRCC codes](crate::ircc::IrccService).
+    pub request: Value,
+    /// HTTP status of the response.
+    pub status: u16,
+    /// Body of the response.
+    pub response: String,
+    /// Values of the `Set-Cookie` headers of the response.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cookies: Vec<String>,
+}
+
+/// Interactions recorded by a [Vcr], in the order they happened.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Cassette {
+    /// Request and response pairs.
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Serializes the interactions to JSON, indented to keep the files readable.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Loads the interactions from JSON created by [to_json](Self::to_json).
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Mode {
+    Record,
+    Replay,
+}
+
+#[derive(Debug)]
+struct State {
+    mode: Mode,
+    cassette: Cassette,
+    // Interactions already served while replaying.
+    replayed: Vec<bool>,
+}
+
+/// Records the interactions of a client with the server, or serves recorded ones instead of
+/// sending the requests, see [vcr](crate::BraviaBuilder::vcr).\
+/// Cloning it is cheap and the clones share the same cassette.
+///
+/// While replaying, a request is answered by the first interaction not served yet
+/// with the same endpoint and body, so a call repeated after a state change gets
+/// the recorded responses in order.
+/// The requests without such an interaction fail with [NotRecorded](crate::error::Error::NotRecorded).
+#[derive(Debug, Clone)]
+pub struct Vcr(Arc<Mutex<State>>);
+
+impl Vcr {
+    /// Sends the requests to the server and records them.
+    pub fn record() -> Self {
+        Self::new(Mode::Record, Cassette::default())
+    }
+
+    /// Answers the requests with the given interactions, nothing is sent to the server.
+    pub fn replay(cassette: Cassette) -> Self {
+        Self::new(Mode::Replay, cassette)
+    }
+
+    /// Copy of the recorded interactions, or of the replayed ones.
+    pub fn cassette(&self) -> Cassette {
+        self.state().cassette.clone()
+    }
+
+    fn new(mode: Mode, cassette: Cassette) -> Self {
+        let replayed = vec![false; cassette.interactions.len()];
+        Self(Arc::new(Mutex::new(State {
+            mode,
+            cassette,
+            replayed,
+        })))
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub(crate) fn is_replaying(&self) -> bool {
+        self.state().mode == Mode::Replay
+    }
+
+    pub(crate) fn record_interaction(&self, interaction: Interaction) {
+        self.state().cassette.interactions.push(interaction);
+    }
+
+    pub(crate) fn replay_interaction(
+        &self,
+        endpoint: &str,
+        request: &Value,
+    ) -> Option<Interaction> {
+        let mut state = self.state();
+        let State {
+            cassette, replayed, ..
+        } = &mut *state;
+        let (interaction, replayed) = cassette.interactions.iter().zip(replayed.iter_mut()).find(
+            |(interaction, replayed)| {
+                !**replayed && interaction.endpoint == endpoint && interaction.request == *request
+            },
+        )?;
+        *replayed = true;
+        Some(interaction.clone())
+    }
+}
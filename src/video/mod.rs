@@ -1,8 +1,12 @@
 //! APIs that are related to video functions.
 
-use crate::{error::Result, Bravia, RequestBodyBuilder, RequestBuilder};
+use crate::{
+    error::{Error, Result},
+    lenient, Bravia, RequestBodyBuilder, RequestBuilder, ServiceContext,
+};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
+use std::fmt;
 
 const ENDPOINT: &str = "video";
 
@@ -25,82 +29,320 @@ pub struct Candidate {
     pub value: String,
     /// Max value of specified settings.\
     /// Only if value of target is not indicated by numerical number, -1 is set.
-    #[serde(default = "candidate_f64_default")]
+    #[serde(
+        default = "candidate_f64_default",
+        deserialize_with = "lenient::number"
+    )]
     pub max: f64,
     /// Min value of specified settings.\
     /// Only if value of target is not indicated by numerical number, -1 is set.
-    #[serde(default = "candidate_f64_default")]
+    #[serde(
+        default = "candidate_f64_default",
+        deserialize_with = "lenient::number"
+    )]
     pub min: f64,
     /// Step value of specified settings.\
     /// Only if value of target is not indicated by numerical number, -1 is set.
-    #[serde(default = "candidate_f64_default")]
+    #[serde(
+        default = "candidate_f64_default",
+        deserialize_with = "lenient::number"
+    )]
     pub step: f64,
 }
 
+impl Candidate {
+    /// Checks if `value` is accepted by this candidate,
+    /// either as the same enumeration value or as a step of the numerical range.
+    pub fn accepts(&self, value: &str) -> bool {
+        if !self.value.is_empty() {
+            return self.value == value;
+        }
+        if self.min < 0.0 && self.max < 0.0 && self.step < 0.0 {
+            return true;
+        }
+        let Ok(value) = value.parse::<f64>() else {
+            return false;
+        };
+        if value < self.min || value > self.max {
+            return false;
+        }
+        if self.step <= 0.0 {
+            return true;
+        }
+        let steps = (value - self.min) / self.step;
+        (steps - steps.round()).abs() < 1e-6
+    }
+}
+
+/// Picture quality setting target.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum PictureQualityTarget {
+    /// Adjust the color saturation level.
+    Color,
+    /// Adjust the luminance level of the screen.
+    Brightness,
+    /// Adjust the picture white level.
+    Contrast,
+    /// Adjust the picture detail.
+    Sharpness,
+    /// Set picture mode.
+    PictureMode,
+    /// Optimize brightness according to ambient light.
+    LightSensor,
+    /// Change the color reproduction range.
+    ColorSpace,
+    /// Adjust the color temperature.
+    ColorTemperature,
+    /// Automatically selects the picture mode based on the viewing content.
+    AutoPictureMode,
+    /// Picture that is suitable for a High Dynamic Range signal.
+    HdrMode,
+    /// Optimizes contrast by adjusting brightness in individual sections of the screen.
+    AutoLocalDimming,
+    /// Adjust peak luminance for the brightness whites and blackest blacks.
+    XtendedDynamicRange,
+    /// Target returned by the server that doesn't match the other variants.
+    Other(String),
+}
+
+impl PictureQualityTarget {
+    /// Name of the target used by the server, like `hdrMode`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Color => "color",
+            Self::Brightness => "brightness",
+            Self::Contrast => "contrast",
+            Self::Sharpness => "sharpness",
+            Self::PictureMode => "pictureMode",
+            Self::LightSensor => "lightSensor",
+            Self::ColorSpace => "colorSpace",
+            Self::ColorTemperature => "colorTemperature",
+            Self::AutoPictureMode => "autoPictureMode",
+            Self::HdrMode => "hdrMode",
+            Self::AutoLocalDimming => "autoLocalDimming",
+            Self::XtendedDynamicRange => "xtendedDynamicRange",
+            Self::Other(target) => target,
+        }
+    }
+}
+
+impl From<String> for PictureQualityTarget {
+    fn from(target: String) -> Self {
+        match target.as_str() {
+            "color" => Self::Color,
+            "brightness" => Self::Brightness,
+            "contrast" => Self::Contrast,
+            "sharpness" => Self::Sharpness,
+            "pictureMode" => Self::PictureMode,
+            "lightSensor" => Self::LightSensor,
+            "colorSpace" => Self::ColorSpace,
+            "colorTemperature" => Self::ColorTemperature,
+            "autoPictureMode" => Self::AutoPictureMode,
+            "hdrMode" => Self::HdrMode,
+            "autoLocalDimming" => Self::AutoLocalDimming,
+            "xtendedDynamicRange" => Self::XtendedDynamicRange,
+            _ => Self::Other(target),
+        }
+    }
+}
+
+impl From<PictureQualityTarget> for String {
+    fn from(target: PictureQualityTarget) -> Self {
+        match target {
+            PictureQualityTarget::Other(target) => target,
+            target => target.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for PictureQualityTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Picture mode, value of the [PictureMode](PictureQualityTarget::PictureMode) target.\
+/// The available modes depend on the device and on the content.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum PictureMode {
+    Vivid,
+    Standard,
+    Cinema,
+    Custom,
+    Game,
+    Graphics,
+    Sports,
+    Animation,
+    PhotoVivid,
+    PhotoStandard,
+    PhotoCustom,
+    DolbyVisionBright,
+    DolbyVisionDark,
+    /// Mode returned by the server that doesn't match the other variants.
+    Other(String),
+}
+
+impl PictureMode {
+    /// Name of the mode used by the server, like `photoVivid`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Vivid => "vivid",
+            Self::Standard => "standard",
+            Self::Cinema => "cinema",
+            Self::Custom => "custom",
+            Self::Game => "game",
+            Self::Graphics => "graphics",
+            Self::Sports => "sports",
+            Self::Animation => "animation",
+            Self::PhotoVivid => "photoVivid",
+            Self::PhotoStandard => "photoStandard",
+            Self::PhotoCustom => "photoCustom",
+            Self::DolbyVisionBright => "dolbyVisionBright",
+            Self::DolbyVisionDark => "dolbyVisionDark",
+            Self::Other(mode) => mode,
+        }
+    }
+}
+
+impl From<String> for PictureMode {
+    fn from(mode: String) -> Self {
+        match mode.as_str() {
+            "vivid" => Self::Vivid,
+            "standard" => Self::Standard,
+            "cinema" => Self::Cinema,
+            "custom" => Self::Custom,
+            "game" => Self::Game,
+            "graphics" => Self::Graphics,
+            "sports" => Self::Sports,
+            "animation" => Self::Animation,
+            "photoVivid" => Self::PhotoVivid,
+            "photoStandard" => Self::PhotoStandard,
+            "photoCustom" => Self::PhotoCustom,
+            "dolbyVisionBright" => Self::DolbyVisionBright,
+            "dolbyVisionDark" => Self::DolbyVisionDark,
+            _ => Self::Other(mode),
+        }
+    }
+}
+
+impl From<PictureMode> for String {
+    fn from(mode: PictureMode) -> Self {
+        match mode {
+            PictureMode::Other(mode) => mode,
+            mode => mode.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for PictureMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PictureQualitySettingsResponse {
     /// Target name
-    pub target: String,
+    pub target: PictureQualityTarget,
     /// Current value of target
     pub current_value: String,
     /// This target is currently available or not
-    #[serde(default = "bool_true")]
+    #[serde(default = "bool_true", deserialize_with = "lenient::bool")]
     pub is_available: bool,
     pub candidate: Option<Vec<Candidate>>,
 }
 
+impl PictureQualitySettingsResponse {
+    /// Checks `value` against the candidates of the target.\
+    /// Any value is accepted when the server doesn't provide candidates.
+    ///
+    /// # Errors
+    /// [InvalidParameter](Error::InvalidParameter) if no candidate accepts `value`.
+    pub fn validate(&self, value: &str) -> Result<()> {
+        match &self.candidate {
+            Some(candidates) if !candidates.iter().any(|c| c.accepts(value)) => {
+                Err(Error::InvalidParameter(format!(
+                    "{} is not a valid value for {}",
+                    value, self.target
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Creates a request to set the target to `value`, after [validating](Self::validate) it.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{video::PictureQualityTarget, Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// let video = bravia.video();
+    /// let settings = video
+    ///     .get_picture_quality_settings(Some(PictureQualityTarget::Brightness))
+    ///     .await?;
+    /// let request = settings[0].request("30")?;
+    /// video.set_picture_quality_settings(vec![request]).await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn request(&self, value: &str) -> Result<PictureQualitySettingsRequest> {
+        self.validate(value)?;
+        Ok(PictureQualitySettingsRequest::new(
+            Some(self.target.clone()),
+            Some(value),
+        ))
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct PictureQualitySettingsRequest {
     /// Target name (UI setting target).\
     /// Please use [get_picture_quality_settings](VideoService::get_picture_quality_settings) to acquire the available targets.
     // From my tests I can't get it to work with None values.
-    pub target: Option<String>,
+    pub target: Option<PictureQualityTarget>,
     /// * `value` - The value to set for target name.
     pub value: Option<String>,
 }
 
 impl PictureQualitySettingsRequest {
-    pub fn new(target: Option<String>, value: Option<String>) -> Self {
-        Self { target, value }
+    pub fn new(target: Option<PictureQualityTarget>, value: Option<&str>) -> Self {
+        Self {
+            target,
+            value: value.map(String::from),
+        }
     }
 }
 
 /// Provides access to video service APIs.
-pub struct VideoService<'a>(&'a Bravia);
+pub struct VideoService(ServiceContext);
 
-impl<'a> VideoService<'a> {
-    pub fn new(bravia: &'a Bravia) -> Self {
-        Self(bravia)
+impl_service_options!(VideoService);
+
+impl VideoService {
+    pub fn new(bravia: &Bravia) -> Self {
+        Self(ServiceContext::new(bravia))
     }
 
     /// Provides current settings and supported settings related to picture quality configuration items.
     ///
     /// # Arguments
     /// * `target` - Target name
-    ///     * `color` - Adjust the color saturation level.
-    ///     * `brightness` - Adjust the luminance level of the screen.
-    ///     * `contrast` - Adjust the picture white level.
-    ///     * `sharpness` - Adjust the picture detail.
-    ///     * `pictureMode` - Set picture mode.
-    ///     * `lightSensor` - Optimize brightness according to ambient light.
-    ///     * `colorSpace` - Change the color reproduction range.
-    ///     * `colorTemperature` - Adjust the color temperature.
-    ///     * `autoPictureMode` - Automatically selects the picture mode based on the viewing content.
-    ///     * `hdrMode` - Picture that is suitable for a High Dynamic Range signal.
-    ///     * `autoLocalDimming` - Optimizes contrast by adjusting brightness in individual sections of the screen.
-    ///     * `xtendedDynamicRange` - Adjust peak luminance for the brightness whites and blackest blacks.
     ///     * `None` - Settings of all targets.
     ///
     /// # Authentication Level
     /// None
     pub async fn get_picture_quality_settings(
         &self,
-        target: Option<String>,
+        target: Option<PictureQualityTarget>,
     ) -> Result<Vec<PictureQualitySettingsResponse>> {
         let mut params = Map::new();
         if let Some(target) = target {
-            params.insert(String::from("target"), Value::from(target));
+            params.insert(String::from("target"), Value::from(String::from(target)));
         }
 
         let body = RequestBodyBuilder::default()
@@ -108,13 +350,12 @@ impl<'a> VideoService<'a> {
             .method("getPictureQualitySettings")
             .params(Value::from(params))
             .build()?;
-        let req = RequestBuilder::default()
+        RequestBuilder::default()
             .endpoint(ENDPOINT)
             .body(body)
             .has_result()
-            .make(self.0)
-            .await?;
-        Ok(serde_json::from_value(req)?)
+            .make_as(&self.0)
+            .await
     }
 
     /// Provides a function to change settings related to picture quality setting items.
@@ -123,10 +364,13 @@ impl<'a> VideoService<'a> {
     /// Generic
     pub async fn set_picture_quality_settings(
         &self,
-        settings: Vec<PictureQualitySettingsRequest>,
+        settings: impl IntoIterator<Item = PictureQualitySettingsRequest>,
     ) -> Result<()> {
         let mut params = Map::new();
-        params.insert(String::from("settings"), serde_json::to_value(settings)?);
+        params.insert(
+            String::from("settings"),
+            serde_json::to_value(settings.into_iter().collect::<Vec<_>>())?,
+        );
 
         let body = RequestBodyBuilder::default()
             .id(12)
@@ -137,8 +381,85 @@ impl<'a> VideoService<'a> {
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
-            .make(self.0)
+            .make(&self.0)
             .await?;
         Ok(())
     }
+    /// Checks the settings against the candidates returned by
+    /// [get_picture_quality_settings](Self::get_picture_quality_settings),
+    /// then sets them with [set_picture_quality_settings](Self::set_picture_quality_settings).
+    ///
+    /// # Errors
+    /// [InvalidParameter](Error::InvalidParameter) listing all the settings
+    /// with an invalid value or an unavailable target, nothing is set in this case.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn set_validated(
+        &self,
+        settings: impl IntoIterator<Item = PictureQualitySettingsRequest>,
+    ) -> Result<()> {
+        let settings: Vec<_> = settings.into_iter().collect();
+        let current = self.get_picture_quality_settings(None).await?;
+
+        let mut invalid = Vec::new();
+        for setting in &settings {
+            let (Some(target), Some(value)) = (&setting.target, &setting.value) else {
+                invalid.push(String::from("missing target or value"));
+                continue;
+            };
+            match current.iter().find(|current| &current.target == target) {
+                Some(current) if !current.is_available => {
+                    invalid.push(format!("{} is not available", target))
+                }
+                Some(current) => {
+                    if let Err(Error::InvalidParameter(err)) = current.validate(value) {
+                        invalid.push(err);
+                    }
+                }
+                None => invalid.push(format!("{} is not supported", target)),
+            }
+        }
+        if !invalid.is_empty() {
+            return Err(Error::InvalidParameter(invalid.join(", ")));
+        }
+
+        self.set_picture_quality_settings(settings).await
+    }
+    /// Provides the current picture mode, reading the `pictureMode` target of
+    /// [getPictureQualitySettings](Self::get_picture_quality_settings).
+    ///
+    /// # Authentication Level
+    /// None
+    pub async fn get_picture_mode(&self) -> Result<PictureMode> {
+        self.get_picture_quality_settings(Some(PictureQualityTarget::PictureMode))
+            .await?
+            .into_iter()
+            .find(|setting| setting.target == PictureQualityTarget::PictureMode)
+            .map(|setting| setting.current_value.into())
+            .ok_or(Error::MissingValue("pictureMode"))
+    }
+
+    /// Changes the picture mode with [setPictureQualitySettings](Self::set_picture_quality_settings).
+    ///
+    /// # Authentication Level
+    /// Generic
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{video::PictureMode, Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// bravia.video().set_picture_mode(PictureMode::Game).await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn set_picture_mode(&self, mode: PictureMode) -> Result<()> {
+        self.set_picture_quality_settings(vec![PictureQualitySettingsRequest::new(
+            Some(PictureQualityTarget::PictureMode),
+            Some(mode.as_str()),
+        )])
+        .await
+    }
 }
@@ -4,32 +4,72 @@
 //! This API is able to set the value at API call timing,
 //!  which might vary depending on the device state. (For example, depending on the "current" input source.)
 
-use crate::{error::Result, Bravia, RequestBodyBuilder, RequestBuilder};
+use crate::{error::Result, Bravia, RequestBodyBuilder, RequestBuilder, ServiceContext};
+use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 
 const ENDPOINT: &str = "videoScreen";
 
+/// Scene of the input source, used to adapt the picture to the content.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Scene {
+    /// Automatically selects the scene based on the viewing content.
+    Auto,
+    /// Automatically selects `Cinema` for 24Hz signal content. Behaves as `Auto` for all other signals.
+    Auto24pSync,
+    /// Turns off scene select for general content.
+    General,
+    /// Scene for movies.
+    Cinema,
+    /// Scene for sports.
+    Sports,
+    /// Scene for video games.
+    Game,
+    /// Scene for graphics, like the output of a PC.
+    Graphics,
+}
+
 /// Provides access to video_screen service APIs.
-pub struct VideoScreenService<'a>(&'a Bravia);
+pub struct VideoScreenService(ServiceContext);
+
+impl_service_options!(VideoScreenService);
 
-impl<'a> VideoScreenService<'a> {
-    pub fn new(bravia: &'a Bravia) -> Self {
-        Self(bravia)
+impl VideoScreenService {
+    pub fn new(bravia: &Bravia) -> Self {
+        Self(ServiceContext::new(bravia))
+    }
+
+    /// Provides the function to get the current scene setting value.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn get_scene_setting(&self) -> Result<Scene> {
+        let body = RequestBodyBuilder::default()
+            .id(45)
+            .method("getSceneSetting")
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .has_result()
+            .get("currentValue".into())
+            .make_as(&self.0)
+            .await
     }
 
     /// Provides the function to change the current scene setting value.
     ///
     /// # Arguments
-    /// * `value` - Scene of the input source.
-    ///     * `auto` - Automatically selects the scene based on the viewing content.
-    ///     * `auto24pSync` - Automatically selects `Cinema` for 24Hz signal content. Behaves as `Auto` for all other signals.
-    ///     * `general` - Turns off scene select for general content.
+    /// * `value` - Scene of the input source.\
+    ///   The supported scenes depend on the device.
     ///
     /// # Authentication Level
     /// Generic
-    pub async fn set_scene_settings(&self, value: String) -> Result<()> {
+    pub async fn set_scene_settings(&self, value: Scene) -> Result<()> {
         let mut params = Map::new();
-        params.insert(String::from("value"), Value::from(value));
+        params.insert(String::from("value"), serde_json::to_value(value)?);
 
         let body = RequestBodyBuilder::default()
             .id(40)
@@ -40,7 +80,162 @@ impl<'a> VideoScreenService<'a> {
             .endpoint(ENDPOINT)
             .body(body)
             .is_protected()
-            .make(self.0)
+            .make(&self.0)
+            .await?;
+        Ok(())
+    }
+
+    /// Provides the function to change the multi screen mode.
+    ///
+    /// # Arguments
+    /// * `mode` - Multi screen mode.
+    ///     * `single` - Only one screen is shown.
+    ///     * `pip` - Picture in picture, a sub screen is shown over the main screen.
+    ///     * `pap` - Picture and picture, two screens are shown side by side.
+    /// * `internet_tv_mode` - Screen used by internet TV contents.
+    ///     * Supported with API version 1.1
+    ///     <!-- end of the list -->
+    ///     Values:
+    ///     * `main` - Internet TV contents are shown on the main screen.
+    ///     * `sub` - Internet TV contents are shown on the sub screen.
+    ///     * `None` - Not specified. (depends on the server)
+    /// * `version` - API version.
+    ///
+    /// # Authentication Level
+    /// Generic
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// bravia.video_screen().set_multi_screen_mode("pip", None, None).await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn set_multi_screen_mode(
+        &self,
+        mode: impl Into<String>,
+        internet_tv_mode: Option<&str>,
+        version: Option<&str>,
+    ) -> Result<()> {
+        let mut params = Map::new();
+        params.insert(String::from("mode"), Value::from(mode.into()));
+        if let (Some("1.1"), Some(internet_tv_mode)) = (version, internet_tv_mode) {
+            let mut option = Map::new();
+            option.insert(
+                String::from("internetTVMode"),
+                Value::from(internet_tv_mode),
+            );
+            params.insert(String::from("option"), Value::from(option));
+        }
+
+        let body = RequestBodyBuilder::default()
+            .id(41)
+            .method("setMultiScreenMode")
+            .version(version)
+            .params(Value::from(params))
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .make(&self.0)
+            .await?;
+        Ok(())
+    }
+
+    /// Provides the function to move the sub screen of the picture in picture mode,
+    /// see [setMultiScreenMode](Self::set_multi_screen_mode).
+    ///
+    /// # Arguments
+    /// * `position` - Corner of the screen.
+    ///     * `leftTop`
+    ///     * `rightTop`
+    ///     * `leftBottom`
+    ///     * `rightBottom`
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn set_pip_sub_screen_position(&self, position: impl Into<String>) -> Result<()> {
+        let mut params = Map::new();
+        params.insert(String::from("position"), Value::from(position.into()));
+
+        let body = RequestBodyBuilder::default()
+            .id(42)
+            .method("setPipSubScreenPosition")
+            .params(Value::from(params))
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .make(&self.0)
+            .await?;
+        Ok(())
+    }
+
+    /// Provides the function to change the size of a screen in the picture and picture mode,
+    /// the other screen is resized accordingly.
+    ///
+    /// # Arguments
+    /// * `screen` - Screen to resize.
+    ///     * `main` - Main screen.
+    ///     * `sub` - Sub screen.
+    /// * `size` - Size to set. The following formats are applied:
+    ///     * `N` - N is a numeric string (ex. "2"). The size is set to level N.
+    ///     * `+N` - N is a numeric string (ex. "+1"). The size is increased by an increment of N.
+    ///     * `-N` - N is a numeric string (ex. "-1"). The size is reduced by an increment of N.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn set_pap_screen_size(
+        &self,
+        screen: impl Into<String>,
+        size: impl Into<String>,
+    ) -> Result<()> {
+        let mut params = Map::new();
+        params.insert(String::from("screen"), Value::from(screen.into()));
+        params.insert(String::from("size"), Value::from(size.into()));
+
+        let body = RequestBodyBuilder::default()
+            .id(43)
+            .method("setPapScreenSize")
+            .params(Value::from(params))
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .make(&self.0)
+            .await?;
+        Ok(())
+    }
+
+    /// Provides the function to choose the screen whose audio is played in the multi screen modes.
+    ///
+    /// # Arguments
+    /// * `screen` - Screen to play the audio of.
+    ///     * `main` - Main screen.
+    ///     * `sub` - Sub screen.
+    ///
+    /// # Authentication Level
+    /// Generic
+    pub async fn set_audio_source_screen(&self, screen: impl Into<String>) -> Result<()> {
+        let mut params = Map::new();
+        params.insert(String::from("screen"), Value::from(screen.into()));
+
+        let body = RequestBodyBuilder::default()
+            .id(44)
+            .method("setAudioSourceScreen")
+            .params(Value::from(params))
+            .build()?;
+        RequestBuilder::default()
+            .endpoint(ENDPOINT)
+            .body(body)
+            .is_protected()
+            .make(&self.0)
             .await?;
         Ok(())
     }
@@ -0,0 +1,145 @@
+//! Change watcher that polls the APIs, a fallback for the displays without WebSocket notifications.
+
+use crate::{
+    audio::VolumeInformation, av_content::PlayingContentInfo, error::Error, runtime, Bravia,
+};
+use std::time::Duration;
+use tokio::sync::broadcast;
+
+// Number of events kept for the receivers that are not keeping up.
+const CHANNEL_CAPACITY: usize = 16;
+
+/// Change detected by a [Watcher].
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum ChangeEvent {
+    /// Power status changed, like from `standby` to `active`.
+    PowerStatus(String),
+    /// Volume or mute status of an output changed.
+    Volume(Vec<VolumeInformation>),
+    /// Content shown changed, `None` if nothing is playing.
+    PlayingContent(Option<PlayingContentInfo>),
+}
+
+/// Polls the selected getters at a fixed interval and broadcasts their changes.\
+/// The first poll always produces an event with the current value of each getter.
+///
+/// # Examples
+/// ```no_run
+/// # use bravia_api::{Bravia, error::Result};
+/// # use std::time::Duration;
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+/// let watcher = bravia.watch(Duration::from_secs(2)).volume(false);
+/// let mut events = watcher.subscribe();
+/// tokio::spawn(async move { watcher.run().await });
+/// while let Ok(event) = events.recv().await {
+///     println!("{event:?}");
+/// }
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Watcher {
+    bravia: Bravia,
+    interval: Duration,
+    power: bool,
+    volume: bool,
+    playing_content: bool,
+    sender: broadcast::Sender<ChangeEvent>,
+}
+
+impl Watcher {
+    /// Watches all the getters by default.
+    ///
+    /// # Arguments
+    /// * `bravia` - Client used to poll the getters.
+    /// * `interval` - Delay between two polls.
+    pub fn new(bravia: &Bravia, interval: Duration) -> Self {
+        Self {
+            bravia: bravia.clone(),
+            interval,
+            power: true,
+            volume: true,
+            playing_content: true,
+            sender: broadcast::channel(CHANNEL_CAPACITY).0,
+        }
+    }
+
+    /// Polls [getPowerStatus](crate::system::SystemService::get_power_status).
+    pub fn power(mut self, enabled: bool) -> Self {
+        self.power = enabled;
+        self
+    }
+
+    /// Polls [getVolumeInformation](crate::audio::AudioService::get_volume_information).
+    pub fn volume(mut self, enabled: bool) -> Self {
+        self.volume = enabled;
+        self
+    }
+
+    /// Polls [getPlayingContentInfo](crate::av_content::AvContentService::get_playing_content_info).
+    pub fn playing_content(mut self, enabled: bool) -> Self {
+        self.playing_content = enabled;
+        self
+    }
+
+    /// Returns a receiver of the changes, it must be created before [run](Self::run).
+    pub fn subscribe(&self) -> broadcast::Receiver<ChangeEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Polls the getters until all the receivers are dropped.\
+    /// The failed polls are skipped, so the display being unreachable doesn't stop the watcher.
+    pub async fn run(&self) {
+        let mut power = None;
+        let mut volume = None;
+        let mut playing_content = None;
+        while self.sender.receiver_count() > 0 {
+            if self.power {
+                if let Ok(status) = self.bravia.system().get_power_status().await {
+                    self.update(&mut power, status, ChangeEvent::PowerStatus);
+                }
+            }
+            if self.volume {
+                if let Ok(info) = self.bravia.audio().get_volume_information().await {
+                    self.update(&mut volume, info, ChangeEvent::Volume);
+                }
+            }
+            if self.playing_content {
+                let content = match self.bravia.av_content().get_playing_content_info().await {
+                    Ok(content) => Some(Some(content)),
+                    // The server returns an error when there is no content to describe
+                    Err(Error::BraviaError(..)) => Some(None),
+                    Err(_) => None,
+                };
+                if let Some(content) = content {
+                    self.update(&mut playing_content, content, ChangeEvent::PlayingContent);
+                }
+            }
+            runtime::sleep(self.interval).await;
+        }
+    }
+
+    // Stores the new value and sends an event if it changed.
+    fn update<T: Clone + PartialEq>(
+        &self,
+        last: &mut Option<T>,
+        value: T,
+        event: fn(T) -> ChangeEvent,
+    ) {
+        if last.as_ref() != Some(&value) {
+            *last = Some(value.clone());
+            // Fails only when there are no receivers, checked by the loop
+            let _ = self.sender.send(event(value));
+        }
+    }
+}
+
+impl Bravia {
+    /// Returns a [Watcher] that polls the getters of this client at the given interval.
+    pub fn watch(&self, interval: Duration) -> Watcher {
+        Watcher::new(self, interval)
+    }
+}
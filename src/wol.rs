@@ -0,0 +1,65 @@
+//! Wake-on-LAN magic packets, used to turn on a display in standby.
+
+use crate::{error::Result, system::MacAddr, Bravia};
+use std::net::{Ipv4Addr, SocketAddr, UdpSocket};
+
+/// Default destination of the magic packets, the broadcast address on the discard port.
+pub const DEFAULT_BROADCAST_ADDRESS: SocketAddr =
+    SocketAddr::new(std::net::IpAddr::V4(Ipv4Addr::BROADCAST), 9);
+
+/// Sends a magic packet for the given MAC address to `broadcast_addr`.
+///
+/// # Arguments
+/// * `mac` - MAC address of the display, six hex octets separated by `:` or `-`.
+/// * `broadcast_addr` - Destination of the packet, usually [DEFAULT_BROADCAST_ADDRESS].
+///
+/// # Errors
+/// Returns [Error::InvalidParameter] if the MAC address is not valid.
+///
+/// # Examples
+/// ```no_run
+/// # use bravia_api::{wol::{send_magic_packet, DEFAULT_BROADCAST_ADDRESS}, error::Result};
+/// # fn main() -> Result<()> {
+/// send_magic_packet("FC:F1:52:00:11:22", DEFAULT_BROADCAST_ADDRESS)?;
+/// #    Ok(())
+/// # }
+/// ```
+pub fn send_magic_packet(mac: &str, broadcast_addr: SocketAddr) -> Result<()> {
+    let mac = mac.parse::<MacAddr>()?.octets();
+    // Six 0xFF bytes followed by the MAC address repeated sixteen times
+    let mut packet = vec![0xFF; 6];
+    for _ in 0..16 {
+        packet.extend_from_slice(&mac);
+    }
+
+    let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))?;
+    socket.set_broadcast(true)?;
+    socket.send_to(&packet, broadcast_addr)?;
+    Ok(())
+}
+
+impl Bravia {
+    /// Turns on the display with a Wake-on-LAN packet.\
+    /// The MAC address is read from the server only once, so it must be called at least once
+    /// while the display is reachable, or set in the [wake policy](crate::retry::WakePolicy).
+    /// The packet is sent to the broadcast address of the wake policy, if any.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use bravia_api::{Bravia, error::Result};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let bravia = Bravia::new("ADDRESS", Some("PASSWORD")).await?;
+    /// bravia.wake().await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub async fn wake(&self) -> Result<()> {
+        let policy = self.shared.wake_policy.as_ref();
+        let broadcast_addr = policy.map_or(DEFAULT_BROADCAST_ADDRESS, |p| p.broadcast_address);
+        match policy.and_then(|p| p.mac_address.as_deref()) {
+            Some(mac) => send_magic_packet(mac, broadcast_addr),
+            None => send_magic_packet(&self.wol_mac().await?, broadcast_addr),
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use crate::common::{server_setup, FromFile};
+use bravia_api::Bravia;
+use wiremock::{
+    matchers::{header, headers, method, path, BodyExactMatcher},
+    Mock, ResponseTemplate,
+};
+
+const ENDPOINT_PATH: &str = "/sony/accessControl";
+const JSON_BASE_PATH: &str = "sample_payloads/access_control";
+
+#[tokio::test]
+async fn test_act_register_without_pin() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body =
+        BodyExactMatcher::from_json_file(&format!("{}/requests/act_register.json", JSON_BASE_PATH));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .named("actRegister POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), None).await.unwrap();
+
+    // Act
+    let cookie = bravia
+        .access_control()
+        .act_register("test:1234", "test", None)
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(cookie, None);
+}
+
+#[tokio::test]
+async fn test_act_register_with_pin() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body =
+        BodyExactMatcher::from_json_file(&format!("{}/requests/act_register.json", JSON_BASE_PATH));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/act_register.json",
+        JSON_BASE_PATH
+    ))
+    .insert_header("Set-Cookie", "auth=ABCDEF; Path=/sony/; Max-Age=1209600");
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(header("Authorization", "Basic OjAwMDA="))
+        .and(expected_body)
+        .respond_with(template)
+        .named("actRegister POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), None).await.unwrap();
+
+    // Act
+    let cookie = bravia
+        .access_control()
+        .act_register("test:1234", "test", Some("0000"))
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(cookie, Some(String::from("ABCDEF")));
+}
+
+#[tokio::test]
+async fn test_auth_cookie() {
+    // Arrange
+    let mock_server = server_setup("sample_payloads/system").await;
+    let expected_body =
+        BodyExactMatcher::from_json_file("sample_payloads/system/requests/raw_call.json");
+    let template =
+        ResponseTemplate::from_json_file("sample_payloads/system/responses/raw_call.json");
+    Mock::given(method("POST"))
+        .and(path("/sony/system"))
+        .and(headers("Cookie", vec!["auth=ABCDEF"]))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getRemoteDeviceSettings POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::builder(&mock_server.uri())
+        .auth_cookie("ABCDEF")
+        .build()
+        .await
+        .unwrap();
+
+    // Act
+    let result = bravia
+        .raw_call(
+            "system",
+            "getRemoteDeviceSettings",
+            "1.0",
+            serde_json::json!({"target": "accessPermission"}),
+        )
+        .await;
+
+    // Assert
+    assert!(result.is_ok());
+}
@@ -1,10 +1,14 @@
 use crate::common::{server_setup, FromFile};
 use bravia_api::{
-    app_control::{Application, ApplicationStatus, WebAppStatus},
-    Bravia,
+    app_control::{AppIntent, AppUri, Application, ApplicationStatus, WebAppStatus},
+    error::Error,
+    test_util::MockBravia,
+    ApiSupport, Bravia,
 };
+use serde_json::json;
+use std::time::Duration;
 use wiremock::{
-    matchers::{headers, method, path, BodyExactMatcher},
+    matchers::{body_partial_json, headers, method, path, BodyExactMatcher},
     Mock, ResponseTemplate,
 };
 
@@ -43,7 +47,7 @@ async fn test_get_application_list() {
         uri: "com.sony.dtv.com.google.android.youtube.tv.com.google.android.apps.youtube.tv.activity.ShellActivity".to_string(),
         icon: "http://43.3.149.111/DIAL/icon/com.sony.dtv.com.google.android.youtube.tv.com.google.android.apps.youtube.tv.activity.ShellActivity.png".to_string(),
     };
-    assert_eq!(&yt, app_list.get(0).unwrap());
+    assert_eq!(&yt, app_list.first().unwrap());
 }
 
 #[tokio::test]
@@ -142,6 +146,103 @@ async fn test_get_web_app_status() {
     assert_eq!(example_app, web_app_status);
 }
 
+#[tokio::test]
+async fn test_launch_by_title() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(BodyExactMatcher::from_json_file(&format!(
+            "{}/requests/get_application_list.json",
+            JSON_BASE_PATH
+        )))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/get_application_list.json",
+            JSON_BASE_PATH
+        )))
+        // Read once, then again when no application matches
+        .expect(2)
+        .named("getApplicationList POST")
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(body_partial_json(json!({
+            "method": "setActiveApp",
+            "params": [{"uri": "com.sony.dtv.screnmirroring.com.screnmirroring.com.StartScreenMirroringHomeActivity"}]
+        })))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/set_active_app.json",
+            JSON_BASE_PATH
+        )))
+        .expect(2)
+        .named("setActiveApp POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+    let app_control = bravia.app_control();
+
+    // Act
+    let exact = app_control.launch_by_title("screen mirroring", false).await;
+    let fuzzy = app_control.launch_by_title("mirroring", true).await;
+    let not_fuzzy = app_control.launch_by_title("mirroring", false).await;
+
+    // Assert
+    assert!(exact.is_ok());
+    assert!(fuzzy.is_ok());
+    assert!(matches!(not_fuzzy, Err(Error::InvalidParameter(_))));
+}
+
+#[tokio::test]
+async fn test_cached_application_list() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(BodyExactMatcher::from_json_file(&format!(
+            "{}/requests/get_application_list.json",
+            JSON_BASE_PATH
+        )))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/get_application_list.json",
+            JSON_BASE_PATH
+        )))
+        // Read once by the client without TTL, twice after the cache is cleared,
+        // then twice by the client whose cache expires immediately
+        .expect(4)
+        .named("getApplicationList POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+    let expiring = Bravia::builder(&mock_server.uri())
+        .auth("TEST")
+        .app_list_ttl(Duration::ZERO)
+        .build()
+        .await
+        .unwrap();
+
+    // Act
+    let app_control = bravia.app_control();
+    let first = app_control.cached_application_list().await.unwrap();
+    let second = app_control.cached_application_list().await.unwrap();
+    app_control.clear_application_cache();
+    app_control.cached_application_list().await.unwrap();
+    expiring
+        .app_control()
+        .cached_application_list()
+        .await
+        .unwrap();
+    expiring
+        .app_control()
+        .cached_application_list()
+        .await
+        .unwrap();
+
+    // Assert
+    assert!(!first.is_empty());
+    assert_eq!(first, second);
+}
+
 #[tokio::test]
 async fn test_set_active_app() {
     // Arrange
@@ -173,6 +274,217 @@ async fn test_set_active_app() {
     // Nothing to assert, this API returns ()
 }
 
+#[tokio::test]
+async fn test_set_active_app_intent() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/set_active_app_intent.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/set_active_app_intent.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("setActiveApp POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let intent = AppIntent::new("com.sony.dtv.com.google.android.youtube.tv")
+        .unwrap()
+        .extra("v", "dQw4w9WgXcQ")
+        .extra("t", "1m 30s");
+    bravia
+        .app_control()
+        .set_active_app_uri(&AppUri::from(intent))
+        .await
+        .unwrap();
+
+    // Nothing to assert, this API returns ()
+}
+
+#[test]
+fn test_app_intent_invalid_uri() {
+    assert!(AppIntent::new("").is_err());
+    assert!(AppIntent::new("com.sony.dtv.app name").is_err());
+    assert!(AppIntent::new("youtube").is_err());
+    assert!(AppIntent::new("com.sony.dtv.1app").is_err());
+    assert!(AppIntent::new("1app://launch").is_err());
+    assert!(AppIntent::new("com.sony.dtv.app?v").is_err());
+    assert!(AppIntent::new("com.sony.dtv.app?v=%zz").is_err());
+    assert!(AppIntent::new("com.sony.dtv.app?v=1&").is_err());
+
+    assert!(AppIntent::new("com.sony.dtv.app?v=1&t=1m%2030s").is_ok());
+    assert!(AppIntent::new("localapp://webappruntime?url=http%3A%2F%2Fexample.com%2F").is_ok());
+}
+
+#[tokio::test]
+async fn test_set_active_app_uri() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/set_active_app.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/set_active_app.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("setActiveApp POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let uri = AppUri::from_url("http://example.com/").unwrap();
+    bravia.app_control().set_active_app_uri(&uri).await.unwrap();
+
+    // Nothing to assert, this API returns ()
+}
+
+#[tokio::test]
+async fn test_open_url() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/set_active_app.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/set_active_app.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .expect(1)
+        .named("setActiveApp POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let app_control = bravia.app_control();
+    // The browser service is not supported, the page is launched as an application
+    app_control
+        .open_url("http://example.com/", true)
+        .await
+        .unwrap();
+    let err = app_control
+        .open_url("example.com", false)
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert!(matches!(err, Error::InvalidParameter(_)));
+}
+
+#[tokio::test]
+async fn test_open_url_browser() {
+    // Arrange
+    let json_base_path = "sample_payloads/browser";
+    let mock_server = server_setup(json_base_path).await;
+    Mock::given(method("POST"))
+        .and(path("/sony/browser"))
+        .and(BodyExactMatcher::from_json_file(&format!(
+            "{}/requests/act_browser_control.json",
+            json_base_path
+        )))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/act_browser_control.json",
+            json_base_path
+        )))
+        .expect(1)
+        .named("actBrowserControl POST")
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/sony/browser"))
+        .and(body_partial_json(json!({
+            "method": "setTextUrl",
+            "params": [{"url": "http://example.com/"}]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"result": [], "id": 163})))
+        .expect(1)
+        .named("setTextUrl POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    bravia
+        .app_control()
+        .open_url("http://example.com/", true)
+        .await
+        .unwrap();
+
+    // Nothing to assert, the mocks expect the browser to be started and the page to be loaded
+}
+
+#[tokio::test]
+async fn test_open_url_text_url_only() {
+    // Arrange
+    let api_support = json!({ "browser": { "setTextUrl": ["1.0"] } });
+    let mock =
+        MockBravia::with_api_support(ApiSupport::from_json(&api_support.to_string()).unwrap())
+            .await;
+    for (api, calls) in [("actBrowserControl", 0), ("setTextUrl", 1)] {
+        Mock::given(method("POST"))
+            .and(path("/sony/browser"))
+            .and(body_partial_json(json!({ "method": api })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"result": [], "id": 1})))
+            .expect(calls)
+            .named(format!("{} POST", api))
+            .mount(mock.server())
+            .await;
+    }
+    let bravia = mock.client(AUTH).await.unwrap();
+
+    // Act
+    bravia
+        .app_control()
+        .open_url("http://example.com/", true)
+        .await
+        .unwrap();
+
+    // Nothing to assert, the page is loaded without starting the browser
+}
+
+#[test]
+fn test_app_uri() {
+    assert_eq!(
+        "localapp://webappruntime?manifest=https%3A%2F%2Fexample.com%2Fapp%3Fid%3D1",
+        AppUri::from_manifest("https://example.com/app?id=1")
+            .unwrap()
+            .to_string()
+    );
+    assert_eq!(
+        "localapp://webappruntime?auid=my%20app",
+        AppUri::from_auid("my app").unwrap().to_string()
+    );
+    assert_eq!(
+        "com.sony.dtv.com.netflix.ninja",
+        AppUri::from_package("com.sony.dtv.com.netflix.ninja")
+            .unwrap()
+            .to_string()
+    );
+    assert!(AppUri::from_url("ftp://example.com/").is_err());
+    assert!(AppUri::from_url("example.com").is_err());
+    assert!(AppUri::from_auid("").is_err());
+    assert!(AppUri::from_package("com.sony.dtv.app name").is_err());
+}
+
 #[tokio::test]
 async fn test_set_text_form() {
     // Arrange
@@ -204,6 +516,63 @@ async fn test_set_text_form() {
     // Nothing to assert, this API returns ()
 }
 
+#[tokio::test]
+async fn test_type_text() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let status_template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_application_status_list.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(body_partial_json(
+            json!({ "method": "getApplicationStatusList" }),
+        ))
+        .respond_with(status_template)
+        .up_to_n_times(1)
+        .named("getApplicationStatusList POST (keyboard hidden)")
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(body_partial_json(
+            json!({ "method": "getApplicationStatusList" }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [[{ "name": "textInput", "status": "on" }]],
+            "id": 55
+        })))
+        .named("getApplicationStatusList POST (keyboard shown)")
+        .mount(&mock_server)
+        .await;
+    let set_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/set_text_form_V1_0.json",
+        JSON_BASE_PATH
+    ));
+    let set_template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/set_text_form_V1_0.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(set_body)
+        .respond_with(set_template)
+        .expect(1)
+        .named("setTextForm POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+    let app_control = bravia.app_control();
+
+    // Act
+    let err = app_control.type_text("hello world!!").await.unwrap_err();
+    app_control.type_text("hello world!!").await.unwrap();
+
+    // Assert
+    assert!(matches!(err, Error::VerificationFailed(_)));
+}
+
 #[tokio::test]
 async fn test_terminate_apps() {
     // Arrange
@@ -230,3 +599,194 @@ async fn test_terminate_apps() {
 
     // Nothing to assert, this API returns ()
 }
+
+// Answers the text form APIs like the device, with the key sent in `encKey`.
+#[cfg(feature = "encryption")]
+struct EncryptedTextForm(rsa::RsaPrivateKey);
+
+#[cfg(feature = "encryption")]
+impl wiremock::Respond for EncryptedTextForm {
+    fn respond(&self, request: &wiremock::Request) -> ResponseTemplate {
+        use aes::cipher::{block_padding::Pkcs7, BlockDecryptMut, BlockEncryptMut, KeyIvInit};
+        use base64::{engine::general_purpose::STANDARD, Engine};
+
+        let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+        let params = &body["params"][0];
+        let enc_key = STANDARD.decode(params["encKey"].as_str().unwrap()).unwrap();
+        let common_key = self.0.decrypt(rsa::Pkcs1v15Encrypt, &enc_key).unwrap();
+        let (key, iv) = common_key.split_at(16);
+        let result = match body["method"].as_str().unwrap() {
+            "getTextForm" => {
+                let text = cbc::Encryptor::<aes::Aes128>::new(key.into(), iv.into())
+                    .encrypt_padded_vec_mut::<Pkcs7>("current text".as_bytes());
+                json!([{ "text": STANDARD.encode(text) }])
+            }
+            _ => {
+                let text = STANDARD.decode(params["text"].as_str().unwrap()).unwrap();
+                let text = cbc::Decryptor::<aes::Aes128>::new(key.into(), iv.into())
+                    .decrypt_padded_vec_mut::<Pkcs7>(&text)
+                    .unwrap();
+                assert_eq!("new text".as_bytes(), text);
+                json!([])
+            }
+        };
+        ResponseTemplate::new(200).set_body_json(json!({ "result": result, "id": body["id"] }))
+    }
+}
+
+#[cfg(feature = "encryption")]
+#[tokio::test]
+async fn test_text_form_encrypted() {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use rsa::pkcs8::EncodePublicKey;
+
+    // Arrange
+    let mock_server = wiremock::MockServer::start().await;
+    let mut services = Vec::new();
+    for base_path in [JSON_BASE_PATH, "sample_payloads/encryption"] {
+        let file = std::fs::read_to_string(format!("{}/supported_api_info.json", base_path));
+        let info: serde_json::Value = serde_json::from_str(&file.unwrap()).unwrap();
+        services.extend(info["result"][0].as_array().unwrap().clone());
+    }
+    Mock::given(method("POST"))
+        .and(path("/sony/guide"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [services],
+            "id": 5
+        })))
+        .named("getSupportedApiInfo POST")
+        .mount(&mock_server)
+        .await;
+    let private_key = rsa::RsaPrivateKey::new(&mut rand::thread_rng(), 1024).unwrap();
+    let public_key = private_key.to_public_key().to_public_key_der().unwrap();
+    Mock::given(method("POST"))
+        .and(path("/sony/encryption"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [{ "publicKey": STANDARD.encode(public_key.as_bytes()) }],
+            "id": 1
+        })))
+        .expect(3)
+        .named("getPublicKey POST")
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(body_partial_json(json!({ "version": "1.1" })))
+        .respond_with(EncryptedTextForm(private_key))
+        .expect(3)
+        .named("getTextForm/setTextForm POST")
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(body_partial_json(
+            json!({ "method": "getApplicationStatusList" }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [[{ "name": "textInput", "status": "on" }]],
+            "id": 55
+        })))
+        .named("getApplicationStatusList POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let text = bravia
+        .app_control()
+        .get_text_form_decrypted()
+        .await
+        .unwrap();
+    bravia
+        .app_control()
+        .set_text_form_encrypted("new text")
+        .await
+        .unwrap();
+    bravia.app_control().type_text("new text").await.unwrap();
+
+    // Assert
+    assert_eq!("current text", text);
+}
+
+#[cfg(feature = "encryption")]
+#[tokio::test]
+async fn test_encryption_session() {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use rsa::pkcs8::EncodePublicKey;
+
+    // Arrange
+    let mock_server = wiremock::MockServer::start().await;
+    let mut services = Vec::new();
+    for base_path in [JSON_BASE_PATH, "sample_payloads/encryption"] {
+        let file = std::fs::read_to_string(format!("{}/supported_api_info.json", base_path));
+        let info: serde_json::Value = serde_json::from_str(&file.unwrap()).unwrap();
+        services.extend(info["result"][0].as_array().unwrap().clone());
+    }
+    Mock::given(method("POST"))
+        .and(path("/sony/guide"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [services],
+            "id": 5
+        })))
+        .named("getSupportedApiInfo POST")
+        .mount(&mock_server)
+        .await;
+    let private_key = rsa::RsaPrivateKey::new(&mut rand::thread_rng(), 1024).unwrap();
+    let public_key = private_key.to_public_key().to_public_key_der().unwrap();
+    Mock::given(method("POST"))
+        .and(path("/sony/encryption"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [{ "publicKey": STANDARD.encode(public_key.as_bytes()) }],
+            "id": 1
+        })))
+        // Asked again only after the key mismatch
+        .expect(2)
+        .named("getPublicKey POST")
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .respond_with(EncryptedTextForm(private_key))
+        .expect(3)
+        .named("setTextForm POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+    let app_control = bravia.app_control();
+    let session = bravia.encryption().session();
+    let set_text = |cipher: bravia_api::encryption::Cipher| {
+        let app_control = &app_control;
+        async move {
+            app_control
+                .set_text_form(
+                    cipher.encrypt("new text"),
+                    Some(cipher.enc_key()),
+                    Some("1.1"),
+                )
+                .await
+        }
+    };
+
+    // Act
+    session.run(set_text).await.unwrap();
+    let first = session.cipher().await.unwrap();
+    session.run(set_text).await.unwrap();
+    let rotated = session.rotate_key().await.unwrap();
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "error": [40002, "Encryption Failed"],
+            "id": 601
+        })))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .named("setTextForm with a stale key POST")
+        .mount(&mock_server)
+        .await;
+    session.run(set_text).await.unwrap();
+    let renewed = session.cipher().await.unwrap();
+
+    // Assert
+    assert_ne!(first.enc_key(), rotated.enc_key());
+    assert_ne!(rotated.enc_key(), renewed.enc_key());
+}
@@ -1,10 +1,15 @@
 use crate::common::{server_setup, FromFile};
 use bravia_api::{
-    audio::{SoundSettings, SpeakerSettings},
-    Bravia,
+    audio::{
+        EqualizerBand, EqualizerCandidate, EqualizerSettings, OutputTerminal, SoundSettings,
+        SpeakerSettings, SubwooferLevel, TvPosition, VolumeChange, VolumeController,
+    },
+    ApiSupport, Bravia,
 };
+use serde_json::json;
+use std::time::{Duration, Instant};
 use wiremock::{
-    matchers::{method, path, BodyExactMatcher},
+    matchers::{body_partial_json, body_string_contains, method, path, BodyExactMatcher},
     Mock, ResponseTemplate,
 };
 
@@ -41,7 +46,10 @@ async fn test_get_sound_settings() {
         .unwrap();
 
     // Assert
-    assert_eq!("audioSystem", sound_settings.get(0).unwrap().value);
+    assert_eq!(
+        &SoundSettings::OutputTerminal(OutputTerminal::AudioSystem),
+        sound_settings.first().unwrap()
+    );
 }
 
 #[tokio::test]
@@ -73,7 +81,10 @@ async fn test_get_speaker_settings() {
         .unwrap();
 
     // Assert
-    assert_eq!("tableTop", speaker_settings.get(0).unwrap().value);
+    assert_eq!(
+        &SpeakerSettings::TvPosition(TvPosition::TableTop),
+        speaker_settings.first().unwrap()
+    );
 }
 
 #[tokio::test]
@@ -101,7 +112,7 @@ async fn test_get_volume_information() {
     let volume_information = bravia.audio().get_volume_information().await.unwrap();
 
     // Assert
-    assert_eq!(25, volume_information.get(0).unwrap().volume);
+    assert_eq!(25, volume_information.first().unwrap().volume);
 }
 
 #[tokio::test]
@@ -155,18 +166,43 @@ async fn test_set_audio_volume() {
     // Act
     bravia
         .audio()
-        .set_audio_volume(
-            Some("speaker".to_string()),
-            "5".to_string(),
-            Some("on".to_string()),
-            Some("1.2"),
-        )
+        .set_audio_volume(Some("speaker"), "5".to_string(), Some("on"), Some("1.2"))
         .await
         .unwrap();
 
     // Nothing to assert
 }
 
+#[test]
+fn test_speaker_settings_unknown() {
+    // Act
+    let settings: Vec<SpeakerSettings> = serde_json::from_str(
+        r#"[{"target": "subwooferLevel", "currentValue": "12"},
+            {"target": "subwooferFreq", "currentValue": "high"},
+            {"target": "surround", "currentValue": "on"}]"#,
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(
+        SpeakerSettings::SubwooferLevel(SubwooferLevel::new(12).unwrap()),
+        settings[0]
+    );
+    assert_eq!(
+        SpeakerSettings::Other {
+            target: String::from("subwooferFreq"),
+            value: String::from("high")
+        },
+        settings[1]
+    );
+    assert_eq!("surround", settings[2].target());
+    assert_eq!(
+        r#"{"target":"surround","value":"on"}"#,
+        serde_json::to_string(&settings[2]).unwrap()
+    );
+    assert!(SubwooferLevel::new(SubwooferLevel::MAX + 1).is_err());
+}
+
 #[tokio::test]
 async fn test_set_sound_settings() {
     // Arrange
@@ -189,7 +225,7 @@ async fn test_set_sound_settings() {
     let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
 
     // Act
-    let settings = SoundSettings::new("outputTerminal".into(), "speaker".into());
+    let settings = SoundSettings::OutputTerminal(OutputTerminal::Speaker);
     bravia
         .audio()
         .set_sound_settings(vec![settings])
@@ -221,7 +257,7 @@ async fn test_set_speaker_settings() {
     let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
 
     // Act
-    let settings = SpeakerSettings::new("tvPosition".into(), "wallMount".into());
+    let settings = SpeakerSettings::TvPosition(TvPosition::WallMount);
     bravia
         .audio()
         .set_speaker_settings(vec![settings])
@@ -230,3 +266,321 @@ async fn test_set_speaker_settings() {
 
     // Nothing to assert
 }
+
+#[tokio::test]
+async fn test_get_custom_equalizer_settings() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_custom_equalizer_settings.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_custom_equalizer_settings.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getCustomEqualizerSettings POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let settings = bravia
+        .audio()
+        .get_custom_equalizer_settings(None)
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(2, settings.len());
+    assert_eq!(EqualizerBand::Band10kHz, settings[1].target);
+    assert_eq!("3", settings[1].value);
+    assert_eq!(
+        vec![EqualizerCandidate {
+            min: -10,
+            max: 10,
+            step: 1
+        }],
+        settings[1].candidate
+    );
+}
+
+#[tokio::test]
+async fn test_set_custom_equalizer_settings() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/set_custom_equalizer_settings.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/set_custom_equalizer_settings.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("setCustomEqualizerSettings POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let settings = EqualizerSettings::new(EqualizerBand::Band330Hz, "-2");
+    bravia
+        .audio()
+        .set_custom_equalizer_settings(vec![settings])
+        .await
+        .unwrap();
+
+    // Nothing to assert
+}
+
+#[tokio::test]
+async fn test_volume_helpers() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    for (request, response, api) in [
+        (
+            "set_audio_volume_up",
+            "set_audio_volume_V1_2",
+            "setAudioVolume",
+        ),
+        (
+            "set_audio_volume_down",
+            "set_audio_volume_V1_2",
+            "setAudioVolume",
+        ),
+        (
+            "get_volume_information",
+            "get_volume_information",
+            "getVolumeInformation",
+        ),
+        ("set_audio_mute", "set_audio_mute", "setAudioMute"),
+    ] {
+        Mock::given(method("POST"))
+            .and(path(ENDPOINT_PATH))
+            .and(BodyExactMatcher::from_json_file(&format!(
+                "{}/requests/{}.json",
+                JSON_BASE_PATH, request
+            )))
+            .respond_with(ResponseTemplate::from_json_file(&format!(
+                "{}/responses/{}.json",
+                JSON_BASE_PATH, response
+            )))
+            .expect(1)
+            .named(format!("{} POST", api))
+            .mount(&mock_server)
+            .await;
+    }
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let audio = bravia.audio();
+    audio.volume_up(2).await.unwrap();
+    audio.volume_down(3).await.unwrap();
+    let mute = audio.toggle_mute().await.unwrap();
+
+    // Assert
+    assert!(mute);
+}
+
+#[tokio::test]
+async fn test_volume_controller() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(BodyExactMatcher::from_json_file(&format!(
+            "{}/requests/get_volume_information.json",
+            JSON_BASE_PATH
+        )))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/get_volume_information.json",
+            JSON_BASE_PATH
+        )))
+        .named("getVolumeInformation POST")
+        .mount(&mock_server)
+        .await;
+    for volume in ["15", "25"] {
+        Mock::given(method("POST"))
+            .and(path(ENDPOINT_PATH))
+            .and(body_partial_json(json!({
+                "method": "setAudioVolume",
+                "params": [{"target": "speaker", "volume": volume}]
+            })))
+            .respond_with(ResponseTemplate::from_json_file(&format!(
+                "{}/responses/set_audio_volume_V1_2.json",
+                JSON_BASE_PATH
+            )))
+            .expect(1)
+            .named("setAudioVolume POST")
+            .mount(&mock_server)
+            .await;
+    }
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let mut volume = VolumeController::new(bravia.audio());
+    volume.duck_to(15).await.unwrap();
+    let ducked = volume.is_ducked();
+    volume.restore().await.unwrap();
+    volume.restore().await.unwrap();
+
+    // Assert
+    assert!(ducked);
+    assert!(!volume.is_ducked());
+}
+
+#[tokio::test]
+async fn test_set_effective_volume_audio_system() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(BodyExactMatcher::from_json_file(&format!(
+            "{}/requests/get_sound_settings.json",
+            JSON_BASE_PATH
+        )))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/get_sound_settings.json",
+            JSON_BASE_PATH
+        )))
+        .named("getSoundSettings POST")
+        .mount(&mock_server)
+        .await;
+    for (code, presses) in [("AAAAAQAAAAEAAAASAw==", 2), ("AAAAAQAAAAEAAAAUAw==", 1)] {
+        Mock::given(method("POST"))
+            .and(path("/sony/ircc"))
+            .and(body_string_contains(format!(
+                "<IRCCCode>{}</IRCCCode>",
+                code
+            )))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(presses)
+            .named("X_SendIRCC POST")
+            .mount(&mock_server)
+            .await;
+    }
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(body_partial_json(json!({"method": "setAudioVolume"})))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .named("setAudioVolume POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let audio = bravia.audio();
+    let start = Instant::now();
+    audio
+        .set_effective_volume(VolumeChange::Up(2))
+        .await
+        .unwrap();
+    let elapsed = start.elapsed();
+    audio
+        .set_effective_volume(VolumeChange::ToggleMute)
+        .await
+        .unwrap();
+
+    // Assert
+    // The mocks expect the key presses, that must not be sent back to back
+    assert!(elapsed >= Duration::from_millis(300));
+}
+
+#[tokio::test]
+async fn test_set_effective_volume_speaker() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(body_partial_json(json!({"method": "getSoundSettings"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [[{"currentValue": "speaker", "target": "outputTerminal"}]],
+            "id": 73
+        })))
+        .named("getSoundSettings POST")
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(BodyExactMatcher::from_json_file(&format!(
+            "{}/requests/set_audio_volume_down.json",
+            JSON_BASE_PATH
+        )))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/set_audio_volume_V1_2.json",
+            JSON_BASE_PATH
+        )))
+        .expect(1)
+        .named("setAudioVolume POST")
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/sony/ircc"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .named("X_SendIRCC POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    bravia
+        .audio()
+        .set_effective_volume(VolumeChange::Down(3))
+        .await
+        .unwrap();
+
+    // Nothing to assert, the mock expects a single setAudioVolume request
+}
+
+#[tokio::test]
+async fn test_supported_notifications() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let supported = bravia
+        .supports_notification("audio", "notifyVolumeInformation", "1.0")
+        .await
+        .unwrap();
+    let unsupported_version = bravia
+        .supports_notification("audio", "notifyVolumeInformation", "9.9")
+        .await
+        .unwrap();
+    let unsupported_service = bravia
+        .supports_notification("system", "notifyPowerStatus", "1.0")
+        .await
+        .unwrap();
+    let notifications = bravia.supported_notifications("audio").await.unwrap();
+    let json = bravia
+        .cached_api_support()
+        .await
+        .unwrap()
+        .to_json()
+        .unwrap();
+    let api_support = ApiSupport::from_json(&json).unwrap();
+
+    // Assert
+    assert!(supported);
+    assert!(!unsupported_version);
+    assert!(!unsupported_service);
+    assert_eq!(vec!["notifyVolumeInformation"], notifications);
+    assert_eq!(
+        Some(&["1.0".to_string()][..]),
+        api_support.notification_versions("audio", "notifyVolumeInformation")
+    );
+    assert!(!bravia
+        .supports_notification("audio", "getVolumeInformation", "1.0")
+        .await
+        .unwrap());
+}
@@ -1,7 +1,16 @@
 use crate::common::{server_setup, FromFile};
-use bravia_api::{av_content::ExternalInputStatus, Bravia};
+use bravia_api::{
+    av_content::{
+        Content, ExternalInputStatus, InputUri, ParentalRatingSettings, TvContentVisibility,
+    },
+    error::Error,
+    Bravia,
+};
+use futures_util::StreamExt;
+use serde_json::json;
+use std::time::Duration;
 use wiremock::{
-    matchers::{method, path, BodyExactMatcher},
+    matchers::{body_partial_json, method, path, BodyExactMatcher},
     Mock, ResponseTemplate,
 };
 
@@ -9,6 +18,37 @@ const ENDPOINT_PATH: &str = "/sony/avContent";
 const JSON_BASE_PATH: &str = "sample_payloads/av_content";
 const AUTH: Option<&str> = Some("TEST");
 
+#[tokio::test]
+async fn test_delete_content() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/delete_content.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/delete_content.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("deleteContent POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    bravia
+        .av_content()
+        .delete_content("pvr:recording?id=5".to_string())
+        .await
+        .unwrap();
+
+    // Nothing to assert, this API returns ()
+}
+
 #[tokio::test]
 async fn test_content_count() {
     // Arrange
@@ -65,7 +105,7 @@ async fn test_get_content_list() {
     // Act
     let hdmi_list = bravia
         .av_content()
-        .get_content_list(Some("extInput:hdmi".to_string()), Some(0), Some(50))
+        .get_content_list(Some("extInput:hdmi"), Some(0), Some(50), None)
         .await
         .unwrap();
 
@@ -77,6 +117,143 @@ async fn test_get_content_list() {
     assert_ne! {3, hdmi_list.get(2).unwrap().index};
 }
 
+#[tokio::test]
+async fn test_get_content_list_tv_channels() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_content_list_tv.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_content_list_tv.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getContentList POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let channels = bravia
+        .av_content()
+        .get_content_list(Some("tv:dvbt"), Some(0), Some(2), Some(&["tv_digital"]))
+        .await
+        .unwrap();
+
+    // Assert
+    let sample_channel = Content {
+        uri: String::from("tv:dvbt?trip=9018.4161.1025&srvName=BBC ONE"),
+        title: Some(String::from("BBC ONE")),
+        index: 0,
+        disp_num: Some(String::from("001")),
+        original_disp_num: Some(String::from("001")),
+        triplet_str: Some(String::from("9018.4161.1025")),
+        program_media_type: Some(String::from("tv")),
+        direct_remote_num: Some(1),
+        is_protected: None,
+        is_already_played: None,
+    };
+    assert_eq!(sample_channel, channels[0]);
+    assert_eq!(Some(false), channels[1].is_protected);
+    assert_eq!(Some(true), channels[1].is_already_played);
+}
+
+#[tokio::test]
+async fn test_content_list_stream() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(body_partial_json(json!({
+            "method": "getContentList",
+            "params": [{"stIdx": 0, "cnt": 2, "uri": "tv:dvbt"}]
+        })))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/get_content_list_tv.json",
+            JSON_BASE_PATH
+        )))
+        .expect(1)
+        .named("getContentList POST")
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(body_partial_json(json!({
+            "method": "getContentList",
+            "params": [{"stIdx": 2, "cnt": 2, "uri": "tv:dvbt"}]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"result": [[]], "id": 88})))
+        .expect(1)
+        .named("getContentList POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let av_content = bravia.av_content();
+    let contents: Vec<_> = av_content.content_list_stream("tv:dvbt", 2).collect().await;
+
+    // Assert
+    let titles: Vec<_> = contents
+        .into_iter()
+        .map(|content| content.unwrap().title.unwrap())
+        .collect();
+    assert_eq!(vec!["BBC ONE", "BBC Radio 4"], titles);
+}
+
+#[tokio::test]
+async fn test_get_all_contents() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(body_partial_json(json!({
+            "method": "getContentList",
+            "params": [{"stIdx": 0, "cnt": 200, "uri": "tv:dvbt"}]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "error": [3, "Illegal Argument"],
+            "id": 88
+        })))
+        .expect(1)
+        .named("getContentList POST")
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(body_partial_json(json!({
+            "method": "getContentList",
+            "params": [{"stIdx": 0, "cnt": 100, "uri": "tv:dvbt"}]
+        })))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/get_content_list_tv.json",
+            JSON_BASE_PATH
+        )))
+        .expect(1)
+        .named("getContentList POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let mut pages = Vec::new();
+    let mut progress = |count| pages.push(count);
+    let contents = bravia
+        .av_content()
+        .get_all_contents("tv:dvbt", Some(&mut progress))
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(2, contents.len());
+    assert_eq!(vec![2], pages);
+}
+
 #[tokio::test]
 async fn test_get_current_external_input_status() {
     // Arrange
@@ -116,6 +293,80 @@ async fn test_get_current_external_input_status() {
     };
 
     assert_eq!(&hdmi, external_inputs_status.get(2).unwrap());
+    assert_eq!(Some(InputUri::Hdmi(1)), hdmi.input_uri());
+    // Not a valid input URI
+    assert_eq!(None, external_inputs_status.get(3).unwrap().input_uri());
+}
+
+#[test]
+fn test_input_uri() {
+    for uri in [
+        "extInput:component?port=1",
+        "extInput:composite?port=2",
+        "extInput:scart?port=1",
+        "extInput:widi?port=1",
+        "extInput:cec?type=recorder&port=2",
+    ] {
+        assert_eq!(uri, uri.parse::<InputUri>().unwrap().to_string());
+    }
+    assert_eq!(
+        Ok(InputUri::Cec {
+            kind: String::from("player"),
+            port: 3,
+            logical_addr: Some(4)
+        }),
+        "extInput:cec?port=3&logicalAddr=4&type=player"
+            .parse::<InputUri>()
+            .map_err(|err| err.to_string())
+    );
+    for uri in [
+        "tv:dvbt",
+        "extInput:hdmi",
+        "extInput:hdmi?port=x",
+        "extInput:cec?port=1",
+        "extInput:usb?port=1",
+    ] {
+        assert!(matches!(
+            uri.parse::<InputUri>(),
+            Err(Error::InvalidParameter(_))
+        ));
+    }
+}
+
+#[tokio::test]
+async fn test_get_current_external_input_status_v1_1() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_current_external_input_status_V1_1.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_current_external_input_status_V1_1.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getCurrentExternalInputsStatus POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let external_inputs_status = bravia
+        .av_content()
+        .get_current_external_input_status(Some("1.1"))
+        .await
+        .unwrap();
+
+    // Assert
+    let statuses: Vec<Option<bool>> = external_inputs_status
+        .iter()
+        .map(|input| input.status)
+        .collect();
+    assert_eq!(vec![Some(true), Some(false), None], statuses);
 }
 
 #[tokio::test]
@@ -143,7 +394,10 @@ async fn test_get_scheme_list() {
     let scheme_list = bravia.av_content().get_scheme_list().await.unwrap();
 
     // Assert
-    assert_eq!(vec!["extInput", "fav"], scheme_list);
+    let schemes: Vec<&str> = scheme_list.iter().map(|s| s.scheme.as_str()).collect();
+    assert_eq!(vec!["extInput", "fav"], schemes);
+    assert_eq!(Some(&json!(true)), scheme_list[0].extra.get("isBrowsable"));
+    assert!(scheme_list[1].extra.is_empty());
 }
 
 #[tokio::test]
@@ -175,6 +429,7 @@ async fn test_get_source_list() {
         .unwrap();
 
     // Assert
+    let sources: Vec<&str> = source_list.iter().map(|s| s.source.as_str()).collect();
     assert_eq!(
         vec![
             "extInput:hdmi",
@@ -182,8 +437,51 @@ async fn test_get_source_list() {
             "extInput:cec",
             "extInput:widi"
         ],
-        source_list
+        sources
     );
+    assert_eq!(Some(&json!(true)), source_list[0].extra.get("isBrowsable"));
+}
+
+#[tokio::test]
+async fn test_get_parental_rating_settings() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_parental_rating_settings.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_parental_rating_settings.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getParentalRatingSettings POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let settings = bravia
+        .av_content()
+        .get_parental_rating_settings()
+        .await
+        .unwrap();
+
+    // Assert
+    let sample_settings = ParentalRatingSettings {
+        rating_type_age: 14,
+        rating_type_sony: Some(String::new()),
+        rating_country: String::from("usa"),
+        rating_custom_type_tv: vec![String::from("TV-14"), String::from("TV-MA")],
+        rating_custom_type_mpaa: Some(String::from("R")),
+        rating_custom_type_ca_english: Some(String::new()),
+        rating_custom_type_ca_french: Some(String::new()),
+        unrated_lock: true,
+    };
+    assert_eq!(sample_settings, settings);
 }
 
 #[tokio::test]
@@ -218,6 +516,52 @@ async fn test_get_playing_content_info() {
     assert_eq!("HDMI 2", playing_content.title);
 }
 
+#[tokio::test]
+async fn test_get_playing_content_info_tv_channel() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_playing_content_info.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_playing_content_info_tv.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getPlayingContentInfo POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let playing_content = bravia
+        .av_content()
+        .get_playing_content_info()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        Some("News at Ten"),
+        playing_content.program_title.as_deref()
+    );
+    assert_eq!(Some("001"), playing_content.disp_num.as_deref());
+    assert_eq!(
+        Some("9018.4161.1025"),
+        playing_content.triplet_str.as_deref()
+    );
+    assert_eq!(Some(Duration::from_secs(1800)), playing_content.duration());
+    #[cfg(feature = "chrono")]
+    assert_eq!(
+        "2018-10-03T22:30:00+01:00",
+        playing_content.end_time().unwrap().to_rfc3339()
+    );
+}
+
 #[tokio::test]
 async fn test_set_play_content() {
     // Arrange
@@ -242,9 +586,156 @@ async fn test_set_play_content() {
     // Act
     bravia
         .av_content()
-        .set_play_content("extInput:hdmi?port=2".to_string())
+        .set_play_content(InputUri::Hdmi(2))
+        .await
+        .unwrap();
+
+    // Nothing to assert, this API returns ()
+}
+
+#[tokio::test]
+async fn test_select_input() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(BodyExactMatcher::from_json_file(&format!(
+            "{}/requests/get_playing_content_info.json",
+            JSON_BASE_PATH
+        )))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/get_playing_content_info.json",
+            JSON_BASE_PATH
+        )))
+        .named("getPlayingContentInfo POST")
+        .mount(&mock_server)
+        .await;
+    for uri in ["extInput:hdmi?port=2", "extInput:component?port=1"] {
+        Mock::given(method("POST"))
+            .and(path(ENDPOINT_PATH))
+            .and(body_partial_json(json!({
+                "method": "setPlayContent",
+                "params": [{"uri": uri}]
+            })))
+            .respond_with(ResponseTemplate::from_json_file(&format!(
+                "{}/responses/set_play_content.json",
+                JSON_BASE_PATH
+            )))
+            .expect(1)
+            .named("setPlayContent POST")
+            .mount(&mock_server)
+            .await;
+    }
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let av_content = bravia.av_content();
+    let hdmi = av_content.select_hdmi(2, true).await;
+    let component = av_content.select_component(1, true).await;
+
+    // Assert
+    assert!(hdmi.is_ok());
+    assert!(matches!(component, Err(Error::VerificationFailed(_))));
+}
+
+#[tokio::test]
+async fn test_set_tv_content_visibility() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/set_tv_content_visibility.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/set_tv_content_visibility.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("setTvContentVisibility POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+    let mut visibility =
+        TvContentVisibility::new("tv:dvbt?trip=9018.4161.1025&srvName=BBC ONE".to_string());
+    visibility.epg_visibility = Some("visible".to_string());
+    visibility.visibility = Some("invisible".to_string());
+
+    // Act
+    bravia
+        .av_content()
+        .set_tv_content_visibility(visibility)
         .await
         .unwrap();
 
     // Nothing to assert, this API returns ()
 }
+
+#[tokio::test]
+async fn test_channel_helpers() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(body_partial_json(json!({
+            "method": "getContentList",
+            "params": [{"stIdx": 0, "cnt": 50, "uri": "tv:dvbt"}]
+        })))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/get_content_list_tv.json",
+            JSON_BASE_PATH
+        )))
+        .expect(1)
+        .named("getContentList POST")
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(BodyExactMatcher::from_json_file(&format!(
+            "{}/requests/get_playing_content_info.json",
+            JSON_BASE_PATH
+        )))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/get_playing_content_info_tv.json",
+            JSON_BASE_PATH
+        )))
+        .named("getPlayingContentInfo POST")
+        .mount(&mock_server)
+        .await;
+    for (uri, calls) in [
+        ("tv:dvbt?trip=9018.4161.1097&srvName=BBC Radio 4", 3),
+        ("tv:dvbt?trip=9018.4161.1025&srvName=BBC ONE", 0),
+    ] {
+        Mock::given(method("POST"))
+            .and(path(ENDPOINT_PATH))
+            .and(body_partial_json(json!({
+                "method": "setPlayContent",
+                "params": [{"uri": uri}]
+            })))
+            .respond_with(ResponseTemplate::from_json_file(&format!(
+                "{}/responses/set_play_content.json",
+                JSON_BASE_PATH
+            )))
+            .expect(calls)
+            .named("setPlayContent POST")
+            .mount(&mock_server)
+            .await;
+    }
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let av_content = bravia.av_content();
+    let played = av_content.play_tv_channel("tv:dvbt", "704").await;
+    let missing = av_content.play_tv_channel("tv:dvbt", "99").await;
+    let next = av_content.next_channel().await;
+    let previous = av_content.previous_channel().await;
+
+    // Assert
+    assert!(played.is_ok());
+    assert!(matches!(missing, Err(Error::InvalidParameter(_))));
+    assert!(next.is_ok());
+    // With two channels the previous one is also the next one
+    assert!(previous.is_ok());
+}
@@ -0,0 +1,1565 @@
+use crate::common::{server_setup, FromFile};
+use bravia_api::{
+    credentials::Credentials,
+    error::{CallContext, Error},
+    health::HealthStatus,
+    metrics::ErrorKind,
+    profile::SettingsProfile,
+    recording::ScheduleRequest,
+    retry::{RetryPolicy, WakePolicy},
+    system::{LEDIndicatorStatus, LedMode, PowerSavingMode, PowerState},
+    test_util::MockBravia,
+    vcr::{Cassette, Vcr},
+    watch::ChangeEvent,
+    wol::send_magic_packet,
+    ApiSupport, Auth, Bravia, RequestOptions,
+};
+use serde_json::json;
+use std::time::{Duration, Instant};
+use wiremock::{
+    matchers::{body_partial_json, header, method, path, BodyExactMatcher},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ENDPOINT_PATH: &str = "/sony/system";
+const JSON_BASE_PATH: &str = "sample_payloads/system";
+const AUTH: &str = "TEST";
+
+#[tokio::test]
+async fn test_max_in_flight_requests() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_power_status.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_power_status.json",
+        JSON_BASE_PATH
+    ))
+    .set_delay(Duration::from_millis(200));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getPowerStatus POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::builder(&mock_server.uri())
+        .auth(AUTH)
+        .max_in_flight_requests(1)
+        .build()
+        .await
+        .unwrap();
+
+    // Act
+    let system = bravia.system();
+    let start = Instant::now();
+    let (first, second) = tokio::join!(system.get_power_status(), system.get_power_status());
+
+    // Assert
+    assert_eq!("standby", first.unwrap());
+    assert_eq!("standby", second.unwrap());
+    assert!(start.elapsed() >= Duration::from_millis(400));
+}
+
+#[tokio::test]
+async fn test_builder_port() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_power_status.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_power_status.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getPowerStatus POST")
+        .mount(&mock_server)
+        .await;
+    let address = mock_server.address();
+
+    // Act
+    let bravia = Bravia::builder(&address.ip().to_string())
+        .port(address.port())
+        .build()
+        .await
+        .unwrap();
+    let power_status = bravia.system().get_power_status().await.unwrap();
+
+    // Assert
+    assert_eq!("standby", power_status);
+}
+
+#[tokio::test]
+async fn test_builder_timeout() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_power_status.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_power_status.json",
+        JSON_BASE_PATH
+    ))
+    .set_delay(Duration::from_millis(500));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getPowerStatus POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::builder(&mock_server.uri())
+        .timeout(Duration::from_millis(100))
+        .build()
+        .await
+        .unwrap();
+
+    // Act
+    let power_status = bravia.system().get_power_status().await;
+
+    // Assert
+    match power_status {
+        Err(Error::NetworkError(err)) => assert!(err.is_timeout()),
+        other => panic!("expected a timeout, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_with_client() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_power_status.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_power_status.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(header("user-agent", "bravia-test"))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getPowerStatus POST")
+        .mount(&mock_server)
+        .await;
+    let client = reqwest::Client::builder()
+        .user_agent("bravia-test")
+        .build()
+        .unwrap();
+    let bravia = Bravia::with_client(&mock_server.uri(), Some(AUTH), client)
+        .await
+        .unwrap();
+
+    // Act
+    let power_status = bravia.system().get_power_status().await.unwrap();
+
+    // Assert
+    assert_eq!("standby", power_status);
+}
+
+#[tokio::test]
+async fn test_builder_proxy() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_power_status.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_power_status.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(header("proxy-authorization", "Basic dXNlcjpwYXNz"))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getPowerStatus POST")
+        .mount(&mock_server)
+        .await;
+    // The mock server acts as the proxy, so the display address doesn't need to exist
+    let bravia = Bravia::builder("http://bravia.invalid")
+        .proxy(&mock_server.uri())
+        .proxy_basic_auth("user", "pass")
+        .build()
+        .await
+        .unwrap();
+
+    // Act
+    let power_status = bravia.system().get_power_status().await.unwrap();
+
+    // Assert
+    assert_eq!("standby", power_status);
+}
+
+#[tokio::test]
+async fn test_retry_policy() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_power_status.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_power_status.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(2)
+        .named("getPowerStatus POST failure")
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getPowerStatus POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::builder(&mock_server.uri())
+        .retry_policy(RetryPolicy {
+            initial_backoff: Duration::from_millis(10),
+            jitter: false,
+            ..Default::default()
+        })
+        .build()
+        .await
+        .unwrap();
+
+    // Act
+    let power_status = bravia.system().get_power_status().await.unwrap();
+
+    // Assert
+    assert_eq!("standby", power_status);
+}
+
+#[tokio::test]
+async fn test_retry_policy_opt_out() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .named("requestReboot POST failure")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::builder(&mock_server.uri())
+        .auth(AUTH)
+        .retry_policy(RetryPolicy {
+            initial_backoff: Duration::from_millis(10),
+            ..Default::default()
+        })
+        .build()
+        .await
+        .unwrap();
+
+    // Act
+    let reboot = bravia.system().request_reboot().await;
+
+    // Assert
+    assert!(matches!(reboot, Err(Error::BadStatus(..))));
+}
+
+#[tokio::test]
+async fn test_retry_policy_non_idempotent_calls() {
+    // Arrange
+    let mock = MockBravia::start().await;
+    for (service, api, calls) in [
+        ("recording", "addSchedule", 1),
+        ("recording", "deleteSchedule", 1),
+        ("avContent", "deleteContent", 1),
+        ("audio", "setAudioVolume", 3),
+    ] {
+        Mock::given(method("POST"))
+            .and(path(format!("/sony/{service}")))
+            .and(body_partial_json(json!({ "method": api })))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(calls)
+            .named(format!("{service}.{api} POST failure"))
+            .mount(mock.server())
+            .await;
+    }
+    let bravia = Bravia::builder(&mock.uri())
+        .auth(AUTH)
+        .retry_policy(RetryPolicy {
+            initial_backoff: Duration::from_millis(10),
+            max_attempts: 2,
+            ..Default::default()
+        })
+        .build()
+        .await
+        .unwrap();
+
+    // Act
+    let schedule = ScheduleRequest::new(
+        "tv:dvbt?trip=9018.1025.1089",
+        "2018-10-03T20:00:00+0100",
+        60,
+    );
+    let add = bravia.recording().add_schedule(&schedule).await;
+    let delete = bravia.recording().delete_schedule("1").await;
+    let delete_content = bravia.av_content().delete_content("tv:dvbt?trip=1").await;
+    // Only the absolute level is sent again
+    let relative = bravia.audio().volume_up(2).await;
+    let absolute = bravia
+        .audio()
+        .set_audio_volume(Some("speaker"), "10", None, None)
+        .await;
+
+    // Assert
+    for result in [add.map(|_| ()), delete, delete_content, relative, absolute] {
+        assert!(matches!(result, Err(Error::BadStatus(..))));
+    }
+}
+
+#[tokio::test]
+async fn test_rate_limit() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_power_status.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_power_status.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getPowerStatus POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::builder(&mock_server.uri())
+        .rate_limit(1, Duration::from_millis(200))
+        .build()
+        .await
+        .unwrap();
+    // Waits for the bucket used by the supported API probe to be refilled
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    // Act
+    let start = Instant::now();
+    for _ in 0..3 {
+        bravia.system().get_power_status().await.unwrap();
+    }
+
+    // Assert
+    assert!(start.elapsed() >= Duration::from_millis(400));
+}
+
+#[tokio::test]
+async fn test_service_timeout() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_power_status.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_power_status.json",
+        JSON_BASE_PATH
+    ))
+    .set_delay(Duration::from_millis(300));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getPowerStatus POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::builder(&mock_server.uri())
+        .timeout(Duration::from_secs(5))
+        .build()
+        .await
+        .unwrap();
+
+    // Act
+    let short = bravia
+        .system()
+        .with_timeout(Duration::from_millis(100))
+        .get_power_status()
+        .await;
+    let long = bravia.system().get_power_status().await;
+
+    // Assert
+    match short {
+        Err(Error::NetworkError(err)) => assert!(err.is_timeout()),
+        other => panic!("expected a timeout, got {:?}", other),
+    }
+    assert_eq!("standby", long.unwrap());
+}
+
+#[tokio::test]
+async fn test_service_retry_policy() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .named("getPowerStatus POST failure")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::builder(&mock_server.uri())
+        .retry_policy(RetryPolicy {
+            initial_backoff: Duration::from_millis(10),
+            ..Default::default()
+        })
+        .build()
+        .await
+        .unwrap();
+
+    // Act
+    let power_status = bravia
+        .system()
+        .with_options(RequestOptions {
+            retry_policy: Some(RetryPolicy::none()),
+            ..Default::default()
+        })
+        .get_power_status()
+        .await;
+
+    // Assert
+    assert!(matches!(power_status, Err(Error::BadStatus(..))));
+}
+
+#[tokio::test]
+async fn test_new_unchecked() {
+    // Arrange
+    // Nothing is listening on this port
+    let unreachable = Bravia::new_unchecked("http://127.0.0.1:9", Some(AUTH));
+    let mock_server = MockServer::start().await;
+    let bravia = Bravia::new_unchecked(&mock_server.uri(), Some(AUTH)).unwrap();
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_power_status.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_power_status.json",
+        JSON_BASE_PATH
+    ));
+    let supported_api_info =
+        ResponseTemplate::from_json_file(&format!("{}/supported_api_info.json", JSON_BASE_PATH));
+    Mock::given(method("POST"))
+        .and(path("/sony/guide"))
+        .respond_with(supported_api_info)
+        .expect(1)
+        .named("getSupportedApiInfo POST")
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getPowerStatus POST")
+        .mount(&mock_server)
+        .await;
+
+    // Act
+    let system = bravia.system();
+    let request = system.get_power_status();
+    assert_send(&request);
+    let first = request.await.unwrap();
+    let second = bravia.system().get_power_status().await.unwrap();
+
+    // Assert
+    assert!(unreachable.is_ok());
+    assert_eq!("standby", first);
+    assert_eq!("standby", second);
+}
+
+fn assert_send<T: Send>(_: &T) {}
+
+#[tokio::test]
+async fn test_cached_api_support() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    let supported_api_info =
+        ResponseTemplate::from_json_file(&format!("{}/supported_api_info.json", JSON_BASE_PATH));
+    Mock::given(method("POST"))
+        .and(path("/sony/guide"))
+        .respond_with(supported_api_info)
+        // Only asked by the first client and by fetch_api_support
+        .expect(2)
+        .named("getSupportedApiInfo POST")
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(BodyExactMatcher::from_json_file(&format!(
+            "{}/requests/get_power_status.json",
+            JSON_BASE_PATH
+        )))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/get_power_status.json",
+            JSON_BASE_PATH
+        )))
+        .named("getPowerStatus POST")
+        .mount(&mock_server)
+        .await;
+    let first = Bravia::new(&mock_server.uri(), Some(AUTH)).await.unwrap();
+    let json = first.cached_api_support().await.unwrap().to_json().unwrap();
+
+    // Act
+    let api_support = ApiSupport::from_json(&json).unwrap();
+    let bravia =
+        Bravia::with_cached_api_support(&mock_server.uri(), Some(AUTH), api_support.clone())
+            .unwrap();
+    let status = bravia.system().get_power_status().await.unwrap();
+    let refreshed = bravia.fetch_api_support().await.unwrap();
+
+    // Assert
+    assert_eq!("standby", status);
+    assert_eq!(api_support, refreshed);
+    assert!(api_support
+        .versions("system", "getPowerStatus")
+        .is_some_and(|versions| versions.iter().any(|version| version == "1.0")));
+    assert_eq!(None, api_support.versions("system", "notAnApi"));
+}
+
+#[tokio::test]
+async fn test_supports() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let bravia = Bravia::new(&mock_server.uri(), Some(AUTH)).await.unwrap();
+
+    // Act
+    let supported = bravia
+        .supports("system", "getCurrentTime", "1.1")
+        .await
+        .unwrap();
+    let unsupported_version = bravia
+        .supports("system", "getCurrentTime", "9.9")
+        .await
+        .unwrap();
+    let unsupported_api = bravia.supports("system", "notAnApi", "1.0").await.unwrap();
+    let versions = bravia
+        .supported_versions("system", "getCurrentTime")
+        .await
+        .unwrap()
+        .unwrap();
+    let services = bravia.supported_services().await.unwrap();
+
+    // Assert
+    assert!(supported);
+    assert!(!unsupported_version);
+    assert!(!unsupported_api);
+    assert!(versions.contains(&"1.0".to_string()));
+    assert_eq!(vec!["system"], services);
+}
+
+#[tokio::test]
+async fn test_raw_call() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body =
+        BodyExactMatcher::from_json_file(&format!("{}/requests/raw_call.json", JSON_BASE_PATH));
+    let template =
+        ResponseTemplate::from_json_file(&format!("{}/responses/raw_call.json", JSON_BASE_PATH));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(header("X-Auth-PSK", AUTH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getRemoteDeviceSettings POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), Some(AUTH)).await.unwrap();
+
+    // Act
+    let result = bravia
+        .raw_call(
+            "system",
+            "getRemoteDeviceSettings",
+            "1.0",
+            json!({"target": "accessPermission"}),
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        json!([[{"currentValue": "on", "target": "accessPermission"}]]),
+        result
+    );
+}
+
+#[tokio::test]
+async fn test_from_credentials() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body =
+        BodyExactMatcher::from_json_file(&format!("{}/requests/raw_call.json", JSON_BASE_PATH));
+    let template =
+        ResponseTemplate::from_json_file(&format!("{}/responses/raw_call.json", JSON_BASE_PATH));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(header("Cookie", "auth=ABCDEF"))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getRemoteDeviceSettings POST")
+        .mount(&mock_server)
+        .await;
+    let json = Credentials::new(
+        &mock_server.uri(),
+        Some(Auth::Cookie(String::from("ABCDEF"))),
+    )
+    .device_id("test:1234")
+    .to_json()
+    .unwrap();
+
+    // Act
+    let credentials = Credentials::from_json(&json).unwrap();
+    let bravia = Bravia::from_credentials(&credentials).await.unwrap();
+    let result = bravia
+        .raw_call(
+            "system",
+            "getRemoteDeviceSettings",
+            "1.0",
+            json!({"target": "accessPermission"}),
+        )
+        .await;
+
+    // Assert
+    assert_eq!(credentials.device_id.as_deref(), Some("test:1234"));
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_error_call_context() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "error": [40005, "Display Is Turned off"],
+            "id": 50
+        })))
+        .named("getPowerStatus POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), None).await.unwrap();
+
+    // Act
+    let err = bravia.system().get_power_status().await.unwrap_err();
+
+    // Assert
+    assert!(matches!(err, Error::BraviaError(..)));
+    assert_eq!(
+        err.call_context(),
+        Some(&CallContext {
+            endpoint: String::from("system"),
+            method: String::from("getPowerStatus"),
+            version: String::from("1.0"),
+        })
+    );
+    assert!(err.to_string().contains("system.getPowerStatus v1.0"));
+}
+
+#[tokio::test]
+async fn test_result_extraction() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let responses = [
+        // Extra fields and elements are skipped
+        json!({
+            "id": 50,
+            "result": [{"standbyDetail": "", "status": "active"}, {"status": "standby"}]
+        }),
+        json!({"result": [{"standbyDetail": ""}], "id": 50}),
+        json!({"result": [], "id": 50}),
+        json!({"result": [{"status": 1}], "id": 50}),
+        json!({"id": 50}),
+    ];
+    for response in responses {
+        Mock::given(method("POST"))
+            .and(path(ENDPOINT_PATH))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response))
+            .up_to_n_times(1)
+            .named("getPowerStatus POST")
+            .mount(&mock_server)
+            .await;
+    }
+    let bravia = Bravia::new(&mock_server.uri(), None).await.unwrap();
+    let system = bravia.system();
+
+    // Act
+    let status = system.get_power_status().await;
+    let missing_field = system.get_power_status().await;
+    let missing_element = system.get_power_status().await;
+    let wrong_type = system.get_power_status().await;
+    let no_result = system.get_power_status().await;
+
+    // Assert
+    assert_eq!("active", status.unwrap());
+    assert!(matches!(missing_field, Err(Error::MissingValue(_))));
+    assert!(matches!(missing_element, Err(Error::MissingValue(_))));
+    assert!(matches!(wrong_type, Err(Error::UnexpectedPayload(..))));
+    assert!(matches!(no_result, Err(Error::InvalidResponse(_))));
+}
+
+#[tokio::test]
+async fn test_metrics() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/get_power_status.json",
+            JSON_BASE_PATH
+        )))
+        .up_to_n_times(1)
+        .named("getPowerStatus POST")
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "error": [40005, "Display Is Turned off"],
+            "id": 50
+        })))
+        .named("getPowerStatus POST (error)")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), None).await.unwrap();
+    bravia.reset_metrics();
+
+    // Act
+    bravia.system().get_power_status().await.unwrap();
+    let clone = bravia.clone();
+    clone.system().get_power_status().await.unwrap_err();
+    let metrics = bravia.metrics();
+    bravia.reset_metrics();
+
+    // Assert
+    assert_eq!(2, metrics.requests);
+    assert_eq!(1, metrics.error_count());
+    assert_eq!(Some(&1), metrics.errors.get(&ErrorKind::Api));
+    let stats = &metrics.methods[&(String::from("system"), String::from("getPowerStatus"))];
+    assert_eq!(2, stats.requests);
+    assert_eq!(1, stats.errors);
+    assert_eq!(2, stats.latency.count());
+    assert_eq!(
+        2,
+        stats.latency.buckets().map(|(_, count)| count).sum::<u64>()
+    );
+    assert!(stats.latency.mean().is_some());
+    assert_eq!(0, bravia.metrics().requests);
+}
+
+#[tokio::test]
+async fn test_wake_policy() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let socket = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+    socket
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+    let power_status_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_power_status.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(BodyExactMatcher::from_json_file(&format!(
+            "{}/requests/get_system_supported_function.json",
+            JSON_BASE_PATH
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [[{"option": "WOL", "value": "FC:F1:52:00:11:22"}]],
+            "id": 55
+        })))
+        .expect(1)
+        .named("getSystemSupportedFunction POST")
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(BodyExactMatcher::from_json_file(&format!(
+            "{}/requests/get_led_indicator_status.json",
+            JSON_BASE_PATH
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "error": [40005, "Display Is Turned off"],
+            "id": 45
+        })))
+        .up_to_n_times(1)
+        .named("getLEDIndicatorStatus POST (standby)")
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(BodyExactMatcher::from_json_file(&format!(
+            "{}/requests/get_led_indicator_status.json",
+            JSON_BASE_PATH
+        )))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/get_led_indicator_status.json",
+            JSON_BASE_PATH
+        )))
+        .named("getLEDIndicatorStatus POST")
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(power_status_body)
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [{"status": "active"}],
+            "id": 50
+        })))
+        .named("getPowerStatus POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::builder(&mock_server.uri())
+        .auth(AUTH)
+        .wake_policy(WakePolicy {
+            broadcast_address: socket.local_addr().unwrap(),
+            poll_interval: Duration::from_millis(10),
+            ..Default::default()
+        })
+        .build()
+        .await
+        .unwrap();
+
+    // Act
+    let led_status = bravia.system().get_led_indicator_status().await;
+    let mut packet = [0; 256];
+    let len = socket.recv(&mut packet).unwrap();
+
+    // Assert
+    assert!(led_status.is_ok());
+    assert_eq!(len, 102);
+    assert_eq!(packet[..6], [0xFF; 6]);
+    assert_eq!(packet[96..102], [0xFC, 0xF1, 0x52, 0x00, 0x11, 0x22]);
+}
+
+#[tokio::test]
+async fn test_set_address_and_auth() {
+    // Arrange
+    let old_server = server_setup(JSON_BASE_PATH).await;
+    let new_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_led_indicator_status.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_led_indicator_status.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(header("X-Auth-PSK", "NEW"))
+        .and(expected_body)
+        .respond_with(template)
+        .expect(1)
+        .named("getLEDIndicatorStatus POST")
+        .mount(&new_server)
+        .await;
+    let mut bravia = Bravia::new(&old_server.uri(), Some(AUTH)).await.unwrap();
+    // Owns a clone created before the change
+    let system = bravia.system();
+
+    // Act
+    bravia.set_address(&new_server.uri()).unwrap();
+    bravia.set_auth(Some(Auth::Psk(String::from("NEW"))));
+    let led_status = system.get_led_indicator_status().await;
+
+    // Assert
+    assert!(led_status.is_ok());
+    assert!(bravia.set_address("http://[invalid").is_err());
+    let requests = new_server.received_requests().await.unwrap();
+    assert!(requests
+        .iter()
+        .any(|request| request.url.path() == "/sony/guide"));
+}
+
+#[tokio::test]
+async fn test_ping() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_power_status.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_power_status.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getPowerStatus POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), None).await.unwrap();
+    let unreachable = Bravia::new_unchecked("127.0.0.1:9", None).unwrap();
+
+    // Act
+    let health = bravia.ping().await;
+    let unreachable_health = unreachable.ping().await;
+
+    // Assert
+    assert!(health.reachable);
+    assert_eq!(health.power.as_deref(), Some("standby"));
+    assert!(health.latency.is_some());
+    assert_eq!(
+        unreachable_health,
+        HealthStatus {
+            reachable: false,
+            power: None,
+            latency: None,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_power_state() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(BodyExactMatcher::from_json_file(&format!(
+            "{}/requests/get_power_status.json",
+            JSON_BASE_PATH
+        )))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/get_power_status.json",
+            JSON_BASE_PATH
+        )))
+        .named("getPowerStatus POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), None).await.unwrap();
+
+    // Act
+    let is_on = bravia.is_on().await.unwrap();
+    let standby = bravia
+        .await_power_state(PowerState::Standby, Duration::from_secs(1))
+        .await;
+    let active = bravia
+        .await_power_state(PowerState::Active, Duration::from_millis(600))
+        .await;
+
+    // Assert
+    assert!(!is_on);
+    assert!(standby.is_ok());
+    assert!(matches!(active, Err(Error::VerificationFailed(_))));
+}
+
+#[tokio::test]
+async fn test_power_state_hanging_request() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(body_partial_json(json!({"method": "getPowerStatus"})))
+        .respond_with(
+            ResponseTemplate::from_json_file(&format!(
+                "{}/responses/get_power_status.json",
+                JSON_BASE_PATH
+            ))
+            .set_delay(Duration::from_secs(5)),
+        )
+        .named("getPowerStatus POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), None).await.unwrap();
+
+    // Act
+    let start = Instant::now();
+    let result = bravia
+        .await_power_state(PowerState::Standby, Duration::from_millis(300))
+        .await;
+
+    // Assert
+    assert!(start.elapsed() < Duration::from_secs(2));
+    assert!(matches!(
+        result,
+        Err(Error::VerificationFailed(message)) if message.contains("last error")
+    ));
+}
+
+#[tokio::test]
+async fn test_device_info() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    for api in ["get_interface_information", "get_system_information"] {
+        Mock::given(method("POST"))
+            .and(path(ENDPOINT_PATH))
+            .and(BodyExactMatcher::from_json_file(&format!(
+                "{}/requests/{}.json",
+                JSON_BASE_PATH, api
+            )))
+            .respond_with(ResponseTemplate::from_json_file(&format!(
+                "{}/responses/{}.json",
+                JSON_BASE_PATH, api
+            )))
+            // Read only once, even by the clones
+            .expect(1)
+            .named(api)
+            .mount(&mock_server)
+            .await;
+    }
+    let bravia = Bravia::new(&mock_server.uri(), Some(AUTH)).await.unwrap();
+    let clone = bravia.clone();
+
+    // Act
+    let info = bravia.device_info().await.unwrap();
+    let cached = clone.device_info().await.unwrap();
+
+    // Assert
+    assert_eq!(info, cached);
+    assert!(!info.interface.model_name.is_empty());
+    assert!(!info.system.model.is_empty());
+}
+
+#[tokio::test]
+async fn test_snapshot() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    let mut services = Vec::new();
+    for base_path in [
+        JSON_BASE_PATH,
+        "sample_payloads/audio",
+        "sample_payloads/av_content",
+    ] {
+        let file = std::fs::read_to_string(format!("{}/supported_api_info.json", base_path));
+        let info: serde_json::Value = serde_json::from_str(&file.unwrap()).unwrap();
+        services.extend(info["result"][0].as_array().unwrap().clone());
+    }
+    Mock::given(method("POST"))
+        .and(path("/sony/guide"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [services],
+            "id": 5
+        })))
+        .named("getSupportedApiInfo POST")
+        .mount(&mock_server)
+        .await;
+    for (endpoint, base_path, name) in [
+        ("system", JSON_BASE_PATH, "get_power_status"),
+        ("audio", "sample_payloads/audio", "get_volume_information"),
+        (
+            "avContent",
+            "sample_payloads/av_content",
+            "get_current_external_input_status_V1_0",
+        ),
+    ] {
+        Mock::given(method("POST"))
+            .and(path(format!("/sony/{}", endpoint)))
+            .and(BodyExactMatcher::from_json_file(&format!(
+                "{}/requests/{}.json",
+                base_path, name
+            )))
+            .respond_with(ResponseTemplate::from_json_file(&format!(
+                "{}/responses/{}.json",
+                base_path, name
+            )))
+            .named(name)
+            .mount(&mock_server)
+            .await;
+    }
+    Mock::given(method("POST"))
+        .and(path("/sony/avContent"))
+        .and(BodyExactMatcher::from_json_file(
+            "sample_payloads/av_content/requests/get_playing_content_info.json",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "error": [7, "Illegal State"],
+            "id": 103
+        })))
+        .named("getPlayingContentInfo POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), Some(AUTH)).await.unwrap();
+
+    // Act
+    let snapshot = bravia.snapshot().await.unwrap();
+
+    // Assert
+    assert_eq!(snapshot.power, "standby");
+    assert!(!snapshot.volume.is_empty());
+    assert_eq!(snapshot.playing_content, None);
+    assert!(!snapshot.external_inputs.is_empty());
+}
+
+#[tokio::test]
+async fn test_spawned_service_handle() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_power_status.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_power_status.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .expect(2)
+        .named("getPowerStatus POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), None).await.unwrap();
+
+    // Act
+    let system = bravia.system();
+    let cloned = bravia.clone();
+    let handles = [
+        tokio::spawn(async move { system.get_power_status().await }),
+        tokio::spawn(async move { cloned.system().get_power_status().await }),
+    ];
+    let mut power_statuses = Vec::new();
+    for handle in handles {
+        power_statuses.push(handle.await.unwrap().unwrap());
+    }
+
+    // Assert
+    assert_eq!(power_statuses, vec!["standby", "standby"]);
+    // The supported API cache is shared by the clones
+    let requests = mock_server.received_requests().await.unwrap();
+    assert_eq!(
+        requests
+            .iter()
+            .filter(|request| request.url.path() == "/sony/guide")
+            .count(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn test_watch() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let request_path = format!("{}/requests/get_power_status.json", JSON_BASE_PATH);
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(BodyExactMatcher::from_json_file(&request_path))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/get_power_status.json",
+            JSON_BASE_PATH
+        )))
+        .up_to_n_times(2)
+        .named("getPowerStatus POST (standby)")
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(BodyExactMatcher::from_json_file(&request_path))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [{"status": "active"}],
+            "id": 50
+        })))
+        .named("getPowerStatus POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), None).await.unwrap();
+    let watcher = bravia
+        .watch(Duration::from_millis(10))
+        .volume(false)
+        .playing_content(false);
+    let mut events = watcher.subscribe();
+
+    // Act
+    let handle = tokio::spawn(async move { watcher.run().await });
+    let first = events.recv().await.unwrap();
+    let second = events.recv().await.unwrap();
+    drop(events);
+    handle.await.unwrap();
+
+    // Assert
+    assert_eq!(first, ChangeEvent::PowerStatus(String::from("standby")));
+    assert_eq!(second, ChangeEvent::PowerStatus(String::from("active")));
+}
+
+#[tokio::test]
+async fn test_wake() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let socket = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+    socket
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(BodyExactMatcher::from_json_file(&format!(
+            "{}/requests/get_system_supported_function.json",
+            JSON_BASE_PATH
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [[{"option": "WOL", "value": "fc-f1-52-00-11-22"}]],
+            "id": 55
+        })))
+        .expect(1)
+        .named("getSystemSupportedFunction POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::builder(&mock_server.uri())
+        .wake_policy(WakePolicy {
+            broadcast_address: socket.local_addr().unwrap(),
+            ..Default::default()
+        })
+        .build()
+        .await
+        .unwrap();
+
+    // Act
+    bravia.wake().await.unwrap();
+    bravia.wake().await.unwrap();
+    let mut packet = [0; 256];
+    let len = socket.recv(&mut packet).unwrap();
+    let invalid = send_magic_packet("FC:F1:52:00:11", socket.local_addr().unwrap());
+
+    // Assert
+    assert_eq!(len, 102);
+    assert_eq!(packet[..6], [0xFF; 6]);
+    assert_eq!(packet[6..12], [0xFC, 0xF1, 0x52, 0x00, 0x11, 0x22]);
+    assert!(matches!(invalid, Err(Error::InvalidParameter(_))));
+}
+
+#[tokio::test]
+async fn test_settings_profile() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    let mut services = Vec::new();
+    for base_path in [
+        JSON_BASE_PATH,
+        "sample_payloads/audio",
+        "sample_payloads/video",
+    ] {
+        let file = std::fs::read_to_string(format!("{}/supported_api_info.json", base_path));
+        let info: serde_json::Value = serde_json::from_str(&file.unwrap()).unwrap();
+        services.extend(info["result"][0].as_array().unwrap().clone());
+    }
+    Mock::given(method("POST"))
+        .and(path("/sony/guide"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [services],
+            "id": 5
+        })))
+        .named("getSupportedApiInfo POST")
+        .mount(&mock_server)
+        .await;
+    for (endpoint, base_path, api, name, calls) in [
+        (
+            "system",
+            JSON_BASE_PATH,
+            "getPowerSavingMode",
+            "get_power_saving_mode",
+            1,
+        ),
+        (
+            "system",
+            JSON_BASE_PATH,
+            "getLEDIndicatorStatus",
+            "get_led_indicator_status",
+            1,
+        ),
+        (
+            "system",
+            JSON_BASE_PATH,
+            "setPowerSavingMode",
+            "set_power_saving_mode",
+            1,
+        ),
+        (
+            "system",
+            JSON_BASE_PATH,
+            "setLEDIndicatorStatus",
+            "set_led_indicator_status_V1_1",
+            1,
+        ),
+        (
+            "audio",
+            "sample_payloads/audio",
+            "getSoundSettings",
+            "get_sound_settings",
+            1,
+        ),
+        (
+            "audio",
+            "sample_payloads/audio",
+            "setSoundSettings",
+            "set_sound_settings",
+            1,
+        ),
+        (
+            "audio",
+            "sample_payloads/audio",
+            "setSpeakerSettings",
+            "set_speaker_settings",
+            0,
+        ),
+        (
+            "video",
+            "sample_payloads/video",
+            "getPictureQualitySettings",
+            "get_picture_quality_settings",
+            1,
+        ),
+        (
+            "video",
+            "sample_payloads/video",
+            "setPictureQualitySettings",
+            "set_picture_quality_settings",
+            1,
+        ),
+    ] {
+        Mock::given(method("POST"))
+            .and(path(format!("/sony/{}", endpoint)))
+            .and(body_partial_json(json!({ "method": api })))
+            .respond_with(ResponseTemplate::from_json_file(&format!(
+                "{}/responses/{}.json",
+                base_path, name
+            )))
+            .expect(calls)
+            .named(api)
+            .mount(&mock_server)
+            .await;
+    }
+    Mock::given(method("POST"))
+        .and(path("/sony/audio"))
+        .and(body_partial_json(json!({ "method": "getSpeakerSettings" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "error": [12, "getSpeakerSettings"],
+            "id": 67
+        })))
+        .named("getSpeakerSettings POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), Some(AUTH)).await.unwrap();
+
+    // Act
+    let profile = bravia.settings_profile().await.unwrap();
+    let json = profile.to_json().unwrap();
+    let result = bravia
+        .apply_settings_profile(&SettingsProfile::from_json(&json).unwrap())
+        .await;
+
+    // Assert
+    assert_eq!(profile.picture_quality.len(), 1);
+    assert_eq!(profile.picture_quality[0].value.as_deref(), Some("2"));
+    assert_eq!(profile.sound[0].value(), "audioSystem");
+    assert!(profile.speaker.is_empty());
+    assert_eq!(profile.power_saving_mode, Some(PowerSavingMode::High));
+    assert_eq!(profile.led_indicator.unwrap().mode, LedMode::Demo);
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_settings_profile_errors() {
+    // Arrange
+    let api_support = json!({
+        "system": {
+            "getPowerSavingMode": ["1.0"],
+            "getLEDIndicatorStatus": ["1.0"],
+            "setLEDIndicatorStatus": ["1.0"]
+        }
+    });
+    let mock =
+        MockBravia::with_api_support(ApiSupport::from_json(&api_support.to_string()).unwrap())
+            .await;
+    mock.stub_error(
+        "system",
+        "getPowerSavingMode",
+        40005,
+        "Display Is Turned off",
+    )
+    .await;
+    mock.stub("system", "setLEDIndicatorStatus", json!([]))
+        .await;
+    let bravia = mock.client(Some(AUTH)).await.unwrap();
+    let profile = SettingsProfile {
+        led_indicator: Some(LEDIndicatorStatus::new(LedMode::Demo, Some(true))),
+        ..SettingsProfile::default()
+    };
+
+    // Act
+    let read = bravia.settings_profile().await;
+    let applied = bravia.apply_settings_profile(&profile).await;
+
+    // Assert
+    assert!(matches!(read, Err(Error::BraviaError(code, _)) if code.code == 40005));
+    assert!(applied.is_ok());
+    let requests = mock.server().received_requests().await.unwrap();
+    let led_request: serde_json::Value = requests
+        .iter()
+        .filter_map(|request| request.body_json::<serde_json::Value>().ok())
+        .find(|body| body["method"] == "setLEDIndicatorStatus")
+        .unwrap();
+    assert_eq!("1.0", led_request["version"]);
+    assert_eq!(json!([{ "mode": "Demo" }]), led_request["params"]);
+}
+
+#[tokio::test]
+async fn test_vcr() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(body_partial_json(json!({ "method": "getPowerStatus" })))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/get_power_status.json",
+            JSON_BASE_PATH
+        )))
+        .expect(1)
+        .named("getPowerStatus POST")
+        .mount(&mock_server)
+        .await;
+    let vcr = Vcr::record();
+    let recorder = Bravia::builder(&mock_server.uri())
+        .auth(AUTH)
+        .vcr(vcr.clone())
+        .build()
+        .await
+        .unwrap();
+    let recorded = recorder.system().get_power_status().await.unwrap();
+    let json = vcr.cassette().to_json().unwrap();
+
+    // Act
+    let cassette = Cassette::from_json(&json).unwrap();
+    let bravia = Bravia::builder("127.0.0.1:9")
+        .auth(AUTH)
+        .vcr(Vcr::replay(cassette))
+        .build()
+        .await
+        .unwrap();
+    let replayed = bravia.system().get_power_status().await;
+    let repeated = bravia.system().get_power_status().await;
+
+    // Assert
+    assert_eq!(recorded, replayed.unwrap());
+    assert!(matches!(
+        repeated,
+        Err(Error::NotRecorded(context)) if context.method == "getPowerStatus"
+    ));
+    let interactions = vcr.cassette().interactions;
+    assert_eq!(2, interactions.len());
+    assert_eq!("guide", interactions[0].endpoint);
+    assert_eq!("system", interactions[1].endpoint);
+    assert_eq!(200, interactions[1].status);
+}
+
+#[tokio::test]
+async fn test_lenient_payloads() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let audio_server = server_setup("sample_payloads/audio").await;
+    let responses = [
+        json!({"result": [[{"status": "active"}]], "id": 50}),
+        json!({"result": [{"status": ["active"]}], "id": 50}),
+    ];
+    for response in responses {
+        Mock::given(method("POST"))
+            .and(path(ENDPOINT_PATH))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response))
+            .up_to_n_times(1)
+            .named("getPowerStatus POST")
+            .mount(&mock_server)
+            .await;
+    }
+    Mock::given(method("POST"))
+        .and(path("/sony/audio"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [[[
+                {"target": "speaker", "volume": "25", "mute": "off", "maxVolume": 100}
+            ]]],
+            "id": 33
+        })))
+        .named("getVolumeInformation POST")
+        .mount(&audio_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), Some(AUTH)).await.unwrap();
+    let audio = Bravia::new(&audio_server.uri(), Some(AUTH)).await.unwrap();
+
+    // Act
+    let nested = bravia.system().get_power_status().await;
+    let wrong_type = bravia.system().get_power_status().await;
+    let volume = audio.audio().get_volume_information().await;
+
+    // Assert
+    assert_eq!("active", nested.unwrap());
+    let err = wrong_type.unwrap_err();
+    assert!(matches!(err, Error::UnexpectedPayload(..)));
+    assert_eq!(
+        Some("getPowerStatus"),
+        err.call_context().map(|c| c.method.as_str())
+    );
+    assert!(err
+        .payload()
+        .is_some_and(|payload| payload.contains("[\"active\"]")));
+    let volume = volume.unwrap();
+    assert_eq!(25, volume[0].volume);
+    assert!(!volume[0].mute);
+    assert_eq!(0, volume[0].min_volume);
+}
+
+#[test]
+fn test_unexpected_payload_display() {
+    let context = CallContext {
+        endpoint: "system".to_string(),
+        method: "getPowerStatus".to_string(),
+        version: "1.0".to_string(),
+    };
+    let source = || serde_json::from_str::<u8>("\"active\"").unwrap_err();
+    let payload = format!("[\"{}\"]", "é".repeat(200));
+
+    let long = Error::UnexpectedPayload(source(), context.clone(), payload.clone());
+    let short = Error::UnexpectedPayload(source(), context, "[\"active\"]".to_string());
+
+    let message = long.to_string();
+    assert!(message.ends_with('…'));
+    assert!(message.len() < payload.len());
+    assert_eq!(Some(payload.as_str()), long.payload());
+    assert!(short.to_string().ends_with("received [\"active\"]"));
+}
+
+#[tokio::test]
+async fn test_last_raw_response() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/get_power_status.json",
+            JSON_BASE_PATH
+        )))
+        .named("getPowerStatus POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::builder(&mock_server.uri())
+        .capture_raw_responses(true)
+        .build()
+        .await
+        .unwrap();
+    let uncaptured = Bravia::new(&mock_server.uri(), None).await.unwrap();
+
+    // Act
+    let status = bravia.system().get_power_status().await.unwrap();
+    uncaptured.system().get_power_status().await.unwrap();
+
+    // Assert
+    let raw = bravia.last_raw_response().unwrap();
+    assert_eq!("getPowerStatus", raw.call.method);
+    assert_eq!(json!(status), raw.body["result"][0]["status"]);
+    assert_eq!(None, uncaptured.last_raw_response());
+}
@@ -0,0 +1,136 @@
+use crate::common::{server_setup, FromFile};
+use bravia_api::{
+    browser::{Bookmark, PageInfo},
+    Bravia,
+};
+use wiremock::{
+    matchers::{method, path, BodyExactMatcher},
+    Mock, ResponseTemplate,
+};
+
+const ENDPOINT_PATH: &str = "/sony/browser";
+const JSON_BASE_PATH: &str = "sample_payloads/browser";
+const AUTH: Option<&str> = Some("TEST");
+
+#[tokio::test]
+async fn test_act_browser_control() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/act_browser_control.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/act_browser_control.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("actBrowserControl POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let result = bravia
+        .browser()
+        .act_browser_control(String::from("start"))
+        .await;
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_get_browser_bookmark_list() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_browser_bookmark_list.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_browser_bookmark_list.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getBrowserBookmarkList POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let bookmarks = bravia.browser().get_browser_bookmark_list().await.unwrap();
+
+    // Assert
+    let sample_bookmark = Bookmark {
+        title: String::from("Sony"),
+        url: String::from("https://www.sony.net/"),
+    };
+    assert_eq!(2, bookmarks.len());
+    assert_eq!(sample_bookmark, bookmarks[0]);
+}
+
+#[tokio::test]
+async fn test_get_text_url() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body =
+        BodyExactMatcher::from_json_file(&format!("{}/requests/get_text_url.json", JSON_BASE_PATH));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_text_url.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getTextUrl POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let page = bravia.browser().get_text_url().await.unwrap();
+
+    // Assert
+    let sample_page = PageInfo {
+        url: String::from("https://example.com/"),
+        title: Some(String::from("Example Domain")),
+    };
+    assert_eq!(sample_page, page);
+}
+
+#[tokio::test]
+async fn test_set_text_url() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body =
+        BodyExactMatcher::from_json_file(&format!("{}/requests/set_text_url.json", JSON_BASE_PATH));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/set_text_url.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("setTextUrl POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let result = bravia
+        .browser()
+        .set_text_url(String::from("https://example.com/"))
+        .await;
+
+    // Assert
+    assert!(result.is_ok());
+}
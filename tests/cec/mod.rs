@@ -0,0 +1,125 @@
+use crate::common::{server_setup, FromFile};
+use bravia_api::{cec::PowerSyncMode, Bravia};
+use wiremock::{
+    matchers::{method, path, BodyExactMatcher},
+    Mock, ResponseTemplate,
+};
+
+const ENDPOINT_PATH: &str = "/sony/cec";
+const JSON_BASE_PATH: &str = "sample_payloads/cec";
+const AUTH: Option<&str> = Some("TEST");
+
+#[tokio::test]
+async fn test_set_cec_control_mode() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/set_cec_control_mode.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/set_cec_control_mode.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("setCecControlMode POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let result = bravia.cec().set_cec_control_mode(true).await;
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_set_power_sync_mode() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/set_power_sync_mode.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/set_power_sync_mode.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("setPowerSyncMode POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let result = bravia
+        .cec()
+        .set_power_sync_mode(PowerSyncMode::new(true, false))
+        .await;
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_set_mhl_auto_input_change_mode() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/set_mhl_auto_input_change_mode.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/set_mhl_auto_input_change_mode.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("setMhlAutoInputChangeMode POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let result = bravia.cec().set_mhl_auto_input_change_mode(false).await;
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_set_mhl_power_feed_mode() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/set_mhl_power_feed_mode.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/set_mhl_power_feed_mode.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("setMhlPowerFeedMode POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let result = bravia.cec().set_mhl_power_feed_mode(true).await;
+
+    // Assert
+    assert!(result.is_ok());
+}
@@ -0,0 +1,106 @@
+use crate::common::{server_setup, FromFile};
+use bravia_api::{
+    content_share::{ShareSession, ShareSessionInfo},
+    Bravia,
+};
+use wiremock::{
+    matchers::{method, path, BodyExactMatcher},
+    Mock, ResponseTemplate,
+};
+
+const ENDPOINT_PATH: &str = "/sony/contentshare";
+const JSON_BASE_PATH: &str = "sample_payloads/content_share";
+const AUTH: Option<&str> = Some("TEST");
+
+#[tokio::test]
+async fn test_start_photo_share() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/start_photo_share.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/start_photo_share.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("startPhotoShare POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let session = bravia.content_share().start_photo_share().await.unwrap();
+
+    // Assert
+    let sample_session = ShareSession {
+        url: String::from("http://192.168.1.10:8080/photoshare"),
+        ssid: Some(String::from("DIRECT-BRAVIA")),
+    };
+    assert_eq!(sample_session, session);
+}
+
+#[tokio::test]
+async fn test_stop_photo_share() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/stop_photo_share.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/stop_photo_share.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("stopPhotoShare POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let result = bravia.content_share().stop_photo_share().await;
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_get_photo_share_info() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_photo_share_info.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_photo_share_info.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getPhotoShareInfo POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let info = bravia.content_share().get_photo_share_info().await.unwrap();
+
+    // Assert
+    let sample_info = ShareSessionInfo {
+        status: String::from("active"),
+        url: Some(String::from("http://192.168.1.10:8080/photoshare")),
+        connected_devices: 2,
+    };
+    assert_eq!(sample_info, info);
+}
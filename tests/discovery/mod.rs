@@ -0,0 +1,66 @@
+use bravia_api::discovery::{DiscoveredDevice, Discovery};
+use std::time::Duration;
+use tokio::net::UdpSocket;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const SEARCH_TARGET: &str = "urn:schemas-sony-com:service:ScalarWebAPI:1";
+
+#[tokio::test]
+async fn test_discovery() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/dd.xml"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            "<?xml version=\"1.0\"?><root><device>\
+            <friendlyName>BRAVIA KD-55XH9096</friendlyName>\
+            </device></root>",
+        ))
+        .named("Device description GET")
+        .mount(&mock_server)
+        .await;
+    let location = format!("{}/dd.xml", mock_server.uri());
+    let responder = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let search_address = responder.local_addr().unwrap();
+    let answer = location.clone();
+    let handle = tokio::spawn(async move {
+        let mut buffer = [0; 1024];
+        let (len, client) = responder.recv_from(&mut buffer).await.unwrap();
+        let request = String::from_utf8_lossy(&buffer[..len]).to_string();
+        for st in ["urn:schemas-upnp-org:device:MediaRenderer:1", SEARCH_TARGET] {
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nLOCATION: {}\r\nST: {}\r\n\r\n",
+                answer, st
+            );
+            responder
+                .send_to(response.as_bytes(), client)
+                .await
+                .unwrap();
+        }
+        request
+    });
+
+    // Act
+    let devices = Discovery::new()
+        .timeout(Duration::from_millis(500))
+        .search_address(search_address)
+        .run()
+        .await
+        .unwrap();
+    let request = handle.await.unwrap();
+
+    // Assert
+    assert!(request.starts_with("M-SEARCH * HTTP/1.1\r\n"));
+    assert!(request.contains(&format!("ST: {}\r\n", SEARCH_TARGET)));
+    assert_eq!(
+        devices,
+        vec![DiscoveredDevice {
+            address: "127.0.0.1".parse().unwrap(),
+            location,
+            friendly_name: Some(String::from("BRAVIA KD-55XH9096")),
+        }]
+    );
+}
@@ -37,3 +37,46 @@ async fn test_get_public_key() {
     let sample_key = "AAAAB3NzaC1yc2EAAAABIwAAAQEA3p6TmGYDRtnnmzckD5leg7lHIUY9cuV6vFvacew1uZ7Bmx2MF9a7SqmtiLDkLS3P9y9eobRjuWriSfgmqDPRFRU2mdwAmRm2aIvYa6WkzvnrfUhGR+SCT/Z62j7V9ps6Mt5HB8mFQj3494p4StTPVS1nFqvEUazEx13EJnJyHsdYqsV6UJV169e43oLSSccb3lr8BzeMUnGEfY+NKlAxDpEycr5jJYyTkLfrbX0lyAPs+vLwLRYhm+h2qJYAZUwknus4vD7aki4G69S+gnENClglh/e9ut9Q5BrtxiBQCEikn9V9rlnVkbp1eEUf89XFiHRWMVrRAINtJyQFvvoPOQ==";
     assert_eq!(sample_key, public_key);
 }
+
+#[cfg(feature = "encryption")]
+#[tokio::test]
+async fn test_cipher() {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use bravia_api::{encryption::Cipher, error::Error};
+    use rsa::{pkcs8::EncodePublicKey, Pkcs1v15Encrypt, RsaPrivateKey};
+    use serde_json::json;
+
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let private_key = RsaPrivateKey::new(&mut rand::thread_rng(), 1024).unwrap();
+    let public_key = private_key.to_public_key().to_public_key_der().unwrap();
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [{ "publicKey": STANDARD.encode(public_key.as_bytes()) }],
+            "id": 1
+        })))
+        .named("getPublicKey POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let cipher = bravia.encryption().cipher().await.unwrap();
+    let other = bravia.encryption().cipher().await.unwrap();
+    let encrypted = cipher.encrypt("hello, world");
+    let invalid_key = Cipher::new("bm90IGEga2V5");
+
+    // Assert
+    let common_key = private_key
+        .decrypt(Pkcs1v15Encrypt, &STANDARD.decode(cipher.enc_key()).unwrap())
+        .unwrap();
+    assert_eq!(32, common_key.len());
+    assert_eq!("hello, world", cipher.decrypt(&encrypted).unwrap());
+    assert_ne!(encrypted, other.encrypt("hello, world"));
+    assert!(matches!(
+        other.decrypt(&encrypted),
+        Err(Error::EncryptionError(_))
+    ));
+    assert!(matches!(invalid_key, Err(Error::EncryptionError(_))));
+}
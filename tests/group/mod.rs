@@ -0,0 +1,364 @@
+use crate::common::{server_setup, FromFile};
+use bravia_api::{
+    av_content::InputUri,
+    error::Error,
+    group::{BraviaGroup, SyncStatus},
+    Bravia,
+};
+use serde_json::json;
+use std::time::Duration;
+use wiremock::{
+    matchers::{body_partial_json, body_string_contains, method, path, BodyExactMatcher},
+    Mock, ResponseTemplate,
+};
+
+const ENDPOINT_PATH: &str = "/sony/system";
+const JSON_BASE_PATH: &str = "sample_payloads/system";
+const AUTH: Option<&str> = Some("TEST");
+
+#[tokio::test]
+async fn test_set_power() {
+    // Arrange
+    let working_server = server_setup(JSON_BASE_PATH).await;
+    let failing_server = server_setup(JSON_BASE_PATH).await;
+    let request = format!("{}/requests/set_power_status.json", JSON_BASE_PATH);
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(BodyExactMatcher::from_json_file(&request))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/set_power_status.json",
+            JSON_BASE_PATH
+        )))
+        .expect(1)
+        .named("setPowerStatus POST")
+        .mount(&working_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(BodyExactMatcher::from_json_file(&request))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "error": [40005, "Display Is Turned off"],
+            "id": 55
+        })))
+        .expect(1)
+        .named("setPowerStatus POST")
+        .mount(&failing_server)
+        .await;
+    let group: BraviaGroup = vec![
+        Bravia::new(&working_server.uri(), AUTH).await.unwrap(),
+        Bravia::new(&failing_server.uri(), AUTH).await.unwrap(),
+    ]
+    .into_iter()
+    .collect();
+
+    // Act
+    let results = group.set_power(false).await;
+
+    // Assert
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_ok());
+    assert!(matches!(&results[1], Err(Error::BraviaError(code, _)) if code.code == 40005));
+}
+
+#[tokio::test]
+async fn test_set_power_synchronized() {
+    // Arrange
+    let servers = [
+        server_setup(JSON_BASE_PATH).await,
+        server_setup(JSON_BASE_PATH).await,
+    ];
+    for server in &servers {
+        Mock::given(method("POST"))
+            .and(path(ENDPOINT_PATH))
+            .and(BodyExactMatcher::from_json_file(&format!(
+                "{}/requests/get_power_status.json",
+                JSON_BASE_PATH
+            )))
+            .respond_with(ResponseTemplate::from_json_file(&format!(
+                "{}/responses/get_power_status.json",
+                JSON_BASE_PATH
+            )))
+            .expect(1)
+            .named("getPowerStatus POST")
+            .mount(server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path(ENDPOINT_PATH))
+            .and(body_partial_json(json!({
+                "method": "setPowerStatus",
+                "params": [{"status": true}]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"result": [], "id": 55})))
+            .expect(1)
+            .named("setPowerStatus POST")
+            .mount(server)
+            .await;
+    }
+    let mut group = BraviaGroup::default();
+    for server in &servers {
+        group.push(Bravia::new(&server.uri(), AUTH).await.unwrap());
+    }
+
+    // Act
+    let results = group
+        .set_power_synchronized(true, Duration::from_secs(2))
+        .await;
+
+    // Assert
+    assert_eq!(
+        vec![SyncStatus::Committed, SyncStatus::Committed],
+        results.into_iter().map(Result::unwrap).collect::<Vec<_>>()
+    );
+}
+
+#[tokio::test]
+async fn test_synchronized_prepare_failure() {
+    // Arrange
+    let working_server = server_setup(JSON_BASE_PATH).await;
+    let failing_server = server_setup(JSON_BASE_PATH).await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(body_partial_json(json!({"method": "getPowerStatus"})))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/get_power_status.json",
+            JSON_BASE_PATH
+        )))
+        .named("getPowerStatus POST")
+        .mount(&working_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(body_partial_json(json!({"method": "getPowerStatus"})))
+        .respond_with(ResponseTemplate::new(500))
+        .named("getPowerStatus POST")
+        .mount(&failing_server)
+        .await;
+    for server in [&working_server, &failing_server] {
+        Mock::given(method("POST"))
+            .and(path(ENDPOINT_PATH))
+            .and(body_partial_json(json!({"method": "setPowerStatus"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"result": [], "id": 55})))
+            .expect(0)
+            .named("setPowerStatus POST")
+            .mount(server)
+            .await;
+    }
+    let group = BraviaGroup::new(vec![
+        Bravia::new(&working_server.uri(), AUTH).await.unwrap(),
+        Bravia::new(&failing_server.uri(), AUTH).await.unwrap(),
+    ]);
+
+    // Act
+    let results = group
+        .set_power_synchronized(true, Duration::from_secs(2))
+        .await;
+
+    // Assert
+    assert!(matches!(results[0], Ok(SyncStatus::Skipped)));
+    assert!(matches!(results[1], Err(Error::BadStatus(..))));
+}
+
+#[tokio::test]
+async fn test_select_input_synchronized_rollback() {
+    // Arrange
+    let json_base_path = "sample_payloads/av_content";
+    let working_server = server_setup(json_base_path).await;
+    let failing_server = server_setup(json_base_path).await;
+    for server in [&working_server, &failing_server] {
+        Mock::given(method("POST"))
+            .and(path("/sony/avContent"))
+            .and(BodyExactMatcher::from_json_file(&format!(
+                "{}/requests/get_playing_content_info.json",
+                json_base_path
+            )))
+            .respond_with(ResponseTemplate::from_json_file(&format!(
+                "{}/responses/get_playing_content_info.json",
+                json_base_path
+            )))
+            .expect(1)
+            .named("getPlayingContentInfo POST")
+            .mount(server)
+            .await;
+    }
+    for (uri, server) in [
+        ("extInput:hdmi?port=3", &working_server),
+        ("extInput:hdmi?port=2", &working_server),
+    ] {
+        Mock::given(method("POST"))
+            .and(path("/sony/avContent"))
+            .and(body_partial_json(json!({
+                "method": "setPlayContent",
+                "params": [{"uri": uri}]
+            })))
+            .respond_with(ResponseTemplate::from_json_file(&format!(
+                "{}/responses/set_play_content.json",
+                json_base_path
+            )))
+            .expect(1)
+            .named("setPlayContent POST")
+            .mount(server)
+            .await;
+    }
+    Mock::given(method("POST"))
+        .and(path("/sony/avContent"))
+        .and(body_partial_json(json!({"method": "setPlayContent"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "error": [7, "Illegal State"],
+            "id": 101
+        })))
+        .expect(1)
+        .named("setPlayContent POST")
+        .mount(&failing_server)
+        .await;
+    let group = BraviaGroup::new(vec![
+        Bravia::new(&working_server.uri(), AUTH).await.unwrap(),
+        Bravia::new(&failing_server.uri(), AUTH).await.unwrap(),
+    ]);
+
+    // Act
+    let results = group
+        .select_input_synchronized(InputUri::Hdmi(3), Duration::from_secs(2))
+        .await;
+
+    // Assert
+    assert!(matches!(results[0], Ok(SyncStatus::RolledBack)));
+    assert!(matches!(&results[1], Err(Error::BraviaError(code, _)) if code.code == 7));
+}
+
+#[tokio::test]
+async fn test_select_input_synchronized_home_screen() {
+    // Arrange
+    let json_base_path = "sample_payloads/av_content";
+    let home_server = server_setup(json_base_path).await;
+    let failing_server = server_setup(json_base_path).await;
+    Mock::given(method("POST"))
+        .and(path("/sony/avContent"))
+        .and(body_partial_json(
+            json!({"method": "getPlayingContentInfo"}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "error": [7, "Illegal State"],
+            "id": 103
+        })))
+        .expect(1)
+        .named("getPlayingContentInfo POST")
+        .mount(&home_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/sony/avContent"))
+        .and(body_partial_json(
+            json!({"method": "getPlayingContentInfo"}),
+        ))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/get_playing_content_info.json",
+            json_base_path
+        )))
+        .expect(1)
+        .named("getPlayingContentInfo POST")
+        .mount(&failing_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/sony/avContent"))
+        .and(body_partial_json(json!({"method": "setPlayContent"})))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/set_play_content.json",
+            json_base_path
+        )))
+        .expect(1)
+        .named("setPlayContent POST")
+        .mount(&home_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/sony/avContent"))
+        .and(body_partial_json(json!({"method": "setPlayContent"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "error": [40005, "Display Is Turned off"],
+            "id": 101
+        })))
+        .expect(1)
+        .named("setPlayContent POST")
+        .mount(&failing_server)
+        .await;
+    // The display that was on the home screen is brought back to it
+    Mock::given(method("POST"))
+        .and(path("/sony/ircc"))
+        .and(body_string_contains(
+            "<IRCCCode>AAAAAQAAAAEAAABgAw==</IRCCCode>",
+        ))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .named("X_SendIRCC POST")
+        .mount(&home_server)
+        .await;
+    let group = BraviaGroup::new(vec![
+        Bravia::new(&home_server.uri(), AUTH).await.unwrap(),
+        Bravia::new(&failing_server.uri(), AUTH).await.unwrap(),
+    ]);
+
+    // Act
+    let results = group
+        .select_input_synchronized(InputUri::Hdmi(3), Duration::from_secs(2))
+        .await;
+
+    // Assert
+    assert!(matches!(results[0], Ok(SyncStatus::RolledBack)));
+    assert!(matches!(&results[1], Err(Error::BraviaError(code, _)) if code.code == 40005));
+}
+
+#[tokio::test]
+async fn test_synchronized_commit_timeout() {
+    // Arrange
+    let working_server = server_setup(JSON_BASE_PATH).await;
+    let slow_server = server_setup(JSON_BASE_PATH).await;
+    for server in [&working_server, &slow_server] {
+        Mock::given(method("POST"))
+            .and(path(ENDPOINT_PATH))
+            .and(body_partial_json(json!({"method": "getPowerStatus"})))
+            .respond_with(ResponseTemplate::from_json_file(&format!(
+                "{}/responses/get_power_status.json",
+                JSON_BASE_PATH
+            )))
+            .named("getPowerStatus POST")
+            .mount(server)
+            .await;
+    }
+    for status in [true, false] {
+        Mock::given(method("POST"))
+            .and(path(ENDPOINT_PATH))
+            .and(body_partial_json(json!({
+                "method": "setPowerStatus",
+                "params": [{"status": status}]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"result": [], "id": 55})))
+            .expect(1)
+            .named("setPowerStatus POST")
+            .mount(&working_server)
+            .await;
+    }
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(body_partial_json(json!({"method": "setPowerStatus"})))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({"result": [], "id": 55}))
+                .set_delay(Duration::from_secs(5)),
+        )
+        .named("setPowerStatus POST")
+        .mount(&slow_server)
+        .await;
+    let group = BraviaGroup::new(vec![
+        Bravia::new(&working_server.uri(), AUTH).await.unwrap(),
+        Bravia::new(&slow_server.uri(), AUTH).await.unwrap(),
+    ]);
+
+    // Act
+    let start = std::time::Instant::now();
+    let results = group
+        .set_power_synchronized(true, Duration::from_millis(300))
+        .await;
+
+    // Assert
+    assert!(start.elapsed() < Duration::from_secs(2));
+    assert!(matches!(results[0], Ok(SyncStatus::RolledBack)));
+    assert!(matches!(results[1], Err(Error::VerificationFailed(_))));
+}
@@ -0,0 +1,98 @@
+use crate::common::{server_setup, FromFile};
+use bravia_api::{guide::ServiceProtocols, Bravia};
+use wiremock::{
+    matchers::{method, path, BodyExactMatcher},
+    Mock, ResponseTemplate,
+};
+
+const ENDPOINT_PATH: &str = "/sony/guide";
+const JSON_BASE_PATH: &str = "sample_payloads/guide";
+const AUTH: Option<&str> = Some("TEST");
+
+#[tokio::test]
+async fn test_get_service_protocols() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_service_protocols.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_service_protocols.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getServiceProtocols POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let resp = bravia.guide().get_service_protocols().await;
+
+    // Assert
+    let protocols = resp.unwrap();
+    assert_eq!(5, protocols.len());
+    assert_eq!(
+        ServiceProtocols {
+            service: String::from("guide"),
+            protocols: vec![String::from("xhrpost:jsonizer")],
+        },
+        protocols[0]
+    );
+    assert!(!protocols[0].supports_websocket());
+    assert!(protocols[4].supports_websocket());
+}
+
+#[tokio::test]
+async fn test_max_version() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_supported_api_info.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_supported_api_info.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .with_priority(1)
+        .named("getSupportedApiInfo POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let max = bravia
+        .guide()
+        .max_version("avContent", "getCurrentExternalInputsStatus")
+        .await;
+    let unsupported = bravia.guide().max_version("avContent", "notAnApi").await;
+    let catalog = bravia.guide().api_catalog(Some(&["avContent"])).await;
+
+    // Assert
+    assert_eq!(Some(String::from("1.1")), max.unwrap());
+    assert_eq!(None, unsupported.unwrap());
+    let catalog = catalog.unwrap();
+    assert_eq!(1, catalog.services().len());
+    assert_eq!(
+        Some("1.0"),
+        catalog.max_version("avContent", "getPlayingContentInfo")
+    );
+    assert_eq!(None, catalog.max_version("avContent", "getSchemeList"));
+    assert_eq!(None, catalog.max_version("system", "getPowerStatus"));
+    assert_eq!(
+        Some("1.0"),
+        catalog
+            .service("avContent")
+            .and_then(|service| service.notification("notifyPlayingContentInfo"))
+            .and_then(|notification| notification.max_version())
+    );
+}
@@ -1,8 +1,28 @@
 mod common;
+mod access_control;
 mod app_control;
 mod audio;
 mod av_content;
+mod bravia;
+mod browser;
+mod cec;
+mod content_share;
+mod discovery;
 mod encryption;
+mod group;
+mod guide;
+mod ircc;
+#[cfg(feature = "mqtt")]
+mod mqtt;
+#[cfg(feature = "notifications")]
+mod notifications;
+mod recording;
+mod settings;
+mod simple_ip;
+#[cfg(feature = "simulator")]
+mod simulator;
 mod system;
+mod test_util;
+mod tv;
 mod video;
-mod video_screen;
\ No newline at end of file
+mod video_screen;
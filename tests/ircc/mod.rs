@@ -0,0 +1,142 @@
+use crate::common::{server_setup, FromFile};
+use bravia_api::{
+    error::Error,
+    ircc::RemoteKey,
+    vcr::{Cassette, Vcr},
+    Bravia,
+};
+use wiremock::{
+    matchers::{body_string_contains, header, method, path, BodyExactMatcher},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ENDPOINT_PATH: &str = "/sony/ircc";
+const AUTH: Option<&str> = Some("TEST");
+
+#[tokio::test]
+async fn test_send() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(header("X-Auth-PSK", "TEST"))
+        .and(header(
+            "SOAPACTION",
+            "\"urn:schemas-sony-com:service:IRCC:1#X_SendIRCC\"",
+        ))
+        .and(body_string_contains(
+            "<IRCCCode>AAAAAQAAAAEAAABgAw==</IRCCCode>",
+        ))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .named("X_SendIRCC POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new_unchecked(&mock_server.uri(), AUTH).unwrap();
+
+    // Act
+    let result = bravia.ircc().send(RemoteKey::Home).await;
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_send_error() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .respond_with(ResponseTemplate::new(500))
+        .named("X_SendIRCC POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new_unchecked(&mock_server.uri(), AUTH).unwrap();
+    let no_auth = Bravia::new_unchecked(&mock_server.uri(), None).unwrap();
+
+    // Act
+    let result = bravia.ircc().send(RemoteKey::Home).await;
+    let no_auth_result = no_auth.ircc().send(RemoteKey::Home).await;
+
+    // Assert
+    assert!(matches!(result, Err(Error::BadStatus(..))));
+    assert!(matches!(no_auth_result, Err(Error::BraviaAuthLevelError)));
+}
+
+#[tokio::test]
+async fn test_send_vcr() {
+    // Arrange
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .named("X_SendIRCC POST")
+        .mount(&mock_server)
+        .await;
+    let vcr = Vcr::record();
+    let recorder = Bravia::builder(&mock_server.uri())
+        .auth("TEST")
+        .vcr(vcr.clone())
+        .build_unchecked()
+        .unwrap();
+    recorder.ircc().send(RemoteKey::Home).await.unwrap();
+    let json = vcr.cassette().to_json().unwrap();
+
+    // Act
+    let bravia = Bravia::builder("127.0.0.1:9")
+        .auth("TEST")
+        .vcr(Vcr::replay(Cassette::from_json(&json).unwrap()))
+        .build_unchecked()
+        .unwrap();
+    let replayed = bravia.ircc().send(RemoteKey::Home).await;
+    let not_recorded = bravia.ircc().send(RemoteKey::Home).await;
+
+    // Assert
+    assert!(replayed.is_ok());
+    assert!(matches!(
+        not_recorded,
+        Err(Error::NotRecorded(context)) if context.method == "X_SendIRCC"
+    ));
+    let interactions = vcr.cassette().interactions;
+    assert_eq!(1, interactions.len());
+    assert_eq!("ircc", interactions[0].endpoint);
+}
+
+#[tokio::test]
+async fn test_send_code() {
+    // Arrange
+    let mock_server = server_setup("sample_payloads/system").await;
+    Mock::given(method("POST"))
+        .and(path("/sony/system"))
+        .and(BodyExactMatcher::from_json_file(
+            "sample_payloads/system/requests/get_remote_controller_info.json",
+        ))
+        .respond_with(ResponseTemplate::from_json_file(
+            "sample_payloads/system/responses/get_remote_controller_info.json",
+        ))
+        .named("getRemoteControllerInfo POST")
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(body_string_contains(
+            "<IRCCCode>AAAAAQAAAAEAAAAlAw==</IRCCCode>",
+        ))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .named("X_SendIRCC POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+    let ircc = bravia.ircc();
+
+    // Act
+    let codes = ircc.remote_codes().await.unwrap();
+    let result = ircc.send_code(&codes["Input"]).await;
+    let invalid = ircc.send_code("<IRCCCode>").await;
+
+    // Assert
+    assert!(result.is_ok());
+    assert!(matches!(invalid, Err(Error::InvalidParameter(_))));
+}
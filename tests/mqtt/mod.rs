@@ -0,0 +1,107 @@
+use crate::common::{server_setup, FromFile};
+use bravia_api::{
+    mqtt::{MqttBridge, MqttOptions},
+    Bravia,
+};
+use std::time::Duration;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+use wiremock::{
+    matchers::{method, path, BodyExactMatcher},
+    Mock, ResponseTemplate,
+};
+
+const ENDPOINT_PATH: &str = "/sony/system";
+const JSON_BASE_PATH: &str = "sample_payloads/system";
+const AUTH: Option<&str> = Some("TEST");
+
+// Reads an MQTT packet, returning its first byte and its body.
+async fn read_packet(stream: &mut TcpStream) -> (u8, Vec<u8>) {
+    let header = stream.read_u8().await.unwrap();
+    let mut len = 0;
+    for shift in (0..28).step_by(7) {
+        let byte = stream.read_u8().await.unwrap();
+        len |= usize::from(byte & 0x7F) << shift;
+        if byte & 0x80 == 0 {
+            break;
+        }
+    }
+    let mut body = vec![0; len];
+    stream.read_exact(&mut body).await.unwrap();
+    (header, body)
+}
+
+#[tokio::test]
+async fn test_bridge() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    for (name, calls) in [("get_power_status", 1..), ("set_power_status", 1..)] {
+        Mock::given(method("POST"))
+            .and(path(ENDPOINT_PATH))
+            .and(BodyExactMatcher::from_json_file(&format!(
+                "{}/requests/{}.json",
+                JSON_BASE_PATH, name
+            )))
+            .respond_with(ResponseTemplate::from_json_file(&format!(
+                "{}/responses/{}.json",
+                JSON_BASE_PATH, name
+            )))
+            .expect(calls)
+            .named(name)
+            .mount(&mock_server)
+            .await;
+    }
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let bridge = MqttBridge::new(&bravia, MqttOptions::new("test", "127.0.0.1", port))
+        .topic_prefix("home/tv/")
+        .poll_interval(Duration::from_millis(50));
+
+    // Act
+    let bridge = tokio::spawn(bridge.run());
+    let (mut stream, _) = listener.accept().await.unwrap();
+    let (connect, _) = read_packet(&mut stream).await;
+    stream.write_all(&[0x20, 0x02, 0x00, 0x00]).await.unwrap();
+    let (subscribe, body) = read_packet(&mut stream).await;
+    stream
+        .write_all(&[0x90, 0x03, body[0], body[1], 0x01])
+        .await
+        .unwrap();
+    let topic = b"home/tv/power/set";
+    let mut publish = vec![0x30, (2 + topic.len() + 3) as u8, 0x00, topic.len() as u8];
+    publish.extend_from_slice(topic);
+    publish.extend_from_slice(b"off");
+    stream.write_all(&publish).await.unwrap();
+    let state = loop {
+        let (header, body) = read_packet(&mut stream).await;
+        let topic_len = usize::from(body[1]);
+        if header & 0xF0 == 0x30 && &body[2..2 + topic_len] == b"home/tv/power" {
+            // Skips the packet identifier of the QoS 1 messages
+            break String::from_utf8(body[2 + topic_len + 2..].to_vec()).unwrap();
+        }
+    };
+    let mut commands = 0;
+    for _ in 0..50 {
+        commands = mock_server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .filter(|request| String::from_utf8_lossy(&request.body).contains("setPowerStatus"))
+            .count();
+        if commands > 0 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    bridge.abort();
+
+    // Assert
+    assert_eq!(connect, 0x10);
+    assert_eq!(subscribe, 0x82);
+    assert_eq!(state, "standby");
+    assert_eq!(commands, 1);
+}
@@ -0,0 +1,185 @@
+use bravia_api::{
+    audio::VolumeInformation,
+    av_content::{ExternalInputStatus, PlayingContentInfo},
+    notifications::Notification,
+    Bravia,
+};
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use std::net::SocketAddr;
+use tokio::{net::TcpListener, task::JoinHandle};
+use tokio_tungstenite::{
+    accept_hdr_async,
+    tungstenite::{handshake::server::Request, Message},
+};
+
+// Starts a WebSocket server that enables the notifications supported by `service`,
+// pushes the given ones and then closes the connection.
+// Returns the server address and the calls it received.
+#[allow(clippy::result_large_err)]
+async fn notification_server(
+    service: &'static str,
+    notifications: Vec<Value>,
+) -> (SocketAddr, JoinHandle<Vec<Value>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let server = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut socket = accept_hdr_async(stream, |request: &Request, response| {
+            assert_eq!(request.uri().path(), format!("/sony/{}", service));
+            assert_eq!(request.headers()["X-Auth-PSK"], "TEST");
+            Ok(response)
+        })
+        .await
+        .unwrap();
+        let supported: Vec<Value> = notifications
+            .iter()
+            .map(|notification| json!({"name": notification["method"], "version": "1.0"}))
+            .chain([json!({"name": "notifyStorageStatus", "version": "1.0"})])
+            .collect();
+        let mut calls = Vec::new();
+        for result in [json!([{"enabled": [], "disabled": supported}]), json!([])] {
+            let message = socket.next().await.unwrap().unwrap();
+            let call: Value = serde_json::from_str(message.to_text().unwrap()).unwrap();
+            let response = json!({"result": result, "id": call["id"]});
+            socket
+                .send(Message::text(response.to_string()))
+                .await
+                .unwrap();
+            calls.push(call);
+        }
+        for notification in notifications {
+            socket
+                .send(Message::text(notification.to_string()))
+                .await
+                .unwrap();
+        }
+        socket.close(None).await.unwrap();
+        calls
+    });
+    (address, server)
+}
+
+#[tokio::test]
+async fn test_subscribe() {
+    // Arrange
+    let (address, server) = notification_server(
+        "system",
+        vec![json!({
+            "method": "notifyPowerStatus",
+            "params": [{"status": "active"}],
+            "version": "1.0"
+        })],
+    )
+    .await;
+    let bravia = Bravia::new_unchecked(&address.to_string(), Some("TEST")).unwrap();
+
+    // Act
+    let mut notifications = bravia
+        .notifications()
+        .subscribe("system", Some(&["notifyPowerStatus"]))
+        .await
+        .unwrap();
+    let notification = notifications.next().await.unwrap().unwrap();
+    let end = notifications.next().await;
+    let calls = server.await.unwrap();
+
+    // Assert
+    assert_eq!(
+        notification,
+        Notification::PowerStatus(String::from("active"))
+    );
+    assert!(end.is_none());
+    assert_eq!(calls[0]["method"], "switchNotifications");
+    assert_eq!(calls[0]["params"], json!([{}]));
+    assert_eq!(
+        calls[1]["params"],
+        json!([{"enabled": [{"name": "notifyPowerStatus", "version": "1.0"}]}])
+    );
+}
+
+#[tokio::test]
+async fn test_typed_notifications() {
+    // Arrange
+    let (address, server) = notification_server(
+        "avContent",
+        vec![
+            json!({
+                "method": "notifyVolumeInformation",
+                "params": [{"target": "speaker", "volume": 15, "mute": false}],
+                "version": "1.0"
+            }),
+            json!({
+                "method": "notifyPlayingContentInfo",
+                "params": [{
+                    "source": "extInput:hdmi",
+                    "title": "HDMI 2",
+                    "uri": "extInput:hdmi?port=2"
+                }],
+                "version": "1.0"
+            }),
+            json!({
+                "method": "notifyExternalTerminalStatus",
+                "params": [{
+                    "icon": "meta:game",
+                    "connection": true,
+                    "label": "GAME",
+                    "title": "HDMI 1",
+                    "uri": "extInput:hdmi?port=1"
+                }],
+                "version": "1.0"
+            }),
+            json!({
+                "method": "notifyStorageStatus",
+                "params": [{"status": "mounted"}],
+                "version": "1.0"
+            }),
+        ],
+    )
+    .await;
+    let bravia = Bravia::new_unchecked(&address.to_string(), Some("TEST")).unwrap();
+
+    // Act
+    let notifications: Vec<Notification> = bravia
+        .notifications()
+        .subscribe("avContent", None)
+        .await
+        .unwrap()
+        .map(Result::unwrap)
+        .collect()
+        .await;
+    server.await.unwrap();
+
+    // Assert
+    assert_eq!(
+        notifications,
+        vec![
+            Notification::Volume(VolumeInformation {
+                target: String::from("speaker"),
+                volume: 15,
+                mute: false,
+                max_volume: 0,
+                min_volume: 0,
+            }),
+            Notification::PlayingContent(PlayingContentInfo {
+                source: String::from("extInput:hdmi"),
+                title: String::from("HDMI 2"),
+                uri: String::from("extInput:hdmi?port=2"),
+                ..Default::default()
+            }),
+            Notification::ExternalTerminalStatus(ExternalInputStatus {
+                icon: String::from("meta:game"),
+                connection: true,
+                label: String::from("GAME"),
+                title: String::from("HDMI 1"),
+                uri: String::from("extInput:hdmi?port=1"),
+                status: None,
+            }),
+            Notification::Other {
+                method: String::from("notifyStorageStatus"),
+                version: String::from("1.0"),
+                params: vec![json!({"status": "mounted"})],
+            },
+        ]
+    );
+}
@@ -0,0 +1,250 @@
+use crate::common::{server_setup, FromFile};
+use bravia_api::{
+    recording::{RecordedProgram, Schedule, ScheduleRequest},
+    Bravia,
+};
+use wiremock::{
+    matchers::{method, path, BodyExactMatcher},
+    Mock, ResponseTemplate,
+};
+
+const ENDPOINT_PATH: &str = "/sony/recording";
+const JSON_BASE_PATH: &str = "sample_payloads/recording";
+const AUTH: Option<&str> = Some("TEST");
+
+#[tokio::test]
+async fn test_get_recording_status() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_recording_status.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_recording_status.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getRecordingStatus POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let status = bravia.recording().get_recording_status().await.unwrap();
+
+    // Assert
+    assert_eq!("notRecording", status);
+}
+
+#[tokio::test]
+async fn test_get_supported_repeat_type() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_supported_repeat_type.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_supported_repeat_type.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getSupportedRepeatType POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let repeat_types = bravia
+        .recording()
+        .get_supported_repeat_type()
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(vec!["none", "everyday", "weekday", "weekly"], repeat_types);
+}
+
+#[tokio::test]
+async fn test_get_history_list() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_history_list.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_history_list.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getHistoryList POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let history = bravia
+        .recording()
+        .get_history_list(Some(0), Some(10))
+        .await
+        .unwrap();
+
+    // Assert
+    let sample_program = RecordedProgram {
+        uri: String::from("pvr:recording?id=5"),
+        title: String::from("Match of the Day"),
+        channel_name: Some(String::from("BBC ONE")),
+        start_date_time: String::from("2018-09-29T22:20:00+0100"),
+        duration_sec: 4800,
+    };
+    assert_eq!(vec![sample_program], history);
+}
+
+#[tokio::test]
+async fn test_get_schedule_list() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_schedule_list.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_schedule_list.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getScheduleList POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let schedules = bravia
+        .recording()
+        .get_schedule_list(None, Some(20))
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(2, schedules.len());
+    assert_eq!("12", schedules[0].id);
+    assert_eq!(Some(String::from("News at Ten")), schedules[0].title);
+    assert_eq!(1800, schedules[0].duration_sec);
+    assert_eq!("weekday", schedules[0].repeat_type);
+}
+
+#[tokio::test]
+async fn test_get_conflict_schedule_list() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_conflict_schedule_list.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_conflict_schedule_list.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getConflictScheduleList POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let schedules = bravia
+        .recording()
+        .get_conflict_schedule_list(None, None)
+        .await
+        .unwrap();
+
+    // Assert
+    let sample_schedule = Schedule {
+        id: String::from("13"),
+        uri: String::from("tv:dvbt?trip=9018.4161.1026&srvName=BBC TWO"),
+        title: Some(String::from("Newsnight")),
+        channel_name: Some(String::from("BBC TWO")),
+        start_date_time: String::from("2018-10-03T22:15:00+0100"),
+        duration_sec: 2700,
+        repeat_type: String::from("none"),
+        status: Some(String::from("conflict")),
+    };
+    assert_eq!(vec![sample_schedule], schedules);
+}
+
+#[tokio::test]
+async fn test_add_schedule() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body =
+        BodyExactMatcher::from_json_file(&format!("{}/requests/add_schedule.json", JSON_BASE_PATH));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/add_schedule.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("addSchedule POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let mut schedule = ScheduleRequest::new(
+        String::from("tv:dvbt?trip=9018.4161.1025&srvName=BBC ONE"),
+        String::from("2018-10-03T22:00:00+0100"),
+        1800,
+    );
+    schedule.repeat_type = Some(String::from("weekday"));
+    let id = bravia.recording().add_schedule(&schedule).await.unwrap();
+
+    // Assert
+    assert_eq!("12", id);
+}
+
+#[tokio::test]
+async fn test_delete_schedule() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/delete_schedule.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/delete_schedule.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("deleteSchedule POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let result = bravia.recording().delete_schedule(String::from("12")).await;
+
+    // Assert
+    assert!(result.is_ok());
+}
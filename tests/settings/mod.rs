@@ -0,0 +1,106 @@
+use crate::common::{server_setup, FromFile};
+use bravia_api::{
+    settings::{SettingValue, SettingsTreeNode},
+    Bravia,
+};
+use wiremock::{
+    matchers::{method, path, BodyExactMatcher},
+    Mock, ResponseTemplate,
+};
+
+const ENDPOINT_PATH: &str = "/sony/system";
+const JSON_BASE_PATH: &str = "sample_payloads/settings";
+const AUTH: Option<&str> = Some("TEST");
+
+#[tokio::test]
+async fn test_get_settings_tree() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_settings_tree.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_settings_tree.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getSettingsTree POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let tree = bravia.settings("system").get_settings_tree().await.unwrap();
+
+    // Assert
+    let node: &SettingsTreeNode = tree.first().unwrap();
+    assert_eq!(Some("Power"), node.title.as_deref());
+    assert!(!node.children[1].is_available);
+    assert_eq!(vec!["powerOnStatus", "ecoMode"], node.targets());
+}
+
+#[tokio::test]
+async fn test_get_settings() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body =
+        BodyExactMatcher::from_json_file(&format!("{}/requests/get_settings.json", JSON_BASE_PATH));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_settings.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getSettings POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let settings = bravia
+        .settings("system")
+        .get_settings(Some("powerOnStatus"))
+        .await
+        .unwrap();
+
+    // Assert
+    let setting = settings.first().unwrap();
+    assert_eq!("lastState", setting.current_value);
+    assert_eq!(Some("enumeration"), setting.kind.as_deref());
+    assert_eq!(3, setting.candidate.as_ref().unwrap().len());
+}
+
+#[tokio::test]
+async fn test_set_settings() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body =
+        BodyExactMatcher::from_json_file(&format!("{}/requests/set_settings.json", JSON_BASE_PATH));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/set_settings.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("setSettings POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    bravia
+        .settings("system")
+        .set_settings(vec![SettingValue::new("powerOnStatus", "on")])
+        .await
+        .unwrap();
+
+    // Nothing to assert
+}
@@ -0,0 +1,70 @@
+use bravia_api::{
+    error::Error,
+    simple_ip::{Input, SimpleIpClient},
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+// Starts a server that answers each expected message with the given answers.
+// Returns the server address.
+async fn simple_ip_server(exchanges: Vec<(&'static str, Vec<&'static str>)>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        for (expected, answers) in exchanges {
+            let mut buffer = [0; 24];
+            stream.read_exact(&mut buffer).await.unwrap();
+            assert_eq!(String::from_utf8_lossy(&buffer), expected);
+            for answer in answers {
+                stream.write_all(answer.as_bytes()).await.unwrap();
+            }
+        }
+    });
+    address
+}
+
+#[tokio::test]
+async fn test_commands() {
+    // Arrange
+    let address = simple_ip_server(vec![
+        (
+            "*SCPOWR0000000000000001\n",
+            vec!["*SAPOWR0000000000000000\n"],
+        ),
+        (
+            "*SEVOLU################\n",
+            vec!["*SNPOWR0000000000000001\n", "*SAVOLU0000000000000015\n"],
+        ),
+        (
+            "*SCINPT0000000100000002\n",
+            vec!["*SAINPT0000000000000000\n"],
+        ),
+        (
+            "*SEINPT################\n",
+            vec!["*SAINPT0000000100000002\n"],
+        ),
+        (
+            "*SCAMUT0000000000000001\n",
+            vec!["*SAAMUTFFFFFFFFFFFFFFFF\n"],
+        ),
+    ])
+    .await;
+    let mut client = SimpleIpClient::connect(&address).await.unwrap();
+
+    // Act
+    let power = client.set_power(true).await;
+    let volume = client.volume().await.unwrap();
+    let set_input = client.set_input(Input::Hdmi(2)).await;
+    let input = client.input().await.unwrap();
+    let mute = client.set_mute(true).await;
+
+    // Assert
+    assert!(power.is_ok());
+    assert_eq!(volume, 15);
+    assert!(set_input.is_ok());
+    assert_eq!(input, Input::Hdmi(2));
+    assert!(matches!(mute, Err(Error::SimpleIpError(command)) if command == "AMUT"));
+}
@@ -0,0 +1,97 @@
+use bravia_api::{
+    error::Error,
+    simulator::{DeviceState, Simulator},
+};
+
+const AUTH: Option<&str> = Some("TEST");
+
+#[tokio::test]
+async fn test_simulator_flow() {
+    // Arrange
+    let simulator = Simulator::start().await.unwrap();
+    let bravia = simulator.client(AUTH).await.unwrap();
+    let turned_off = bravia.audio().get_volume_information().await;
+
+    // Act
+    bravia.system().set_power_status(true).await.unwrap();
+    bravia
+        .av_content()
+        .set_play_content("extInput:hdmi?port=3")
+        .await
+        .unwrap();
+    bravia
+        .audio()
+        .set_audio_volume(Some("speaker"), "+5", None, None)
+        .await
+        .unwrap();
+    bravia.audio().set_audio_mute(true).await.unwrap();
+
+    // Assert
+    assert!(matches!(turned_off, Err(Error::BraviaError(code, _)) if code.code == 40005));
+    assert_eq!("active", bravia.system().get_power_status().await.unwrap());
+    let content = bravia
+        .av_content()
+        .get_playing_content_info()
+        .await
+        .unwrap();
+    assert_eq!("extInput:hdmi?port=3", content.uri);
+    assert_eq!("extInput:hdmi", content.source);
+    let volume = bravia.audio().get_volume_information().await.unwrap();
+    assert_eq!(25, volume[0].volume);
+    assert!(volume[0].mute);
+    let state = simulator.state();
+    assert_eq!(Some("extInput:hdmi?port=3"), state.input.as_deref());
+    assert_eq!(25, state.volume);
+}
+
+#[tokio::test]
+async fn test_simulator_applications() {
+    // Arrange
+    let simulator = Simulator::with_state(DeviceState {
+        power: true,
+        ..DeviceState::default()
+    })
+    .await
+    .unwrap();
+    let bravia = simulator.client(AUTH).await.unwrap();
+    let apps = bravia.app_control().get_application_list().await.unwrap();
+
+    // Act
+    bravia
+        .app_control()
+        .set_active_app(&apps[0].uri)
+        .await
+        .unwrap();
+    let unknown = bravia.app_control().set_active_app("com.example.app").await;
+
+    // Assert
+    assert_eq!(Some(apps[0].uri.clone()), simulator.state().application);
+    assert!(matches!(unknown, Err(Error::BraviaError(code, _)) if code.code == 3));
+    // No input is shown while an application is in the foreground
+    let content = bravia.av_content().get_playing_content_info().await;
+    assert!(matches!(content, Err(Error::BraviaError(code, _)) if code.code == 7));
+    let inputs = bravia
+        .av_content()
+        .get_current_external_input_status(Some("1.1"))
+        .await
+        .unwrap();
+    assert_eq!(4, inputs.len());
+    assert_eq!(Some(true), inputs[0].status);
+}
+
+#[tokio::test]
+async fn test_simulator_update_state() {
+    // Arrange
+    let simulator = Simulator::start().await.unwrap();
+    let bravia = simulator.client(AUTH).await.unwrap();
+
+    // Act
+    simulator.update_state(|state| state.power = true);
+
+    // Assert
+    assert_eq!("active", bravia.system().get_power_status().await.unwrap());
+    assert!(!bravia
+        .supports("video", "getPictureQualitySettings", "1.0")
+        .await
+        .unwrap());
+}
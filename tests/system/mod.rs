@@ -1,10 +1,21 @@
 use crate::common::{server_setup, FromFile};
 use bravia_api::{
-    system::{InterfaceInfo, LEDIndicatorStatus, NetworkSettings, RemoteControllerAction},
+    error::Error,
+    settings::SettingValue,
+    system::{
+        InterfaceInfo, LEDIndicatorStatus, Language, LedMode, MacAddr, NetworkSettings, Parsed,
+        PowerSavingMode, PowerStatus, RemoteControllerAction, Time, BOOTUP_TARGET,
+        POWER_ON_STATUS_TARGET,
+    },
     Bravia,
 };
+use serde_json::json;
+use std::{
+    net::{IpAddr, Ipv4Addr},
+    time::{Duration, UNIX_EPOCH},
+};
 use wiremock::{
-    matchers::{method, path, BodyExactMatcher},
+    matchers::{body_partial_json, method, path, BodyExactMatcher},
     Mock, ResponseTemplate,
 };
 
@@ -12,6 +23,42 @@ const ENDPOINT_PATH: &str = "/sony/system";
 const JSON_BASE_PATH: &str = "sample_payloads/system";
 const AUTH: Option<&str> = Some("TEST");
 
+#[tokio::test]
+async fn test_get_bootup_settings() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body =
+        BodyExactMatcher::from_json_file(&format!("{}/requests/get_settings.json", JSON_BASE_PATH));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_settings.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getSettings POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let settings = bravia.system().get_bootup_settings(None).await.unwrap();
+
+    // Assert
+    let values: Vec<(&str, &str)> = settings
+        .iter()
+        .map(|x| (x.target.as_str(), x.current_value.as_str()))
+        .collect();
+    assert_eq!(
+        vec![
+            (POWER_ON_STATUS_TARGET, "on"),
+            (BOOTUP_TARGET, "extInput:hdmi?port=2"),
+        ],
+        values
+    );
+}
+
 #[tokio::test]
 async fn test_get_current_time() {
     // Arrange
@@ -55,6 +102,86 @@ async fn test_get_current_time() {
     // Assert
     assert_eq!("2018-10-03T13:03:04+0100", time_1_0.date_time);
     assert_eq!("2018-10-03T13:03:59+0100", time_1_1.date_time);
+    assert_eq!(Some(60), time_1_1.time_zone_offset_minute);
+    assert_eq!(None, time_1_0.utc_offset_minute());
+}
+
+#[test]
+fn test_time_negative_offsets() {
+    // Act
+    let time: Time = serde_json::from_str(
+        r#"{"dateTime": "2018-10-03T08:03:59-0400", "timeZoneOffsetMinute": -300, "dstOffsetMinute": 60}"#,
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(Some(-300), time.time_zone_offset_minute);
+    assert_eq!(Some(60), time.dst_offset_minute);
+    assert_eq!(Some(-240), time.utc_offset_minute());
+    assert_eq!(Some(true), time.is_dst());
+    assert_eq!(
+        Some((UNIX_EPOCH + Duration::from_secs(1538568239), -240)),
+        time.utc_and_offset()
+    );
+}
+
+#[test]
+fn test_time_utc_and_offset() {
+    // Arrange
+    let time = |date_time: &str| Time {
+        date_time: date_time.to_string(),
+        time_zone_offset_minute: None,
+        dst_offset_minute: None,
+    };
+
+    // Act
+    let leap_day = time("2024-02-29T23:30:00-03:30").utc_and_offset();
+    let utc = time("1969-12-31T23:59:59Z").utc_and_offset();
+    let invalid = time("2018-13-03T13:03:04+0100").utc_and_offset();
+
+    // Assert
+    assert_eq!(
+        Some((UNIX_EPOCH + Duration::from_secs(1709262000), -210)),
+        leap_day
+    );
+    assert_eq!(Some((UNIX_EPOCH - Duration::from_secs(1), 0)), utc);
+    assert_eq!(None, invalid);
+}
+
+#[cfg(feature = "chrono")]
+#[tokio::test]
+async fn test_get_current_date_time() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_current_time_V1_1.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_current_time_V1_1.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getCurrentTime POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let date_time = bravia
+        .system()
+        .get_current_date_time(Some("1.1"))
+        .await
+        .unwrap();
+    let time = bravia.system().get_current_time(Some("1.1")).await.unwrap();
+
+    // Assert
+    assert_eq!("2018-10-03T12:03:59+00:00", date_time.to_utc().to_rfc3339());
+    assert_eq!(Some(chrono::Duration::hours(1)), time.time_zone_offset());
+    assert_eq!(Some(chrono::Duration::hours(1)), time.dst_offset());
 }
 
 #[tokio::test]
@@ -118,12 +245,24 @@ async fn test_get_led_indicator_status() {
 
     // Assert
     let status = LEDIndicatorStatus {
-        mode: "Demo".to_string(),
-        status: Some("true".to_string()),
+        mode: LedMode::Demo,
+        status: Some(true),
     };
     assert_eq!(status, led_status);
 }
 
+#[test]
+fn test_led_mode_unknown() {
+    // Act
+    let status: LEDIndicatorStatus =
+        serde_json::from_str(r#"{"mode": "Ambient", "status": "true"}"#).unwrap();
+
+    // Assert
+    assert_eq!(LedMode::Unknown(String::from("Ambient")), status.mode);
+    assert_eq!("\"Ambient\"", serde_json::to_string(&status.mode).unwrap());
+    assert_eq!("\"Dark\"", serde_json::to_string(&LedMode::Dark).unwrap());
+}
+
 #[tokio::test]
 async fn test_get_network_settings() {
     // Arrange
@@ -148,32 +287,60 @@ async fn test_get_network_settings() {
     // Act
     let net_status = bravia
         .system()
-        .get_network_settings(Some("eth0".to_string()))
+        .get_network_settings(Some("eth0"))
         .await
         .unwrap();
 
     // Assert
     let eth0 = NetworkSettings {
         netif: "eth0".to_string(),
-        hw_addr: "FF-FF-FF-FF-FF-FF".to_string(),
-        ip_addr_v4: "0.0.0.0".to_string(),
-        ip_addr_v6: "ffff::ffff:ffff:fffd%7".to_string(),
-        netmask: "255.255.255.0".to_string(),
-        gateway: "0.0.0.0".to_string(),
+        hw_addr: Parsed::Value(MacAddr([0xFF; 6])),
+        ip_addr_v4: Parsed::Value(IpAddr::V4(Ipv4Addr::UNSPECIFIED)),
+        ip_addr_v6: Parsed::Raw("ffff::ffff:ffff:fffd%7".to_string()),
+        netmask: Parsed::Value(IpAddr::V4(Ipv4Addr::new(255, 255, 255, 0))),
+        gateway: Parsed::Value(IpAddr::V4(Ipv4Addr::UNSPECIFIED)),
         dns: vec!["0.0.0.0".to_string(), "1.1.1.1".to_string()],
     };
     let wlan0 = NetworkSettings {
         netif: "wlan0".to_string(),
-        hw_addr: "00-00-00-00-00-00".to_string(),
-        ip_addr_v4: "0.0.0.1".to_string(),
-        ip_addr_v6: "ffff::ffff:ffff:fffd%8".to_string(),
-        netmask: "255.255.255.0".to_string(),
-        gateway: "0.0.0.0".to_string(),
+        hw_addr: Parsed::Value(MacAddr([0x00; 6])),
+        ip_addr_v4: Parsed::Value(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 1))),
+        ip_addr_v6: Parsed::Raw("ffff::ffff:ffff:fffd%8".to_string()),
+        netmask: Parsed::Value(IpAddr::V4(Ipv4Addr::new(255, 255, 255, 0))),
+        gateway: Parsed::Value(IpAddr::V4(Ipv4Addr::UNSPECIFIED)),
         dns: vec!["0.0.0.0".to_string(), "fec0:0:0:ffff::1%1".to_string()],
     };
     assert_eq!(vec![eth0, wlan0], net_status);
 }
 
+#[tokio::test]
+async fn test_get_postal_code() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_postal_code.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_postal_code.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getPostalCode POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let postal_code = bravia.system().get_postal_code().await.unwrap();
+
+    // Assert
+    assert_eq!("1000001", postal_code);
+}
+
 #[tokio::test]
 async fn test_get_power_saving_mode() {
     // Arrange
@@ -199,7 +366,17 @@ async fn test_get_power_saving_mode() {
     let power_saving_mode = bravia.system().get_power_saving_mode().await.unwrap();
 
     // Assert
-    assert_eq!("high", power_saving_mode);
+    assert_eq!(PowerSavingMode::High, power_saving_mode);
+}
+
+#[test]
+fn test_power_saving_mode_unknown() {
+    // Act
+    let mode: PowerSavingMode = serde_json::from_str("\"eco\"").unwrap();
+
+    // Assert
+    assert_eq!(PowerSavingMode::Unknown(String::from("eco")), mode);
+    assert_eq!("\"eco\"", serde_json::to_string(&mode).unwrap());
 }
 
 #[tokio::test]
@@ -230,6 +407,48 @@ async fn test_get_power_status() {
     assert_eq!("standby", power_status);
 }
 
+#[tokio::test]
+async fn test_get_power_status_detail() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    for version in ["", "_V1_1"] {
+        let expected_body = BodyExactMatcher::from_json_file(&format!(
+            "{}/requests/get_power_status{}.json",
+            JSON_BASE_PATH, version
+        ));
+        let template = ResponseTemplate::from_json_file(&format!(
+            "{}/responses/get_power_status{}.json",
+            JSON_BASE_PATH, version
+        ));
+        Mock::given(method("POST"))
+            .and(path(ENDPOINT_PATH))
+            .and(expected_body)
+            .respond_with(template)
+            .named("getPowerStatus POST")
+            .mount(&mock_server)
+            .await;
+    }
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let status_1_0 = bravia.system().get_power_status_detail(None).await.unwrap();
+    let status_1_1 = bravia
+        .system()
+        .get_power_status_detail(Some("1.1"))
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        PowerStatus {
+            status: String::from("standby"),
+            standby_detail: None,
+        },
+        status_1_0
+    );
+    assert_eq!(Some("normalStandby"), status_1_1.standby_detail.as_deref());
+}
+
 #[tokio::test]
 async fn test_get_remote_controller_info() {
     // Arrange
@@ -259,7 +478,7 @@ async fn test_get_remote_controller_info() {
         name: "PowerOff".to_string(),
         value: "AAAAAQAAAAEAAAAvAw==".to_string(),
     };
-    assert_eq!(&power_off, controller_info.get(0).unwrap());
+    assert_eq!(&power_off, controller_info.first().unwrap());
 }
 
 #[tokio::test]
@@ -286,12 +505,12 @@ async fn test_get_remote_device_settings() {
     // Act
     let device_settings = bravia
         .system()
-        .get_remote_device_settings(Some("accessPermission".to_string()))
+        .get_remote_device_settings(Some("accessPermission"))
         .await
         .unwrap();
 
     // Assert
-    assert_eq!("accessPermission", device_settings.get(0).unwrap().target);
+    assert_eq!("accessPermission", device_settings.first().unwrap().target);
 }
 
 #[tokio::test]
@@ -320,6 +539,10 @@ async fn test_get_system_information() {
 
     // Assert
     assert_eq!("FW-55BZ35F", system_info.model);
+    assert_eq!(
+        Some(&MacAddr([0x04, 0x5D, 0x4B, 0xAD, 0x6D, 0xE1])),
+        system_info.mac_addr.value()
+    );
 }
 
 #[tokio::test]
@@ -349,7 +572,7 @@ async fn test_get_system_supported_function() {
         .get_system_supported_function()
         .await
         .unwrap();
-    let system_fn = system_fn.get(0).unwrap();
+    let system_fn = system_fn.first().unwrap();
 
     // Assert
     assert_eq!("WOL", system_fn.option);
@@ -410,31 +633,111 @@ async fn test_request_reboot() {
 }
 
 #[tokio::test]
-async fn test_set_led_indicator_status() {
+async fn test_set_bootup_settings() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body =
+        BodyExactMatcher::from_json_file(&format!("{}/requests/set_settings.json", JSON_BASE_PATH));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/set_settings.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("setSettings POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let settings = SettingValue::new(POWER_ON_STATUS_TARGET, "lastState");
+    bravia
+        .system()
+        .set_bootup_settings(vec![settings])
+        .await
+        .unwrap();
+
+    // Nothing to assert
+}
+
+#[tokio::test]
+async fn test_set_current_time() {
     // Arrange
     let mock_server = server_setup(JSON_BASE_PATH).await;
     let expected_body = BodyExactMatcher::from_json_file(&format!(
-        "{}/requests/set_led_indicator_status.json",
+        "{}/requests/set_current_time.json",
         JSON_BASE_PATH
     ));
     let template = ResponseTemplate::from_json_file(&format!(
-        "{}/responses/set_led_indicator_status.json",
+        "{}/responses/set_current_time.json",
         JSON_BASE_PATH
     ));
     Mock::given(method("POST"))
         .and(path(ENDPOINT_PATH))
         .and(expected_body)
         .respond_with(template)
-        .named("setLEDIndicatorStatus POST")
+        .named("setCurrentTime POST")
+        .expect(if cfg!(feature = "chrono") { 2 } else { 1 })
         .mount(&mock_server)
         .await;
     let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
 
     // Act
-    let led_status = LEDIndicatorStatus::new("Demo".to_string(), Some("true".to_string()));
     bravia
         .system()
-        .set_led_indicator_status(led_status)
+        .set_current_time("2018-10-03T13:03:04+0200", 60, 60)
+        .await
+        .unwrap();
+    #[cfg(feature = "chrono")]
+    {
+        let date_time = chrono::DateTime::parse_from_rfc3339("2018-10-03T13:03:04+02:00").unwrap();
+        bravia
+            .system()
+            .set_current_date_time(&date_time, 60)
+            .await
+            .unwrap();
+    }
+
+    // Nothing to assert
+}
+
+#[tokio::test]
+async fn test_set_led_indicator_status() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    for version in ["V1_0", "V1_1"] {
+        let expected_body = BodyExactMatcher::from_json_file(&format!(
+            "{}/requests/set_led_indicator_status_{}.json",
+            JSON_BASE_PATH, version
+        ));
+        let template = ResponseTemplate::from_json_file(&format!(
+            "{}/responses/set_led_indicator_status_{}.json",
+            JSON_BASE_PATH, version
+        ));
+        Mock::given(method("POST"))
+            .and(path(ENDPOINT_PATH))
+            .and(expected_body)
+            .respond_with(template)
+            .named("setLEDIndicatorStatus POST")
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let led_status_1_0 = LEDIndicatorStatus::new(LedMode::Dark, Some(false));
+    bravia
+        .system()
+        .set_led_indicator_status(led_status_1_0, None)
+        .await
+        .unwrap();
+    let led_status_1_1 = LEDIndicatorStatus::new(LedMode::Demo, Some(true));
+    bravia
+        .system()
+        .set_led_indicator_status(led_status_1_1, Some("1.1"))
         .await
         .unwrap();
 
@@ -463,13 +766,56 @@ async fn test_set_language() {
     // Act
     bravia
         .system()
-        .set_language("eng".to_string())
+        .set_language("eng".parse().unwrap())
         .await
         .unwrap();
 
     // Nothing to assert, this API returns ()
 }
 
+#[test]
+fn test_language_from_str() {
+    // Assert
+    assert_eq!(
+        Language::Iso639(String::from("jpn")),
+        "jpn".parse().unwrap()
+    );
+    assert_eq!(Language::SimplifiedChinese, "CHS".parse().unwrap());
+    assert!("en".parse::<Language>().is_err());
+    assert!("ENG".parse::<Language>().is_err());
+    assert_eq!(
+        "es-419",
+        Language::Other(String::from("es-419")).to_string()
+    );
+}
+
+#[tokio::test]
+async fn test_set_postal_code() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/set_postal_code.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/set_postal_code.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("setPostalCode POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    bravia.system().set_postal_code("1000001").await.unwrap();
+
+    // Nothing to assert
+}
+
 #[tokio::test]
 async fn test_set_power_saving_mode() {
     // Arrange
@@ -494,7 +840,7 @@ async fn test_set_power_saving_mode() {
     // Act
     bravia
         .system()
-        .set_power_saving_mode("pictureOff".to_string())
+        .set_power_saving_mode(PowerSavingMode::PictureOff)
         .await
         .unwrap();
 
@@ -552,3 +898,97 @@ async fn test_set_wol_mode() {
 
     // Nothing to assert, this API returns ()
 }
+
+#[tokio::test]
+async fn test_power_helpers() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(BodyExactMatcher::from_json_file(&format!(
+            "{}/requests/get_power_status.json",
+            JSON_BASE_PATH
+        )))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/get_power_status.json",
+            JSON_BASE_PATH
+        )))
+        .named("getPowerStatus POST")
+        .mount(&mock_server)
+        .await;
+    for status in [false, true] {
+        Mock::given(method("POST"))
+            .and(path(ENDPOINT_PATH))
+            .and(body_partial_json(json!({
+                "method": "setPowerStatus",
+                "params": [{"status": status}]
+            })))
+            .respond_with(ResponseTemplate::from_json_file(&format!(
+                "{}/responses/set_power_status.json",
+                JSON_BASE_PATH
+            )))
+            .expect(1)
+            .named("setPowerStatus POST")
+            .mount(&mock_server)
+            .await;
+    }
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let system = bravia.system();
+    let off = system.power_off(Duration::from_secs(1)).await;
+    let on = system.power_on(Duration::from_millis(600)).await;
+
+    // Assert
+    assert!(off.is_ok());
+    assert!(matches!(
+        on,
+        Err(Error::VerificationFailed(message))
+            if message == "did not become active within 600ms; last status: standby"
+    ));
+}
+
+#[tokio::test]
+async fn test_power_on_handle_timeout() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(body_partial_json(json!({"method": "getPowerStatus"})))
+        .respond_with(
+            ResponseTemplate::from_json_file(&format!(
+                "{}/responses/get_power_status.json",
+                JSON_BASE_PATH
+            ))
+            .set_delay(Duration::from_millis(300)),
+        )
+        .named("getPowerStatus POST")
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(body_partial_json(json!({"method": "setPowerStatus"})))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/set_power_status.json",
+            JSON_BASE_PATH
+        )))
+        .expect(1)
+        .named("setPowerStatus POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let on = bravia
+        .system()
+        .with_timeout(Duration::from_millis(100))
+        .power_on(Duration::from_millis(800))
+        .await;
+
+    // Assert
+    // The status is never read, the polls time out like the handle requires
+    assert!(matches!(
+        on,
+        Err(Error::VerificationFailed(message)) if message.contains("last error")
+    ));
+}
@@ -0,0 +1,78 @@
+use bravia_api::{error::Error, test_util::MockBravia, ApiSupport};
+use serde_json::json;
+
+const AUTH: Option<&str> = Some("TEST");
+
+#[tokio::test]
+async fn test_mock_bravia() {
+    // Arrange
+    let mock = MockBravia::start().await;
+    mock.stub("system", "getPowerStatus", json!([{ "status": "standby" }]))
+        .await;
+    mock.stub_error(
+        "audio",
+        "getVolumeInformation",
+        40005,
+        "Display Is Turned off",
+    )
+    .await;
+    let bravia = mock.client(AUTH).await.unwrap();
+
+    // Act
+    let status = bravia.system().get_power_status().await;
+    let volume = bravia.audio().get_volume_information().await;
+
+    // Assert
+    assert_eq!("standby", status.unwrap());
+    assert!(matches!(volume, Err(Error::BraviaError(code, _)) if code.code == 40005));
+    assert!(bravia
+        .supports("appControl", "getApplicationList", "1.0")
+        .await
+        .unwrap());
+    assert!(bravia
+        .supports("videoScreen", "setSceneSetting", "1.0")
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+async fn test_mock_bravia_reset() {
+    // Arrange
+    let mock = MockBravia::start().await;
+    mock.stub("system", "getPowerStatus", json!([{ "status": "standby" }]))
+        .await;
+    let bravia = mock.client(AUTH).await.unwrap();
+    bravia.system().get_power_status().await.unwrap();
+
+    // Act
+    mock.reset().await;
+    mock.stub("system", "getPowerStatus", json!([{ "status": "active" }]))
+        .await;
+    let status = bravia.system().get_power_status().await;
+
+    // Assert
+    assert_eq!("active", status.unwrap());
+    assert!(mock.client(AUTH).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_mock_bravia_api_support() {
+    // Arrange
+    let api_support =
+        ApiSupport::from_json(&json!({ "system": { "getPowerStatus": ["1.0"] } }).to_string())
+            .unwrap();
+    let mock = MockBravia::with_api_support(api_support).await;
+
+    // Act
+    let bravia = mock.client(AUTH).await.unwrap();
+
+    // Assert
+    assert!(bravia
+        .supports("system", "getPowerStatus", "1.0")
+        .await
+        .unwrap());
+    assert!(!bravia
+        .supports("appControl", "getApplicationList", "1.0")
+        .await
+        .unwrap());
+}
@@ -0,0 +1,82 @@
+use crate::common::{server_setup, FromFile};
+use bravia_api::{av_content::InputUri, error::Error, tv::Tv, Bravia};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, method, path, BodyExactMatcher},
+    Mock, ResponseTemplate,
+};
+
+const AUTH: &str = "TEST";
+
+#[tokio::test]
+async fn test_select_input() {
+    // Arrange
+    let json_base_path = "sample_payloads/av_content";
+    let mock_server = server_setup(json_base_path).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/set_play_content.json",
+        json_base_path
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/set_play_content.json",
+        json_base_path
+    ));
+    Mock::given(method("POST"))
+        .and(path("/sony/avContent"))
+        .and(expected_body)
+        .respond_with(template)
+        .expect(1)
+        .named("setPlayContent POST")
+        .mount(&mock_server)
+        .await;
+    let tv = Tv::new(Bravia::new(&mock_server.uri(), Some(AUTH)).await.unwrap());
+
+    // Act
+    let result = tv.select_input(InputUri::Hdmi(2)).await;
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_launch_app() {
+    // Arrange
+    let json_base_path = "sample_payloads/app_control";
+    let mock_server = server_setup(json_base_path).await;
+    Mock::given(method("POST"))
+        .and(path("/sony/appControl"))
+        .and(BodyExactMatcher::from_json_file(&format!(
+            "{}/requests/get_application_list.json",
+            json_base_path
+        )))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/get_application_list.json",
+            json_base_path
+        )))
+        .named("getApplicationList POST")
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/sony/appControl"))
+        .and(body_partial_json(json!({
+            "method": "setActiveApp",
+            "params": [{"uri": "com.sony.dtv.com.google.android.youtube.tv.com.google.android.apps.youtube.tv.activity.ShellActivity"}]
+        })))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/set_active_app.json",
+            json_base_path
+        )))
+        .expect(1)
+        .named("setActiveApp POST")
+        .mount(&mock_server)
+        .await;
+    let tv = Tv::new(Bravia::new(&mock_server.uri(), Some(AUTH)).await.unwrap());
+
+    // Act
+    let launched = tv.launch_app("youtube").await;
+    let missing = tv.launch_app("Netflix").await;
+
+    // Assert
+    assert!(launched.is_ok());
+    assert!(matches!(missing, Err(Error::InvalidParameter(_))));
+}
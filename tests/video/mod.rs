@@ -1,7 +1,12 @@
 use crate::common::{server_setup, FromFile};
-use bravia_api::{video::PictureQualitySettingsRequest, Bravia};
+use bravia_api::{
+    error::Error,
+    video::{Candidate, PictureMode, PictureQualitySettingsRequest, PictureQualityTarget},
+    Bravia,
+};
+use serde_json::json;
 use wiremock::{
-    matchers::{method, path, BodyExactMatcher},
+    matchers::{body_partial_json, method, path, BodyExactMatcher},
     Mock, ResponseTemplate,
 };
 
@@ -33,14 +38,17 @@ async fn test_get_picture_quality_settings() {
     // Act
     let quality_settings = bravia
         .video()
-        .get_picture_quality_settings(Some("color".to_string()))
+        .get_picture_quality_settings(Some(PictureQualityTarget::Color))
         .await
         .unwrap();
 
     // Assert
-    let quality_settings = quality_settings.get(0).unwrap();
-    assert_eq!("color", quality_settings.target.as_str());
+    let quality_settings = quality_settings.first().unwrap();
+    assert_eq!(PictureQualityTarget::Color, quality_settings.target);
     assert_eq!("2", quality_settings.current_value.as_str());
+    assert!(quality_settings.validate("100").is_ok());
+    assert!(quality_settings.validate("101").is_err());
+    assert!(quality_settings.request("vivid").is_err());
 }
 
 #[tokio::test]
@@ -65,8 +73,7 @@ async fn test_set_picture_quality_settings() {
     let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
 
     // Act
-    let settings =
-        PictureQualitySettingsRequest::new(Some("color".to_string()), Some("2".to_string()));
+    let settings = PictureQualitySettingsRequest::new(Some(PictureQualityTarget::Color), Some("2"));
     bravia
         .video()
         .set_picture_quality_settings(vec![settings])
@@ -75,3 +82,159 @@ async fn test_set_picture_quality_settings() {
 
     // Nothing to assert, this API returns ()
 }
+
+#[tokio::test]
+async fn test_picture_mode() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(body_partial_json(json!({
+            "method": "getPictureQualitySettings",
+            "params": [{"target": "pictureMode"}]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [[{
+                "target": "pictureMode",
+                "currentValue": "cinema",
+                "candidate": [{"value": "cinema"}, {"value": "game"}]
+            }]],
+            "id": 52
+        })))
+        .named("getPictureQualitySettings POST")
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(body_partial_json(json!({
+            "method": "setPictureQualitySettings",
+            "params": [{"settings": [{"target": "pictureMode", "value": "game"}]}]
+        })))
+        .respond_with(ResponseTemplate::from_json_file(&format!(
+            "{}/responses/set_picture_quality_settings.json",
+            JSON_BASE_PATH
+        )))
+        .expect(1)
+        .named("setPictureQualitySettings POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let video = bravia.video();
+    let mode = video.get_picture_mode().await.unwrap();
+    video.set_picture_mode(PictureMode::Game).await.unwrap();
+
+    // Assert
+    assert_eq!(PictureMode::Cinema, mode);
+}
+
+#[tokio::test]
+async fn test_set_validated() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let get_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_picture_quality_settings_all.json",
+        JSON_BASE_PATH
+    ));
+    let get_template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_picture_quality_settings_all.json",
+        JSON_BASE_PATH
+    ));
+    let set_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/set_picture_quality_settings.json",
+        JSON_BASE_PATH
+    ));
+    let set_template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/set_picture_quality_settings.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(get_body)
+        .respond_with(get_template)
+        .expect(2)
+        .named("getPictureQualitySettings POST")
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(set_body)
+        .respond_with(set_template)
+        .expect(1)
+        .named("setPictureQualitySettings POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+    let video = bravia.video();
+
+    // Act
+    video
+        .set_validated(vec![PictureQualitySettingsRequest::new(
+            Some(PictureQualityTarget::Color),
+            Some("2"),
+        )])
+        .await
+        .unwrap();
+    let err = video
+        .set_validated(vec![
+            PictureQualitySettingsRequest::new(Some(PictureQualityTarget::Color), Some("120")),
+            PictureQualitySettingsRequest::new(
+                Some(PictureQualityTarget::PictureMode),
+                Some("game"),
+            ),
+            PictureQualitySettingsRequest::new(Some(PictureQualityTarget::HdrMode), Some("off")),
+            PictureQualitySettingsRequest::new(Some(PictureQualityTarget::Sharpness), Some("10")),
+        ])
+        .await
+        .unwrap_err();
+
+    // Assert
+    match err {
+        Error::InvalidParameter(message) => assert_eq!(
+            "120 is not a valid value for color, game is not a valid value for pictureMode, \
+             hdrMode is not available, sharpness is not supported",
+            message
+        ),
+        err => panic!("Unexpected error: {:?}", err),
+    }
+}
+
+#[test]
+fn test_candidate_accepts() {
+    // Arrange
+    let range = Candidate {
+        value: String::new(),
+        max: 50.0,
+        min: -50.0,
+        step: 2.0,
+    };
+    let mode = Candidate {
+        value: String::from("vivid"),
+        max: -1.0,
+        min: -1.0,
+        step: -1.0,
+    };
+
+    // Assert
+    assert!(range.accepts("-50"));
+    assert!(range.accepts("10"));
+    assert!(!range.accepts("11"));
+    assert!(!range.accepts("52"));
+    assert!(!range.accepts("vivid"));
+    assert!(mode.accepts("vivid"));
+    assert!(!mode.accepts("standard"));
+}
+
+#[test]
+fn test_picture_quality_target_other() {
+    // Act
+    let target: PictureQualityTarget = serde_json::from_str("\"blackLevel\"").unwrap();
+
+    // Assert
+    assert_eq!(
+        PictureQualityTarget::Other(String::from("blackLevel")),
+        target
+    );
+    assert_eq!("\"blackLevel\"", serde_json::to_string(&target).unwrap());
+}
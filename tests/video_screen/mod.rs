@@ -1,5 +1,5 @@
 use crate::common::{server_setup, FromFile};
-use bravia_api::Bravia;
+use bravia_api::{video_screen::Scene, Bravia};
 use wiremock::{
     matchers::{method, path, BodyExactMatcher},
     Mock, ResponseTemplate,
@@ -9,6 +9,34 @@ const ENDPOINT_PATH: &str = "/sony/videoScreen";
 const JSON_BASE_PATH: &str = "sample_payloads/video_screen";
 const AUTH: Option<&str> = Some("TEST");
 
+#[tokio::test]
+async fn test_get_scene_setting() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/get_scene_setting.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/get_scene_setting.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("getSceneSetting POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    let scene = bravia.video_screen().get_scene_setting().await.unwrap();
+
+    // Assert
+    assert_eq!(Scene::Auto24pSync, scene);
+}
+
 #[tokio::test]
 async fn test_set_scene_settings() {
     // Arrange
@@ -33,7 +61,139 @@ async fn test_set_scene_settings() {
     // Act
     bravia
         .video_screen()
-        .set_scene_settings("auto".to_string())
+        .set_scene_settings(Scene::Auto)
+        .await
+        .unwrap();
+
+    // Nothing to assert, this API returns ()
+}
+
+#[tokio::test]
+async fn test_set_multi_screen_mode() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    for version in ["V1_0", "V1_1"] {
+        let expected_body = BodyExactMatcher::from_json_file(&format!(
+            "{}/requests/set_multi_screen_mode_{}.json",
+            JSON_BASE_PATH, version
+        ));
+        let template = ResponseTemplate::from_json_file(&format!(
+            "{}/responses/set_multi_screen_mode_{}.json",
+            JSON_BASE_PATH, version
+        ));
+        Mock::given(method("POST"))
+            .and(path(ENDPOINT_PATH))
+            .and(expected_body)
+            .respond_with(template)
+            .expect(1)
+            .named("setMultiScreenMode POST")
+            .mount(&mock_server)
+            .await;
+    }
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    bravia
+        .video_screen()
+        .set_multi_screen_mode("pip", Some("sub"), None)
+        .await
+        .unwrap();
+    bravia
+        .video_screen()
+        .set_multi_screen_mode("pap", Some("sub"), Some("1.1"))
+        .await
+        .unwrap();
+
+    // Nothing to assert, this API returns ()
+}
+
+#[tokio::test]
+async fn test_set_pip_sub_screen_position() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/set_pip_sub_screen_position.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/set_pip_sub_screen_position.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("setPipSubScreenPosition POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    bravia
+        .video_screen()
+        .set_pip_sub_screen_position("rightBottom".to_string())
+        .await
+        .unwrap();
+
+    // Nothing to assert, this API returns ()
+}
+
+#[tokio::test]
+async fn test_set_pap_screen_size() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/set_pap_screen_size.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/set_pap_screen_size.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("setPapScreenSize POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    bravia
+        .video_screen()
+        .set_pap_screen_size("main".to_string(), "+1".to_string())
+        .await
+        .unwrap();
+
+    // Nothing to assert, this API returns ()
+}
+
+#[tokio::test]
+async fn test_set_audio_source_screen() {
+    // Arrange
+    let mock_server = server_setup(JSON_BASE_PATH).await;
+    let expected_body = BodyExactMatcher::from_json_file(&format!(
+        "{}/requests/set_audio_source_screen.json",
+        JSON_BASE_PATH
+    ));
+    let template = ResponseTemplate::from_json_file(&format!(
+        "{}/responses/set_audio_source_screen.json",
+        JSON_BASE_PATH
+    ));
+    Mock::given(method("POST"))
+        .and(path(ENDPOINT_PATH))
+        .and(expected_body)
+        .respond_with(template)
+        .named("setAudioSourceScreen POST")
+        .mount(&mock_server)
+        .await;
+    let bravia = Bravia::new(&mock_server.uri(), AUTH).await.unwrap();
+
+    // Act
+    bravia
+        .video_screen()
+        .set_audio_source_screen("sub".to_string())
         .await
         .unwrap();
 